@@ -11,9 +11,11 @@ use std::{
 use anyhow::{Context, Result};
 use tauri_utils::{
   acl::{
-    capability::Capability, manifest::Manifest, schema::CAPABILITIES_SCHEMA_FOLDER_PATH,
-    ACL_MANIFESTS_FILE_NAME, APP_ACL_KEY, CAPABILITIES_FILE_NAME,
+    capability::Capability, lint::LintLevel, manifest::Manifest,
+    schema::CAPABILITIES_SCHEMA_FOLDER_PATH, ACL_MANIFESTS_FILE_NAME, APP_ACL_KEY,
+    CAPABILITIES_FILE_NAME,
   },
+  config::Config,
   platform::Target,
   write_if_changed,
 };
@@ -373,7 +375,45 @@ fn validate_capabilities(
   Ok(())
 }
 
-pub fn build(out_dir: &Path, target: Target, attributes: &Attributes) -> super::Result<()> {
+fn lint_capabilities(
+  acl_manifests: &BTreeMap<String, Manifest>,
+  capabilities: &BTreeMap<String, Capability>,
+  target: Target,
+  attributes: &Attributes,
+  config: &Config,
+) -> Result<()> {
+  let lint_config = attributes
+    .acl_lints
+    .clone()
+    .window_labels(config.app.windows.iter().map(|w| w.label.clone()));
+  let lint_config = attributes
+    .inlined_plugins
+    .keys()
+    .fold(lint_config, |lint_config, name| lint_config.plugin(*name));
+
+  let capabilities = tauri_utils::acl::lint::with_inlined_capabilities(capabilities, config)?;
+  let mut errors = Vec::new();
+  for diagnostic in tauri_utils::acl::lint::lint(acl_manifests, &capabilities, target, &lint_config)
+  {
+    match diagnostic.level {
+      LintLevel::Deny => errors.push(diagnostic.to_string()),
+      _ => println!("cargo:warning={diagnostic}"),
+    }
+  }
+
+  if !errors.is_empty() {
+    anyhow::bail!("capability lints failed:\n{}", errors.join("\n"));
+  }
+
+  Ok(())
+}
+
+pub fn build(
+  out_dir: &Path,
+  target: Target,
+  attributes: &Attributes,
+  config: &Config,
+) -> super::Result<()> {
   let mut acl_manifests = read_plugins_manifests()?;
 
   let app_manifest = app_manifest_permissions(
@@ -395,13 +435,15 @@ pub fn build(out_dir: &Path, target: Target, attributes: &Attributes) -> super::
 
   tauri_utils::acl::schema::generate_capability_schema(&acl_manifests, target)?;
 
-  let capabilities = if let Some(pattern) = attributes.capabilities_path_pattern {
+  let mut capabilities = if let Some(pattern) = attributes.capabilities_path_pattern {
     tauri_utils::acl::build::parse_capabilities(pattern)?
   } else {
     println!("cargo:rerun-if-changed=capabilities");
     tauri_utils::acl::build::parse_capabilities("./capabilities/**/*")?
   };
+  tauri_utils::acl::capability::resolve_extends(&mut capabilities)?;
   validate_capabilities(&acl_manifests, &capabilities)?;
+  lint_capabilities(&acl_manifests, &capabilities, target, attributes, config)?;
 
   let capabilities_path = save_capabilities(&capabilities)?;
   fs::copy(capabilities_path, out_dir.join(CAPABILITIES_FILE_NAME))?;
@@ -39,6 +39,9 @@ mod static_vcruntime;
 pub use codegen::context::CodegenContext;
 
 pub use acl::{AppManifest, DefaultPermissionRule, InlinedPlugin};
+pub use tauri_utils::acl::lint::{
+  Lint as AclLint, LintConfig as AclLintConfig, LintLevel as AclLintLevel,
+};
 
 fn copy_file(from: impl AsRef<Path>, to: impl AsRef<Path>) -> Result<()> {
   let from = from.as_ref();
@@ -352,6 +355,7 @@ pub struct Attributes {
   codegen: Option<codegen::context::CodegenContext>,
   inlined_plugins: HashMap<&'static str, InlinedPlugin>,
   app_manifest: AppManifest,
+  acl_lints: AclLintConfig,
 }
 
 impl Attributes {
@@ -405,6 +409,30 @@ impl Attributes {
     self
   }
 
+  /// Sets the configuration of the lints that run on the app capabilities.
+  ///
+  /// Lints are reported as cargo warnings by default, see [`AclLintConfig`] to allow or deny them.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri_build::{AclLint, AclLintConfig, AclLintLevel, Attributes};
+  ///
+  /// let attributes = Attributes::new().acl_lints(
+  ///   AclLintConfig::new()
+  ///     // windows created at runtime
+  ///     .window_labels(["editor-*"])
+  ///     .level(AclLint::UnusedPermissionSet, AclLintLevel::Allow)
+  ///     .deny_warnings(),
+  /// );
+  /// tauri_build::try_build(attributes).unwrap();
+  /// ```
+  #[must_use]
+  pub fn acl_lints(mut self, config: AclLintConfig) -> Self {
+    self.acl_lints = config;
+    self
+  }
+
   #[cfg(feature = "codegen")]
   #[cfg_attr(docsrs, doc(cfg(feature = "codegen")))]
   #[must_use]
@@ -526,7 +554,7 @@ pub fn try_build(attributes: Attributes) -> Result<()> {
 
   manifest::check(&config, &mut manifest)?;
 
-  acl::build(&out_dir, target, &attributes)?;
+  acl::build(&out_dir, target, &attributes, &config)?;
 
   println!("cargo:rustc-env=TAURI_ENV_TARGET_TRIPLE={target_triple}");
   // when running codegen in this build script, we need to access the env var directly
@@ -0,0 +1,113 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{collections::BTreeMap, fs::read_to_string};
+
+use clap::Parser;
+use tauri_utils::{
+  acl::{
+    capability::Capability,
+    lint::{lint, with_inlined_capabilities, Lint, LintConfig, LintLevel},
+    manifest::Manifest,
+    ACL_MANIFESTS_FILE_NAME, CAPABILITIES_FILE_NAME,
+  },
+  platform::Target,
+};
+
+use crate::{helpers::app_paths::tauri_dir, Result};
+
+#[derive(Debug, Parser)]
+#[clap(about = "Check the app capabilities for unused, shadowed or mistargeted permissions")]
+pub struct Options {
+  /// Label of a window or webview created at runtime, in addition to the windows in the app configuration.
+  #[clap(short, long)]
+  window: Vec<String>,
+  /// Lint to report as an error.
+  #[clap(short = 'D', long)]
+  deny: Vec<Lint>,
+  /// Lint to skip.
+  #[clap(short = 'A', long)]
+  allow: Vec<Lint>,
+  /// Report all warnings as errors.
+  #[clap(long)]
+  deny_warnings: bool,
+  /// Name of a plugin that is part of the app, to check its permission sets are used.
+  #[clap(long)]
+  plugin: Vec<String>,
+  /// Target triple to check the capabilities for, defaults to the current platform.
+  ///
+  /// It must be one of the values outputted by `$rustc --print target-list`.
+  #[clap(short, long)]
+  target: Option<String>,
+}
+
+pub fn command(options: Options) -> Result<()> {
+  crate::helpers::app_paths::resolve();
+
+  let target = options
+    .target
+    .as_deref()
+    .map(Target::from_triple)
+    .unwrap_or_else(Target::current);
+  let schemas_dir = tauri_dir().join("gen").join("schemas");
+  let acl_manifests_path = schemas_dir.join(ACL_MANIFESTS_FILE_NAME);
+  let capabilities_path = schemas_dir.join(CAPABILITIES_FILE_NAME);
+
+  if !(acl_manifests_path.exists() && capabilities_path.exists()) {
+    anyhow::bail!("capabilities not found, please build your application once first");
+  }
+
+  let acl =
+    serde_json::from_str::<BTreeMap<String, Manifest>>(&read_to_string(&acl_manifests_path)?)?;
+  let capabilities =
+    serde_json::from_str::<BTreeMap<String, Capability>>(&read_to_string(&capabilities_path)?)?;
+
+  let config = crate::helpers::config::get(target, None)?;
+  let config_guard = config.lock().unwrap();
+  let config = config_guard.as_ref().unwrap();
+  let window_labels = config
+    .app
+    .windows
+    .iter()
+    .map(|w| w.label.clone())
+    .collect::<Vec<_>>();
+  let capabilities = with_inlined_capabilities(&capabilities, config)?;
+
+  let mut lint_config = LintConfig::new()
+    .window_labels(window_labels)
+    .window_labels(options.window);
+  for plugin in options.plugin {
+    lint_config = lint_config.plugin(plugin);
+  }
+  for lint in options.allow {
+    lint_config = lint_config.level(lint, LintLevel::Allow);
+  }
+  for lint in options.deny {
+    lint_config = lint_config.level(lint, LintLevel::Deny);
+  }
+  if options.deny_warnings {
+    lint_config = lint_config.deny_warnings();
+  }
+
+  let diagnostics = lint(&acl, &capabilities, target, &lint_config);
+  let mut errors = 0;
+  for diagnostic in &diagnostics {
+    if diagnostic.level == LintLevel::Deny {
+      errors += 1;
+      log::error!("{diagnostic}");
+    } else {
+      log::warn!("{diagnostic}");
+    }
+  }
+
+  if errors > 0 {
+    anyhow::bail!("capability check failed with {errors} error(s)");
+  }
+
+  if diagnostics.is_empty() {
+    log::info!("No capability issues found");
+  }
+
+  Ok(())
+}
@@ -6,6 +6,7 @@ use clap::{Parser, Subcommand};
 
 use crate::Result;
 
+mod check;
 mod new;
 
 #[derive(Debug, Parser)]
@@ -19,10 +20,12 @@ pub struct Cli {
 enum Commands {
   #[clap(alias = "create")]
   New(new::Options),
+  Check(check::Options),
 }
 
 pub fn command(cli: Cli) -> Result<()> {
   match cli.command {
     Commands::New(options) => new::command(options),
+    Commands::Check(options) => check::command(options),
   }
 }
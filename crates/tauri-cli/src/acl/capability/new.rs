@@ -102,6 +102,7 @@ pub fn command(options: Options) -> Result<()> {
         )
       })
       .collect(),
+    extends: Vec::new(),
     platforms: None,
   };
 
@@ -45,6 +45,7 @@ pub fn migrate(tauri_dir: &Path) -> Result<MigratedConfig> {
         windows: vec!["main".into()],
         webviews: vec![],
         permissions,
+        extends: Vec::new(),
         platforms: None,
       })?,
     )?;
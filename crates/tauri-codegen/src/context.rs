@@ -20,7 +20,7 @@ use sha2::{Digest, Sha256};
 use syn::Expr;
 use tauri_utils::acl::{ACL_MANIFESTS_FILE_NAME, CAPABILITIES_FILE_NAME};
 use tauri_utils::{
-  acl::capability::{Capability, CapabilityFile},
+  acl::capability::{resolve_extends, resolve_extends_with, Capability, CapabilityFile},
   acl::manifest::Manifest,
   acl::resolved::Resolved,
   assets::AssetKey,
@@ -286,11 +286,36 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
     }
   );
 
+  let with_menu_icons_code = if target.is_desktop() {
+    if let Some(menu) = &config.app.menu {
+      let mut code = quote!();
+      for icon_path in menu.icons(target) {
+        let icon = CachedIcon::new(&root, &config_parent.join(icon_path))?;
+        let icon_path = icon_path.to_string_lossy();
+        code.extend(quote!(context.set_menu_icon(#icon_path, #icon);));
+      }
+      code
+    } else {
+      quote!()
+    }
+  } else {
+    quote!()
+  };
+
   let with_tray_icon_code = if target.is_desktop() {
     if let Some(tray) = &config.app.tray_icon {
       let tray_icon_icon_path = config_parent.join(&tray.icon_path);
       let icon = CachedIcon::new(&root, &tray_icon_icon_path)?;
-      quote!(context.set_tray_icon(::std::option::Option::Some(#icon));)
+      let mut code = quote!(context.set_tray_icon(::std::option::Option::Some(#icon)););
+      let frames = tray
+        .animation_frames()
+        .iter()
+        .map(|frame| CachedIcon::new(&root, &config_parent.join(frame)))
+        .collect::<Result<Vec<_>, _>>()?;
+      if !frames.is_empty() {
+        code.extend(quote!(context.set_tray_icon_animation(::std::vec![#(#frames),*]);));
+      }
+      code
     } else {
       quote!()
     }
@@ -386,8 +411,7 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
   };
 
   let capabilities_file_path = out_dir.join(CAPABILITIES_FILE_NAME);
-  let mut capabilities_from_files: BTreeMap<String, Capability> = if capabilities_file_path.exists()
-  {
+  let capabilities_from_files: BTreeMap<String, Capability> = if capabilities_file_path.exists() {
     let capabilities_file =
       std::fs::read_to_string(capabilities_file_path).expect("failed to read capabilities");
     serde_json::from_str(&capabilities_file).expect("failed to parse capabilities")
@@ -406,12 +430,16 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
         }
         CapabilityEntry::Reference(id) => {
           let capability = capabilities_from_files
-            .remove(id)
+            .get(id)
+            .cloned()
             .unwrap_or_else(|| panic!("capability with identifier {id} not found"));
           capabilities.insert(id.clone(), capability);
         }
       }
     }
+    // inlined capabilities can extend the capabilities defined in the capabilities folder
+    resolve_extends_with(&mut capabilities, &capabilities_from_files)
+      .expect("failed to resolve extended capabilities");
     capabilities
   };
 
@@ -444,8 +472,19 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
     }
   }
 
+  resolve_extends(&mut capabilities).expect("failed to resolve extended capabilities");
+  let capabilities_tokens = map_lit(
+    quote! { ::std::collections::BTreeMap },
+    &capabilities,
+    str_lit,
+    identity,
+  );
+
   let resolved = Resolved::resolve(&acl, capabilities, target).expect("failed to resolve ACL");
-  let runtime_authority = quote!(#root::ipc::RuntimeAuthority::new(#acl_tokens, #resolved));
+  let runtime_authority = quote!(
+    #root::ipc::RuntimeAuthority::new(#acl_tokens, #resolved)
+      .with_capabilities(#capabilities_tokens)
+  );
 
   let plugin_global_api_scripts = if config.app.with_global_tauri {
     if let Some(scripts) = tauri_utils::plugin::read_global_api_scripts(&out_dir) {
@@ -483,6 +522,7 @@ pub fn context_codegen(data: ContextData) -> EmbeddedAssetsResult<TokenStream> {
     );
 
     #with_tray_icon_code
+    #with_menu_icons_code
     #maybe_config_parent_setter
 
     context
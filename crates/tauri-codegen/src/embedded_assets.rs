@@ -23,7 +23,7 @@ use brotli::enc::backward_references::BrotliEncoderParams;
 const TARGET_PATH: &str = "tauri-codegen-assets";
 
 /// (key, (original filepath, compressed bytes))
-type Asset = (AssetKey, (PathBuf, PathBuf));
+type Asset = (AssetKey, (PathBuf, PathBuf, String));
 
 /// All possible errors while reading and compressing an [`EmbeddedAssets`] directory
 #[derive(Debug, Error)]
@@ -76,7 +76,7 @@ pub type EmbeddedAssetsResult<T> = Result<T, EmbeddedAssetsError>;
 /// the compressed assets in that application's binary.
 #[derive(Default)]
 pub struct EmbeddedAssets {
-  assets: HashMap<AssetKey, (PathBuf, PathBuf)>,
+  assets: HashMap<AssetKey, (PathBuf, PathBuf, String)>,
   csp_hashes: CspHashes,
 }
 
@@ -264,7 +264,7 @@ impl EmbeddedAssets {
 
     struct CompressState {
       csp_hashes: CspHashes,
-      assets: HashMap<AssetKey, (PathBuf, PathBuf)>,
+      assets: HashMap<AssetKey, (PathBuf, PathBuf, String)>,
     }
 
     let CompressState { assets, csp_hashes } = paths.into_iter().try_fold(
@@ -346,7 +346,7 @@ impl EmbeddedAssets {
     let out_path = if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
       out_dir.join(format!("{hash}.{ext}"))
     } else {
-      out_dir.join(hash)
+      out_dir.join(&hash)
     };
 
     // only compress and write to the file if it doesn't already exist.
@@ -382,14 +382,15 @@ impl EmbeddedAssets {
       }
     }
 
-    Ok((key, (path.into(), out_path)))
+    Ok((key, (path.into(), out_path, hash)))
   }
 }
 
 impl ToTokens for EmbeddedAssets {
   fn to_tokens(&self, tokens: &mut TokenStream) {
     let mut assets = TokenStream::new();
-    for (key, (input, output)) in &self.assets {
+    let mut asset_hashes = TokenStream::new();
+    for (key, (input, output, hash)) in &self.assets {
       let key: &str = key.as_ref();
       let input = input.display().to_string();
       let output = output.display().to_string();
@@ -399,6 +400,7 @@ impl ToTokens for EmbeddedAssets {
         const _: &[u8] = include_bytes!(#input);
         include_bytes!(#output)
       },));
+      asset_hashes.append_all(quote!(#key => #hash,));
     }
 
     let mut global_hashes = TokenStream::new();
@@ -428,6 +430,7 @@ impl ToTokens for EmbeddedAssets {
         #[allow(unused_imports)]
         use ::tauri::utils::assets::{CspHash, EmbeddedAssets, phf, phf::phf_map};
         EmbeddedAssets::new(phf_map! { #assets }, &[#global_hashes], phf_map! { #html_hashes })
+          .with_asset_hashes(phf_map! { #asset_hashes })
     }});
   }
 }
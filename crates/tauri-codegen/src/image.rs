@@ -8,7 +8,8 @@ use crate::{
 };
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
-use std::{ffi::OsStr, io::Cursor, path::Path};
+use std::{ffi::OsStr, path::Path};
+use tauri_utils::image::ImageFormat;
 
 /// The format the Icon is consumed as.
 pub(crate) enum IconFormat {
@@ -47,53 +48,30 @@ impl CachedIcon {
     })
   }
 
-  /// Cache an ICO icon as RGBA data, see [`ImageFormat::Image`].
+  /// Cache an ICO icon as RGBA data, see [`IconFormat::Image`].
+  ///
+  /// The largest image of the icon is used.
   pub fn new_ico(root: &TokenStream, icon: &Path) -> EmbeddedAssetsResult<Self> {
-    let buf = Self::open(icon);
-
-    let icon_dir = ico::IconDir::read(Cursor::new(&buf))
-      .unwrap_or_else(|e| panic!("failed to parse icon {}: {}", icon.display(), e));
-
-    let entry = &icon_dir.entries()[0];
-    let rgba = entry
-      .decode()
-      .unwrap_or_else(|e| panic!("failed to decode icon {}: {}", icon.display(), e))
-      .rgba_data()
-      .to_vec();
-
-    Cached::try_from(rgba).map(|cache| Self {
-      cache,
-      root: root.clone(),
-      format: IconFormat::Image {
-        width: entry.width(),
-        height: entry.height(),
-      },
-    })
+    Self::new_image(root, icon, ImageFormat::Ico)
   }
 
-  /// Cache a PNG icon as RGBA data, see [`ImageFormat::Image`].
+  /// Cache a PNG icon as RGBA data, see [`IconFormat::Image`].
   pub fn new_png(root: &TokenStream, icon: &Path) -> EmbeddedAssetsResult<Self> {
-    let buf = Self::open(icon);
-    let decoder = png::Decoder::new(Cursor::new(&buf));
-    let mut reader = decoder
-      .read_info()
-      .unwrap_or_else(|e| panic!("failed to read icon {}: {}", icon.display(), e));
-
-    if reader.output_color_type().0 != png::ColorType::Rgba {
-      panic!("icon {} is not RGBA", icon.display());
-    }
+    Self::new_image(root, icon, ImageFormat::Png)
+  }
 
-    let mut rgba = Vec::with_capacity(reader.output_buffer_size());
-    while let Ok(Some(row)) = reader.next_row() {
-      rgba.extend(row.data());
-    }
+  /// Decodes the icon with the same decoders as [`tauri::image::Image`].
+  fn new_image(root: &TokenStream, icon: &Path, format: ImageFormat) -> EmbeddedAssetsResult<Self> {
+    let buf = Self::open(icon);
+    let image = tauri_utils::image::decode(&buf, format, None)
+      .unwrap_or_else(|e| panic!("failed to decode icon {}: {}", icon.display(), e));
 
-    Cached::try_from(rgba).map(|cache| Self {
+    Cached::try_from(image.rgba).map(|cache| Self {
       cache,
       root: root.clone(),
       format: IconFormat::Image {
-        width: reader.info().width,
-        height: reader.info().height,
+        width: image.width,
+        height: image.height,
       },
     })
   }
@@ -38,6 +38,7 @@ struct WrapperAttributes {
   root: TokenStream2,
   execution_context: ExecutionContext,
   argument_case: ArgumentCase,
+  timeout: Option<u64>,
 }
 
 impl Parse for WrapperAttributes {
@@ -46,6 +47,7 @@ impl Parse for WrapperAttributes {
       root: quote!(::tauri),
       execution_context: ExecutionContext::Blocking,
       argument_case: ArgumentCase::Camel,
+      timeout: None,
     };
 
     let attrs = Punctuated::<WrapperAttributeKind, Token![,]>::parse_terminated(input)?;
@@ -87,12 +89,25 @@ impl Parse for WrapperAttributes {
                 quote!(#ident)
               };
             }
+          } else if v.path.is_ident("timeout") {
+            if let Expr::Lit(ExprLit {
+              lit: Lit::Int(i),
+              attrs: _,
+            }) = v.value
+            {
+              wrapper_attributes.timeout = Some(i.base10_parse()?);
+            } else {
+              return Err(syn::Error::new(
+                v.value.span(),
+                "expected the timeout in milliseconds as an integer literal",
+              ));
+            }
           }
         }
         WrapperAttributeKind::Meta(Meta::Path(_)) => {
           return Err(syn::Error::new(
             input.span(),
-            "unexpected input, expected one of `rename_all`, `root`, `async`",
+            "unexpected input, expected one of `rename_all`, `root`, `async`, `timeout`",
           ));
         }
         WrapperAttributeKind::Async => {
@@ -136,6 +151,14 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
     attrs.execution_context = ExecutionContext::Async;
   }
 
+  // the body of a sync command can't be interrupted, even when it runs on the thread pool with `async`
+  if attrs.timeout.is_some() && function.sig.asyncness.is_none() {
+    return quote_spanned! {
+      function.sig.span() => compile_error!("the `timeout` attribute is only supported on `async fn` commands");
+    }
+    .into();
+  }
+
   // macros used with `pub use my_macro;` need to be exported with `#[macro_export]`
   let maybe_macro_export = match &function.vis {
     Visibility::Public(_) | Visibility::Restricted(_) => quote!(#[macro_export]),
@@ -226,6 +249,14 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
 
   let root = attrs.root;
 
+  let maybe_timeout = match attrs.timeout {
+    Some(timeout) => quote!(
+      let mut $invoke = $invoke;
+      $invoke.set_timeout(::std::time::Duration::from_millis(#timeout));
+    ),
+    None => quote!(),
+  };
+
   let kind = match attrs.execution_context {
     ExecutionContext::Async if function.sig.asyncness.is_none() => "sync_threadpool",
     ExecutionContext::Async => "async",
@@ -265,6 +296,9 @@ pub fn wrapper(attributes: TokenStream, item: TokenStream) -> TokenStream {
         ($path:path, $invoke:ident) => {{
           #[allow(unused_imports)]
           use #root::ipc::private::*;
+
+          #maybe_timeout
+
           // prevent warnings when the body is a `compile_error!` or if the command has no arguments
           #[allow(unused_variables)]
           let #root::ipc::Invoke { message: #message, resolver: #resolver, acl: #acl } = $invoke;
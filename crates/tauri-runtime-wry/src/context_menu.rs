@@ -0,0 +1,144 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![cfg(any(
+  windows,
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+
+use tauri_runtime::webview::ContextMenuRequest;
+
+#[cfg(not(windows))]
+pub use self::gtk::*;
+#[cfg(windows)]
+pub use self::windows::*;
+
+type ContextMenuHandler = dyn Fn(ContextMenuRequest) -> bool + Send + Sync;
+
+#[cfg(windows)]
+mod windows {
+  use tauri_runtime::{dpi::PhysicalPosition, webview::ContextMenuRequest};
+  use webview2_com::{
+    take_pwstr, ContextMenuRequestedEventHandler, Microsoft::Web::WebView2::Win32::*,
+  };
+  use windows::{
+    core::{Interface, PWSTR},
+    Win32::{
+      Foundation::{BOOL, POINT},
+      System::WinRT::EventRegistrationToken,
+    },
+  };
+  use wry::WebViewExtWindows;
+
+  use super::ContextMenuHandler;
+
+  /// Calls the handler when the context menu of the webview is requested,
+  /// hiding the native menu if it returns `false`.
+  pub fn attach_context_menu_handler(webview: &wry::WebView, handler: Box<ContextMenuHandler>) {
+    let Ok(webview) = (unsafe { webview.controller().CoreWebView2() })
+      .and_then(|webview| webview.cast::<ICoreWebView2_11>())
+    else {
+      return;
+    };
+
+    let mut token = EventRegistrationToken::default();
+    let _ = unsafe {
+      webview.add_ContextMenuRequested(
+        &ContextMenuRequestedEventHandler::create(Box::new(move |_, args| {
+          let Some(args) = args else {
+            return Ok(());
+          };
+          let target = args.ContextMenuTarget()?;
+
+          let mut location = POINT::default();
+          args.Location(&mut location)?;
+
+          let mut has_link = BOOL::default();
+          target.HasLinkUri(&mut has_link)?;
+          let link_url = if has_link.as_bool() {
+            let mut uri = PWSTR::null();
+            target.LinkUri(&mut uri)?;
+            take_pwstr(uri).parse().ok()
+          } else {
+            None
+          };
+
+          let mut kind = COREWEBVIEW2_CONTEXT_MENU_TARGET_KIND::default();
+          target.Kind(&mut kind)?;
+          let image_url = if kind == COREWEBVIEW2_CONTEXT_MENU_TARGET_KIND_IMAGE {
+            let mut uri = PWSTR::null();
+            target.SourceUri(&mut uri)?;
+            take_pwstr(uri).parse().ok()
+          } else {
+            None
+          };
+
+          let mut has_selection = BOOL::default();
+          target.HasSelection(&mut has_selection)?;
+          let selection_text = if has_selection.as_bool() {
+            let mut text = PWSTR::null();
+            target.SelectionText(&mut text)?;
+            Some(take_pwstr(text))
+          } else {
+            None
+          };
+
+          let mut is_editable = BOOL::default();
+          target.IsEditable(&mut is_editable)?;
+
+          let show_native_menu = handler(ContextMenuRequest {
+            position: PhysicalPosition::new(location.x, location.y).into(),
+            link_url,
+            image_url,
+            selection_text,
+            is_editable: is_editable.as_bool(),
+          });
+          if !show_native_menu {
+            args.SetHandled(true)?;
+          }
+
+          Ok(())
+        })),
+        &mut token,
+      )
+    };
+  }
+}
+
+#[cfg(not(windows))]
+mod gtk {
+  use gtk::prelude::*;
+  use tauri_runtime::{dpi::LogicalPosition, webview::ContextMenuRequest};
+  use webkit2gtk::{HitTestResultExt, WebViewExt};
+  use wry::WebViewExtUnix;
+
+  use super::ContextMenuHandler;
+
+  /// Calls the handler when the context menu of the webview is requested,
+  /// hiding the native menu if it returns `false`.
+  pub fn attach_context_menu_handler(webview: &wry::WebView, handler: Box<ContextMenuHandler>) {
+    webview
+      .webview()
+      .connect_context_menu(move |_webview, _menu, event, hit_test| {
+        // menus opened with the keyboard have no position
+        let (x, y) = event.coords().unwrap_or_default();
+        let show_native_menu = handler(ContextMenuRequest {
+          position: LogicalPosition::new(x, y).into(),
+          link_url: hit_test.link_uri().and_then(|uri| uri.parse().ok()),
+          image_url: hit_test.image_uri().and_then(|uri| uri.parse().ok()),
+          // WebKitGTK does not give the selected text, and reading the primary selection
+          // would run a nested main loop in the signal handler
+          selection_text: None,
+          is_editable: hit_test.context_is_editable(),
+        });
+
+        // returning `true` prevents the native menu from showing
+        !show_native_menu
+      });
+  }
+}
@@ -19,10 +19,10 @@ use raw_window_handle::{DisplayHandle, HasDisplayHandle, HasWindowHandle};
 
 use tauri_runtime::{
   dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size},
-  monitor::Monitor,
+  monitor::{Monitor, PhysicalRect},
   webview::{DetachedWebview, DownloadEvent, PendingWebview, WebviewIpcHandler},
   window::{
-    CursorIcon, DetachedWindow, DragDropEvent, PendingWindow, RawWindow, WebviewEvent,
+    CursorIcon, DetachedWindow, DragDropEvent, HitTest, PendingWindow, RawWindow, WebviewEvent,
     WindowBuilder, WindowBuilderBase, WindowEvent, WindowId, WindowSizeConstraints,
   },
   DeviceEventFilter, Error, EventLoopProxy, ExitRequestedEventAction, Icon, ProgressBarState,
@@ -120,6 +120,26 @@ use std::{
 pub type WebviewId = u32;
 type IpcHandler = dyn Fn(Request<String>) + 'static;
 
+#[cfg(any(
+  windows,
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+mod context_menu;
+#[cfg(desktop)]
+mod monitors;
+#[cfg(any(
+  windows,
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+mod permission_request;
 #[cfg(any(
   windows,
   target_os = "linux",
@@ -380,6 +400,24 @@ pub enum ActiveTracingSpan {
   },
 }
 
+/// The last known monitors, used to report the monitor changes notified by the platform.
+#[derive(Debug, Clone, Default)]
+pub struct MonitorsStore(Arc<Mutex<Option<Vec<Monitor>>>>);
+
+impl MonitorsStore {
+  /// Reads the available monitors, returning them if they changed since the last read.
+  fn update<T>(&self, event_loop: &EventLoopWindowTarget<T>) -> Option<Vec<Monitor>> {
+    let monitors: Vec<Monitor> = event_loop
+      .available_monitors()
+      .map(|m| MonitorHandleWrapper(m).into())
+      .collect();
+    let previous = self.0.lock().unwrap().replace(monitors.clone());
+    previous
+      .filter(|previous| *previous != monitors)
+      .map(|_| monitors)
+  }
+}
+
 #[derive(Debug)]
 pub struct WindowsStore(RefCell<BTreeMap<WindowId, WindowWrapper>>);
 
@@ -397,6 +435,7 @@ pub struct DispatcherMainThreadContext<T: UserEvent> {
   pub web_context: WebContextStore,
   // changing this to an Rc will cause frequent app crashes.
   pub windows: Arc<WindowsStore>,
+  pub monitors: MonitorsStore,
   #[cfg(feature = "tracing")]
   pub active_tracing_spans: ActiveTraceSpanStore,
 }
@@ -523,12 +562,17 @@ pub struct MonitorHandleWrapper(pub MonitorHandle);
 
 impl From<MonitorHandleWrapper> for Monitor {
   fn from(monitor: MonitorHandleWrapper) -> Monitor {
-    Self {
-      name: monitor.0.name(),
-      position: PhysicalPositionWrapper(monitor.0.position()).into(),
-      size: PhysicalSizeWrapper(monitor.0.size()).into(),
-      scale_factor: monitor.0.scale_factor(),
-    }
+    let (work_area_position, work_area_size) = window::monitor_work_area(&monitor.0);
+    Self::new(
+      monitor.0.name(),
+      PhysicalSizeWrapper(monitor.0.size()).into(),
+      PhysicalPositionWrapper(monitor.0.position()).into(),
+      monitor.0.scale_factor(),
+    )
+    .with_work_area(PhysicalRect {
+      position: PhysicalPositionWrapper(work_area_position).into(),
+      size: PhysicalSizeWrapper(work_area_size).into(),
+    })
   }
 }
 
@@ -1220,6 +1264,7 @@ pub enum WindowMessage {
   SetTheme(Option<Theme>),
   DragWindow,
   ResizeDragWindow(tauri_runtime::ResizeDirection),
+  SetHitTest(HitTest),
   RequestRedraw,
 }
 
@@ -1301,6 +1346,8 @@ pub enum Message<T: 'static> {
     Box<dyn FnOnce() -> (String, TaoWindowBuilder) + Send>,
     Sender<Result<Weak<Window>>>,
   ),
+  /// The monitors or their work areas may have changed.
+  MonitorsChanged,
   UserEvent(T),
 }
 
@@ -2064,6 +2111,13 @@ impl<T: UserEvent> WindowDispatch<T> for WryWindowDispatcher<T> {
     )
   }
 
+  fn set_hit_test(&self, hit_test: HitTest) -> Result<()> {
+    send_user_message(
+      &self.context,
+      Message::Window(self.window_id, WindowMessage::SetHitTest(hit_test)),
+    )
+  }
+
   fn set_progress_bar(&self, progress_state: ProgressBarState) -> Result<()> {
     send_user_message(
       &self.context,
@@ -2134,6 +2188,8 @@ pub struct WindowWrapper {
   has_children: AtomicBool,
   webviews: Vec<WebviewWrapper>,
   window_event_listeners: WindowEventListeners,
+  /// The drag, caption button and resize areas of the undecorated window.
+  hit_test: Arc<Mutex<HitTest>>,
   #[cfg(windows)]
   is_window_transparent: bool,
   #[cfg(windows)]
@@ -2422,6 +2478,17 @@ impl<T: UserEvent> Wry<T> {
     let windows = Arc::new(WindowsStore(RefCell::new(BTreeMap::default())));
     let window_id_map = WindowIdStore::default();
 
+    let monitors = MonitorsStore::default();
+    // the changes are compared to the monitors available on startup
+    monitors.update(event_loop.deref());
+    #[cfg(desktop)]
+    {
+      let proxy = event_loop.create_proxy();
+      crate::monitors::observe_monitors(move || {
+        let _ = proxy.send_event(Message::MonitorsChanged);
+      });
+    }
+
     let context = Context {
       window_id_map,
       main_thread_id,
@@ -2430,6 +2497,7 @@ impl<T: UserEvent> Wry<T> {
         window_target: event_loop.deref().clone(),
         web_context,
         windows,
+        monitors,
         #[cfg(feature = "tracing")]
         active_tracing_spans: Default::default(),
       },
@@ -2665,6 +2733,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
   fn run_iteration<F: FnMut(RunEvent<T>) + 'static>(&mut self, mut callback: F) {
     use tao::platform::run_return::EventLoopExtRunReturn;
     let windows = self.context.main_thread.windows.clone();
+    let monitors = self.context.main_thread.monitors.clone();
     let window_id_map = self.context.window_id_map.clone();
     let web_context = &self.context.main_thread.web_context;
     let plugins = self.context.plugins.clone();
@@ -2692,6 +2761,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
               callback: &mut callback,
               window_id_map: window_id_map.clone(),
               windows: windows.clone(),
+              monitors: monitors.clone(),
               #[cfg(feature = "tracing")]
               active_tracing_spans: active_tracing_spans.clone(),
             },
@@ -2709,6 +2779,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
           EventLoopIterationContext {
             callback: &mut callback,
             windows: windows.clone(),
+            monitors: monitors.clone(),
             window_id_map: window_id_map.clone(),
             #[cfg(feature = "tracing")]
             active_tracing_spans: active_tracing_spans.clone(),
@@ -2719,6 +2790,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
 
   fn run<F: FnMut(RunEvent<T>) + 'static>(self, mut callback: F) {
     let windows = self.context.main_thread.windows.clone();
+    let monitors = self.context.main_thread.monitors.clone();
     let window_id_map = self.context.window_id_map.clone();
     let web_context = self.context.main_thread.web_context;
     let plugins = self.context.plugins.clone();
@@ -2738,6 +2810,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
             callback: &mut callback,
             window_id_map: window_id_map.clone(),
             windows: windows.clone(),
+            monitors: monitors.clone(),
             #[cfg(feature = "tracing")]
             active_tracing_spans: active_tracing_spans.clone(),
           },
@@ -2755,6 +2828,7 @@ impl<T: UserEvent> Runtime<T> for Wry<T> {
           callback: &mut callback,
           window_id_map: window_id_map.clone(),
           windows: windows.clone(),
+          monitors: monitors.clone(),
           #[cfg(feature = "tracing")]
           active_tracing_spans: active_tracing_spans.clone(),
         },
@@ -2767,6 +2841,7 @@ pub struct EventLoopIterationContext<'a, T: UserEvent> {
   pub callback: &'a mut (dyn FnMut(RunEvent<T>) + 'static),
   pub window_id_map: WindowIdStore,
   pub windows: Arc<WindowsStore>,
+  pub monitors: MonitorsStore,
   #[cfg(feature = "tracing")]
   pub active_tracing_spans: ActiveTraceSpanStore,
 }
@@ -2808,9 +2883,10 @@ fn handle_user_message<T: UserEvent>(
           w.webviews.clone(),
           w.has_children.load(Ordering::Relaxed),
           w.window_event_listeners.clone(),
+          w.hit_test.clone(),
         )
       });
-      if let Some((Some(window), webviews, has_children, window_event_listeners)) = w {
+      if let Some((Some(window), webviews, has_children, window_event_listeners, hit_test)) = w {
         match window_message {
           WindowMessage::AddEventListener(id, listener) => {
             window_event_listeners.lock().unwrap().insert(id, listener);
@@ -2900,7 +2976,7 @@ fn handle_user_message<T: UserEvent>(
             if !resizable {
               undecorated_resizing::detach_resize_handler(window.hwnd());
             } else if !window.is_decorated() {
-              undecorated_resizing::attach_resize_handler(window.hwnd());
+              undecorated_resizing::attach_resize_handler(window.hwnd(), hit_test);
             }
           }
           WindowMessage::SetMaximizable(maximizable) => window.set_maximizable(maximizable),
@@ -2926,7 +3002,7 @@ fn handle_user_message<T: UserEvent>(
             if decorations {
               undecorated_resizing::detach_resize_handler(window.hwnd());
             } else if window.is_resizable() {
-              undecorated_resizing::attach_resize_handler(window.hwnd());
+              undecorated_resizing::attach_resize_handler(window.hwnd(), hit_test);
             }
           }
           WindowMessage::SetShadow(_enable) => {
@@ -3011,6 +3087,11 @@ fn handle_user_message<T: UserEvent>(
               tauri_runtime::ResizeDirection::West => tao::window::ResizeDirection::West,
             });
           }
+          WindowMessage::SetHitTest(new_hit_test) => {
+            *hit_test.lock().unwrap() = new_hit_test;
+            #[cfg(windows)]
+            undecorated_resizing::update_resize_handler(window.hwnd());
+          }
           WindowMessage::RequestRedraw => {
             window.request_redraw();
           }
@@ -3443,6 +3524,7 @@ fn handle_user_message<T: UserEvent>(
             inner: Some(window.clone()),
             window_event_listeners: Default::default(),
             webviews: Vec::new(),
+            hit_test: Default::default(),
             #[cfg(windows)]
             is_window_transparent,
             #[cfg(windows)]
@@ -3455,7 +3537,7 @@ fn handle_user_message<T: UserEvent>(
       }
     }
 
-    Message::UserEvent(_) => (),
+    Message::MonitorsChanged | Message::UserEvent(_) => (),
     Message::EventLoopWindowTarget(message) => match message {
       EventLoopWindowTargetMessage::CursorPosition(sender) => {
         let pos = event_loop
@@ -3477,6 +3559,7 @@ fn handle_event_loop<T: UserEvent>(
     callback,
     window_id_map,
     windows,
+    monitors,
     #[cfg(feature = "tracing")]
     active_tracing_spans,
   } = context;
@@ -3686,6 +3769,12 @@ fn handle_event_loop<T: UserEvent>(
         on_window_close(id, windows);
       }
       Message::UserEvent(t) => callback(RunEvent::UserEvent(t)),
+      Message::MonitorsChanged => {
+        // several notifications are sent for a single change
+        if let Some(monitors) = monitors.update(event_loop) {
+          callback(RunEvent::MonitorsChanged { monitors });
+        }
+      }
       message => {
         handle_user_message(
           event_loop,
@@ -3918,6 +4007,25 @@ fn create_window<T: UserEvent, F: Fn(RawWindow) + Send + 'static>(
     )?);
   }
 
+  let hit_test = Arc::<Mutex<HitTest>>::default();
+
+  // the undecorated window areas are only handled when the webview is the entire window content
+  #[cfg(not(feature = "unstable"))]
+  if let Some(_webview) = webviews.first() {
+    #[cfg(any(
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd"
+    ))]
+    undecorated_resizing::attach_resize_handler(&_webview.inner, hit_test.clone());
+    #[cfg(windows)]
+    if window.is_resizable() && !window.is_decorated() {
+      undecorated_resizing::attach_resize_handler(window.hwnd(), hit_test.clone());
+    }
+  }
+
   let window = Arc::new(window);
 
   #[cfg(windows)]
@@ -3942,6 +4050,7 @@ fn create_window<T: UserEvent, F: Fn(RawWindow) + Send + 'static>(
     inner: Some(window),
     webviews,
     window_event_listeners,
+    hit_test,
     #[cfg(windows)]
     is_window_transparent,
     #[cfg(windows)]
@@ -4071,6 +4180,13 @@ fn create_webview<T: UserEvent>(
     });
   }
 
+  if let Some(new_window_handler) = pending.new_window_handler {
+    webview_builder = webview_builder.with_new_window_req_handler(move |url| match url.parse() {
+      Ok(url) => new_window_handler(url),
+      Err(_) => false,
+    });
+  }
+
   let webview_bounds = if let Some(bounds) = webview_attributes.bounds {
     let bounds: RectWrapper = bounds.into();
     let bounds = bounds.0;
@@ -4283,19 +4399,28 @@ fn create_webview<T: UserEvent>(
   }
   .map_err(|e| Error::CreateWebview(Box::new(dbg!(e))))?;
 
-  if kind == WebviewKind::WindowContent {
-    #[cfg(any(
-      target_os = "linux",
-      target_os = "dragonfly",
-      target_os = "freebsd",
-      target_os = "netbsd",
-      target_os = "openbsd"
-    ))]
-    undecorated_resizing::attach_resize_handler(&webview);
-    #[cfg(windows)]
-    if window.is_resizable() && !window.is_decorated() {
-      undecorated_resizing::attach_resize_handler(window.hwnd());
-    }
+  #[cfg(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  if let Some(handler) = pending.permission_request_handler {
+    permission_request::attach_permission_request_handler(&webview, context.clone(), handler);
+  }
+
+  #[cfg(any(
+    windows,
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  if let Some(handler) = pending.context_menu_handler {
+    context_menu::attach_context_menu_handler(&webview, handler);
   }
 
   #[cfg(windows)]
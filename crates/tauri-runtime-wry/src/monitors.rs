@@ -0,0 +1,142 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![cfg(any(
+  windows,
+  target_os = "macos",
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+
+#[cfg(not(any(windows, target_os = "macos")))]
+pub use self::gtk::*;
+#[cfg(target_os = "macos")]
+pub use self::macos::*;
+#[cfg(windows)]
+pub use self::windows::*;
+
+#[cfg(windows)]
+mod windows {
+  use std::cell::RefCell;
+
+  use windows::core::*;
+  use windows::Win32::Foundation::*;
+  use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+  use windows::Win32::UI::WindowsAndMessaging::*;
+
+  const CLASS_NAME: PCWSTR = w!("TAURI_MONITORS_OBSERVER");
+
+  thread_local! {
+    static NOTIFY: RefCell<Option<Box<dyn Fn()>>> = RefCell::new(None);
+  }
+
+  /// Calls `notify` on the main thread when a display changes or when the work area changes.
+  pub fn observe_monitors<F: Fn() + 'static>(notify: F) {
+    NOTIFY.with(|n| n.borrow_mut().replace(Box::new(notify)));
+
+    let hinstance = unsafe { HINSTANCE(GetModuleHandleW(PCWSTR::null()).unwrap_or_default().0) };
+    let class = WNDCLASSEXW {
+      cbSize: std::mem::size_of::<WNDCLASSEXW>() as u32,
+      lpfnWndProc: Some(monitors_observer_proc),
+      hInstance: hinstance,
+      lpszClassName: CLASS_NAME,
+      ..Default::default()
+    };
+    unsafe { RegisterClassExW(&class) };
+
+    // a hidden top-level window since message-only windows don't receive the broadcast messages
+    let window = unsafe {
+      CreateWindowExW(
+        WINDOW_EX_STYLE::default(),
+        CLASS_NAME,
+        PCWSTR::null(),
+        WINDOW_STYLE::default(),
+        0,
+        0,
+        0,
+        0,
+        HWND::default(),
+        HMENU::default(),
+        hinstance,
+        None,
+      )
+    };
+    if let Err(e) = window {
+      log::error!("failed to observe the monitor changes: {e}");
+    }
+  }
+
+  unsafe extern "system" fn monitors_observer_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+  ) -> LRESULT {
+    let changed = match msg {
+      WM_DISPLAYCHANGE => true,
+      WM_SETTINGCHANGE => wparam.0 as u32 == SPI_SETWORKAREA.0,
+      _ => false,
+    };
+    if changed {
+      NOTIFY.with(|n| {
+        if let Some(notify) = &*n.borrow() {
+          notify();
+        }
+      });
+    }
+
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+  }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+  use std::ptr::NonNull;
+
+  use block2::RcBlock;
+  use objc2_app_kit::NSApplicationDidChangeScreenParametersNotification;
+  use objc2_foundation::{NSNotification, NSNotificationCenter};
+
+  /// Calls `notify` on the main thread when the screens or their visible frames change.
+  pub fn observe_monitors<F: Fn() + 'static>(notify: F) {
+    let block = RcBlock::new(move |_: NonNull<NSNotification>| notify());
+    let observer = unsafe {
+      NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+        Some(NSApplicationDidChangeScreenParametersNotification),
+        None,
+        None,
+        &block,
+      )
+    };
+    // the monitors are observed until the app exits
+    std::mem::forget(observer);
+  }
+}
+
+#[cfg(not(any(windows, target_os = "macos")))]
+mod gtk {
+  use std::rc::Rc;
+
+  use gtk::gdk::Display;
+
+  /// Calls `notify` on the main thread when a monitor is added or removed or when the monitors change.
+  pub fn observe_monitors<F: Fn() + 'static>(notify: F) {
+    let Some(display) = Display::default() else {
+      log::error!("failed to observe the monitor changes: no default display");
+      return;
+    };
+
+    let notify = Rc::new(notify);
+    let notify_ = notify.clone();
+    display.connect_monitor_added(move |_, _| notify_());
+    let notify_ = notify.clone();
+    display.connect_monitor_removed(move |_, _| notify_());
+    display
+      .default_screen()
+      .connect_monitors_changed(move |_| notify());
+  }
+}
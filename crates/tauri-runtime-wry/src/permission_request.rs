@@ -0,0 +1,215 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+#![cfg(any(
+  windows,
+  target_os = "linux",
+  target_os = "dragonfly",
+  target_os = "freebsd",
+  target_os = "netbsd",
+  target_os = "openbsd"
+))]
+
+use std::{
+  cell::RefCell,
+  collections::HashMap,
+  sync::atomic::{AtomicU32, Ordering},
+};
+
+use tauri_runtime::{
+  webview::{PermissionRequest, PermissionResponder},
+  UserEvent,
+};
+use tauri_utils::config::WebviewPermission;
+use url::Url;
+
+use crate::{send_user_message, Context, Message};
+
+#[cfg(not(windows))]
+pub use self::gtk::*;
+#[cfg(windows)]
+pub use self::windows::*;
+
+type PermissionRequestHandler = dyn Fn(PermissionRequest) + Send + Sync;
+
+static NEXT_REQUEST_ID: AtomicU32 = AtomicU32::new(0);
+
+thread_local! {
+  /// The answers of the requests waiting for the handler, they can only be used on the main thread.
+  static PENDING_ANSWERS: RefCell<HashMap<u32, Box<dyn FnOnce(Option<bool>)>>> = Default::default();
+}
+
+/// Sends the request to the handler, calling `answer` on the main thread with whether the permissions are granted,
+/// or `None` to keep the default behavior of the webview.
+fn request_permissions<T: UserEvent>(
+  context: &Context<T>,
+  handler: &PermissionRequestHandler,
+  permissions: Vec<WebviewPermission>,
+  origin: Url,
+  answer: impl FnOnce(Option<bool>) + 'static,
+) {
+  let id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+  PENDING_ANSWERS.with(|answers| answers.borrow_mut().insert(id, Box::new(answer)));
+
+  let context = context.clone();
+  handler(PermissionRequest {
+    permissions,
+    origin,
+    responder: PermissionResponder::new(move |allow| {
+      let _ = send_user_message(
+        &context,
+        Message::Task(Box::new(move || {
+          let answer = PENDING_ANSWERS.with(|answers| answers.borrow_mut().remove(&id));
+          if let Some(answer) = answer {
+            answer(allow);
+          }
+        })),
+      );
+    }),
+  });
+}
+
+#[cfg(windows)]
+mod windows {
+  use std::sync::Arc;
+
+  use tauri_runtime::UserEvent;
+  use tauri_utils::config::WebviewPermission;
+  use webview2_com::{
+    take_pwstr, Microsoft::Web::WebView2::Win32::*, PermissionRequestedEventHandler,
+  };
+  use windows::{core::PWSTR, Win32::System::WinRT::EventRegistrationToken};
+  use wry::WebViewExtWindows;
+
+  use super::{request_permissions, PermissionRequestHandler};
+  use crate::Context;
+
+  fn requested_permission(kind: COREWEBVIEW2_PERMISSION_KIND) -> Option<WebviewPermission> {
+    match kind {
+      COREWEBVIEW2_PERMISSION_KIND_CAMERA => Some(WebviewPermission::Camera),
+      COREWEBVIEW2_PERMISSION_KIND_MICROPHONE => Some(WebviewPermission::Microphone),
+      COREWEBVIEW2_PERMISSION_KIND_GEOLOCATION => Some(WebviewPermission::Geolocation),
+      COREWEBVIEW2_PERMISSION_KIND_CLIPBOARD_READ => Some(WebviewPermission::ClipboardRead),
+      COREWEBVIEW2_PERMISSION_KIND_NOTIFICATIONS => Some(WebviewPermission::Notifications),
+      _ => None,
+    }
+  }
+
+  /// Forwards the permission requests of the webview to the handler.
+  ///
+  /// The requests not mapped to a [`WebviewPermission`] keep the WebView2 behavior.
+  pub fn attach_permission_request_handler<T: UserEvent>(
+    webview: &wry::WebView,
+    context: Context<T>,
+    handler: Arc<PermissionRequestHandler>,
+  ) {
+    let Ok(webview) = (unsafe { webview.controller().CoreWebView2() }) else {
+      return;
+    };
+
+    let mut token = EventRegistrationToken::default();
+    let _ = unsafe {
+      webview.add_PermissionRequested(
+        &PermissionRequestedEventHandler::create(Box::new(move |_, args| {
+          let Some(args) = args else {
+            return Ok(());
+          };
+
+          let mut kind = COREWEBVIEW2_PERMISSION_KIND::default();
+          args.PermissionKind(&mut kind)?;
+          let Some(permission) = requested_permission(kind) else {
+            return Ok(());
+          };
+          let mut uri = PWSTR::null();
+          args.Uri(&mut uri)?;
+          let Ok(origin) = take_pwstr(uri).parse() else {
+            return Ok(());
+          };
+
+          let deferral = args.GetDeferral()?;
+          request_permissions(&context, &handler, vec![permission], origin, move |allow| {
+            let _ = args.SetState(match allow {
+              Some(true) => COREWEBVIEW2_PERMISSION_STATE_ALLOW,
+              Some(false) => COREWEBVIEW2_PERMISSION_STATE_DENY,
+              None => COREWEBVIEW2_PERMISSION_STATE_DEFAULT,
+            });
+            let _ = deferral.Complete();
+          });
+
+          Ok(())
+        })),
+        &mut token,
+      )
+    };
+  }
+}
+
+#[cfg(not(windows))]
+mod gtk {
+  use std::sync::Arc;
+
+  use gtk::prelude::*;
+  use tauri_runtime::UserEvent;
+  use tauri_utils::config::WebviewPermission;
+  use webkit2gtk::{
+    GeolocationPermissionRequest, NotificationPermissionRequest, PermissionRequest,
+    PermissionRequestExt, UserMediaPermissionRequest, UserMediaPermissionRequestExt, WebViewExt,
+  };
+  use wry::WebViewExtUnix;
+
+  use super::{request_permissions, PermissionRequestHandler};
+  use crate::Context;
+
+  fn requested_permissions(request: &PermissionRequest) -> Vec<WebviewPermission> {
+    if let Some(request) = request.downcast_ref::<UserMediaPermissionRequest>() {
+      let mut permissions = Vec::new();
+      if request.is_for_video_device() {
+        permissions.push(WebviewPermission::Camera);
+      }
+      if request.is_for_audio_device() {
+        permissions.push(WebviewPermission::Microphone);
+      }
+      permissions
+    } else if request.is::<GeolocationPermissionRequest>() {
+      vec![WebviewPermission::Geolocation]
+    } else if request.is::<NotificationPermissionRequest>() {
+      vec![WebviewPermission::Notifications]
+    } else {
+      Vec::new()
+    }
+  }
+
+  /// Forwards the permission requests of the webview to the handler.
+  ///
+  /// The requests not mapped to a [`WebviewPermission`] keep the WebKitGTK behavior.
+  pub fn attach_permission_request_handler<T: UserEvent>(
+    webview: &wry::WebView,
+    context: Context<T>,
+    handler: Arc<PermissionRequestHandler>,
+  ) {
+    webview
+      .webview()
+      .connect_permission_request(move |webview, request| {
+        let permissions = requested_permissions(request);
+        if permissions.is_empty() {
+          return false;
+        }
+        let Some(origin) = webview.uri().and_then(|uri| uri.parse().ok()) else {
+          return false;
+        };
+
+        let request = request.clone();
+        request_permissions(&context, &handler, permissions, origin, move |allow| {
+          // WebKitGTK denies the requests that are not handled
+          if allow == Some(true) {
+            request.allow();
+          } else {
+            request.deny();
+          }
+        });
+
+        true
+      });
+  }
+}
@@ -11,101 +11,45 @@
   target_os = "openbsd"
 ))]
 
-const CLIENT: isize = 0b0000;
-const LEFT: isize = 0b0001;
-const RIGHT: isize = 0b0010;
-const TOP: isize = 0b0100;
-const BOTTOM: isize = 0b1000;
-const TOPLEFT: isize = TOP | LEFT;
-const TOPRIGHT: isize = TOP | RIGHT;
-const BOTTOMLEFT: isize = BOTTOM | LEFT;
-const BOTTOMRIGHT: isize = BOTTOM | RIGHT;
-
 #[cfg(not(windows))]
 pub use self::gtk::*;
 #[cfg(windows)]
 pub use self::windows::*;
 
-#[cfg(windows)]
-type WindowPositions = i32;
-#[cfg(not(windows))]
-type WindowPositions = f64;
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum HitTestResult {
-  Client,
-  Left,
-  Right,
-  Top,
-  Bottom,
-  TopLeft,
-  TopRight,
-  BottomLeft,
-  BottomRight,
-  NoWhere,
-}
-
-#[allow(clippy::too_many_arguments)]
-fn hit_test(
-  left: WindowPositions,
-  top: WindowPositions,
-  right: WindowPositions,
-  bottom: WindowPositions,
-  cx: WindowPositions,
-  cy: WindowPositions,
-  border_x: WindowPositions,
-  border_y: WindowPositions,
-) -> HitTestResult {
-  #[rustfmt::skip]
-  let result = (LEFT * (cx < left + border_x) as isize)
-             | (RIGHT * (cx >= right - border_x) as isize)
-             | (TOP * (cy < top + border_y) as isize)
-             | (BOTTOM * (cy >= bottom - border_y) as isize);
-
-  match result {
-    CLIENT => HitTestResult::Client,
-    LEFT => HitTestResult::Left,
-    RIGHT => HitTestResult::Right,
-    TOP => HitTestResult::Top,
-    BOTTOM => HitTestResult::Bottom,
-    TOPLEFT => HitTestResult::TopLeft,
-    TOPRIGHT => HitTestResult::TopRight,
-    BOTTOMLEFT => HitTestResult::BottomLeft,
-    BOTTOMRIGHT => HitTestResult::BottomRight,
-    _ => HitTestResult::NoWhere,
-  }
-}
-
 #[cfg(windows)]
 mod windows {
-  use super::{hit_test, HitTestResult};
+  use std::sync::{Arc, Mutex};
 
+  use tauri_runtime::{
+    dpi::{LogicalPosition, LogicalSize},
+    window::{HitTest, HitTestArea},
+    ResizeDirection,
+  };
   use windows::core::*;
   use windows::Win32::System::LibraryLoader::*;
   use windows::Win32::UI::WindowsAndMessaging::*;
-  use windows::Win32::{Foundation::*, UI::Shell::SetWindowSubclass};
+  use windows::Win32::{Foundation::*, UI::HiDpi::GetDpiForWindow, UI::Shell::SetWindowSubclass};
   use windows::Win32::{Graphics::Gdi::*, UI::Shell::DefSubclassProc};
 
-  impl HitTestResult {
-    fn to_win32(self) -> i32 {
-      match self {
-        HitTestResult::Left => HTLEFT as _,
-        HitTestResult::Right => HTRIGHT as _,
-        HitTestResult::Top => HTTOP as _,
-        HitTestResult::Bottom => HTBOTTOM as _,
-        HitTestResult::TopLeft => HTTOPLEFT as _,
-        HitTestResult::TopRight => HTTOPRIGHT as _,
-        HitTestResult::BottomLeft => HTBOTTOMLEFT as _,
-        HitTestResult::BottomRight => HTBOTTOMRIGHT as _,
-        _ => HTTRANSPARENT,
-      }
+  fn to_win32(area: HitTestArea) -> i32 {
+    match area {
+      HitTestArea::Resize(ResizeDirection::West) => HTLEFT as _,
+      HitTestArea::Resize(ResizeDirection::East) => HTRIGHT as _,
+      HitTestArea::Resize(ResizeDirection::North) => HTTOP as _,
+      HitTestArea::Resize(ResizeDirection::South) => HTBOTTOM as _,
+      HitTestArea::Resize(ResizeDirection::NorthWest) => HTTOPLEFT as _,
+      HitTestArea::Resize(ResizeDirection::NorthEast) => HTTOPRIGHT as _,
+      HitTestArea::Resize(ResizeDirection::SouthWest) => HTBOTTOMLEFT as _,
+      HitTestArea::Resize(ResizeDirection::SouthEast) => HTBOTTOMRIGHT as _,
+      HitTestArea::Drag => HTCAPTION as _,
+      _ => HTTRANSPARENT,
     }
   }
 
   const CLASS_NAME: PCWSTR = w!("TAURI_DRAG_RESIZE_BORDERS");
   const WINDOW_NAME: PCWSTR = w!("TAURI_DRAG_RESIZE_WINDOW");
 
-  pub fn attach_resize_handler(hwnd: isize) {
+  pub fn attach_resize_handler(hwnd: isize, hit_test: Arc<Mutex<HitTest>>) {
     let parent = HWND(hwnd as _);
 
     // return early if we already attached
@@ -155,6 +99,9 @@ mod windows {
     };
 
     unsafe {
+      // the child window owns a reference to the hit-test areas, released on WM_NCDESTROY
+      SetWindowLongPtrW(drag_window, GWLP_USERDATA, Arc::into_raw(hit_test) as _);
+
       set_drag_hwnd_rgn(drag_window, width, height);
 
       let _ = SetWindowPos(
@@ -229,86 +176,28 @@ mod windows {
   ) -> LRESULT {
     match msg {
       WM_NCHITTEST => {
-        let Ok(parent) = GetParent(child) else {
+        let Some(area) = area_at(child, lparam) else {
           return DefWindowProcW(child, msg, wparam, lparam);
         };
-        let style = GetWindowLongPtrW(parent, GWL_STYLE);
-        let style = WINDOW_STYLE(style as u32);
 
-        let is_resizable = (style & WS_SIZEBOX).0 != 0;
-        if !is_resizable {
-          return DefWindowProcW(child, msg, wparam, lparam);
-        }
-
-        let mut rect = RECT::default();
-        if GetWindowRect(child, &mut rect).is_err() {
-          return DefWindowProcW(child, msg, wparam, lparam);
-        }
-
-        let (cx, cy) = (GET_X_LPARAM(lparam) as i32, GET_Y_LPARAM(lparam) as i32);
-
-        let padded_border = GetSystemMetrics(SM_CXPADDEDBORDER);
-        let border_x = GetSystemMetrics(SM_CXFRAME) + padded_border;
-        let border_y = GetSystemMetrics(SM_CYFRAME) + padded_border;
-
-        let res = hit_test(
-          rect.left,
-          rect.top,
-          rect.right,
-          rect.bottom,
-          cx,
-          cy,
-          border_x,
-          border_y,
-        );
-
-        return LRESULT(res.to_win32() as _);
+        return LRESULT(to_win32(area) as _);
       }
 
       WM_NCLBUTTONDOWN => {
-        let Ok(parent) = GetParent(child) else {
+        let (Ok(parent), Some(area)) = (GetParent(child), area_at(child, lparam)) else {
           return DefWindowProcW(child, msg, wparam, lparam);
         };
-        let style = GetWindowLongPtrW(parent, GWL_STYLE);
-        let style = WINDOW_STYLE(style as u32);
-
-        let is_resizable = (style & WS_SIZEBOX).0 != 0;
-        if !is_resizable {
-          return DefWindowProcW(child, msg, wparam, lparam);
-        }
-
-        let mut rect = RECT::default();
-        if GetWindowRect(child, &mut rect).is_err() {
-          return DefWindowProcW(child, msg, wparam, lparam);
-        }
 
-        let (cx, cy) = (GET_X_LPARAM(lparam) as i32, GET_Y_LPARAM(lparam) as i32);
-
-        let padded_border = GetSystemMetrics(SM_CXPADDEDBORDER);
-        let border_x = GetSystemMetrics(SM_CXFRAME) + padded_border;
-        let border_y = GetSystemMetrics(SM_CYFRAME) + padded_border;
-
-        let res = hit_test(
-          rect.left,
-          rect.top,
-          rect.right,
-          rect.bottom,
-          cx,
-          cy,
-          border_x,
-          border_y,
-        );
-
-        if res != HitTestResult::NoWhere {
+        if matches!(area, HitTestArea::Resize(_) | HitTestArea::Drag) {
           let points = POINTS {
-            x: cx as i16,
-            y: cy as i16,
+            x: GET_X_LPARAM(lparam),
+            y: GET_Y_LPARAM(lparam),
           };
 
           let _ = PostMessageW(
             parent,
             WM_NCLBUTTONDOWN,
-            WPARAM(res.to_win32() as _),
+            WPARAM(to_win32(area) as _),
             LPARAM(&points as *const _ as _),
           );
         }
@@ -316,6 +205,13 @@ mod windows {
         return LRESULT(0);
       }
 
+      WM_NCDESTROY => {
+        let hit_test = SetWindowLongPtrW(child, GWLP_USERDATA, 0) as *const Mutex<HitTest>;
+        if !hit_test.is_null() {
+          drop(Arc::from_raw(hit_test));
+        }
+      }
+
       _ => {}
     }
 
@@ -333,10 +229,80 @@ mod windows {
     let _ = unsafe { DestroyWindow(child) };
   }
 
-  unsafe fn set_drag_hwnd_rgn(hwnd: HWND, width: i32, height: i32) {
+  /// Updates the resize borders after the hit-test areas changed.
+  pub fn update_resize_handler(hwnd: isize) {
+    let parent = HWND(hwnd as _);
+
+    let Ok(child) = (unsafe { FindWindowExW(parent, HWND::default(), CLASS_NAME, WINDOW_NAME) })
+    else {
+      return;
+    };
+
+    let mut rect = RECT::default();
+    if unsafe { GetClientRect(parent, &mut rect) }.is_ok() {
+      unsafe { set_drag_hwnd_rgn(child, rect.right - rect.left, rect.bottom - rect.top) };
+    }
+  }
+
+  /// The hit-test areas of the window, see [`attach_resize_handler`].
+  unsafe fn hit_test(child: HWND) -> HitTest {
+    let hit_test = GetWindowLongPtrW(child, GWLP_USERDATA) as *const Mutex<HitTest>;
+    if hit_test.is_null() {
+      HitTest::default()
+    } else {
+      (*hit_test).lock().unwrap().clone()
+    }
+  }
+
+  unsafe fn scale_factor(hwnd: HWND) -> f64 {
+    GetDpiForWindow(hwnd) as f64 / 96.
+  }
+
+  /// The width of the resize borders in physical pixels.
+  unsafe fn resize_border(child: HWND) -> (i32, i32) {
+    if let Some(border) = hit_test(child).resize_border {
+      let border = (border * scale_factor(child)) as i32;
+      return (border, border);
+    }
+
     let padded_border = GetSystemMetrics(SM_CXPADDEDBORDER);
-    let border_x = GetSystemMetrics(SM_CXFRAME) + padded_border;
-    let border_y = GetSystemMetrics(SM_CYFRAME) + padded_border;
+    (
+      GetSystemMetrics(SM_CXFRAME) + padded_border,
+      GetSystemMetrics(SM_CYFRAME) + padded_border,
+    )
+  }
+
+  /// The area under the cursor position of a mouse message, or `None` if the window is not resizable.
+  unsafe fn area_at(child: HWND, lparam: LPARAM) -> Option<HitTestArea> {
+    let parent = GetParent(child).ok()?;
+    let style = GetWindowLongPtrW(parent, GWL_STYLE);
+    let style = WINDOW_STYLE(style as u32);
+
+    let is_resizable = (style & WS_SIZEBOX).0 != 0;
+    if !is_resizable {
+      return None;
+    }
+
+    let mut rect = RECT::default();
+    GetWindowRect(child, &mut rect).ok()?;
+
+    let (cx, cy) = (GET_X_LPARAM(lparam) as i32, GET_Y_LPARAM(lparam) as i32);
+    let scale_factor = scale_factor(child);
+    let (border, _) = resize_border(child);
+
+    let position = LogicalPosition::new(
+      (cx - rect.left) as f64 / scale_factor,
+      (cy - rect.top) as f64 / scale_factor,
+    );
+    let size = LogicalSize::new(
+      (rect.right - rect.left) as f64 / scale_factor,
+      (rect.bottom - rect.top) as f64 / scale_factor,
+    );
+    Some(hit_test(child).area(position, size, Some(border as f64 / scale_factor)))
+  }
+
+  unsafe fn set_drag_hwnd_rgn(hwnd: HWND, width: i32, height: i32) {
+    let (border_x, border_y) = resize_border(hwnd);
 
     let hrgn1 = CreateRectRgn(0, 0, width, height);
     let hrgn2 = CreateRectRgn(border_x, border_y, width - border_x, height - border_y);
@@ -370,32 +336,71 @@ mod windows {
 
 #[cfg(not(windows))]
 mod gtk {
-  use super::{hit_test, HitTestResult};
+  use std::sync::{Arc, Mutex};
+
+  use gtk::{
+    gdk::{prelude::*, EventType, WindowEdge},
+    glib::Propagation,
+    prelude::*,
+  };
+  use tauri_runtime::{
+    dpi::{LogicalPosition, LogicalSize},
+    window::{HitTest, HitTestArea},
+    ResizeDirection,
+  };
 
   const BORDERLESS_RESIZE_INSET: i32 = 5;
 
-  impl HitTestResult {
-    fn to_gtk_edge(self) -> gtk::gdk::WindowEdge {
-      match self {
-        HitTestResult::Client | HitTestResult::NoWhere => gtk::gdk::WindowEdge::__Unknown(0),
-        HitTestResult::Left => gtk::gdk::WindowEdge::West,
-        HitTestResult::Right => gtk::gdk::WindowEdge::East,
-        HitTestResult::Top => gtk::gdk::WindowEdge::North,
-        HitTestResult::Bottom => gtk::gdk::WindowEdge::South,
-        HitTestResult::TopLeft => gtk::gdk::WindowEdge::NorthWest,
-        HitTestResult::TopRight => gtk::gdk::WindowEdge::NorthEast,
-        HitTestResult::BottomLeft => gtk::gdk::WindowEdge::SouthWest,
-        HitTestResult::BottomRight => gtk::gdk::WindowEdge::SouthEast,
-      }
+  fn to_gtk_edge(direction: ResizeDirection) -> WindowEdge {
+    match direction {
+      ResizeDirection::West => WindowEdge::West,
+      ResizeDirection::East => WindowEdge::East,
+      ResizeDirection::North => WindowEdge::North,
+      ResizeDirection::South => WindowEdge::South,
+      ResizeDirection::NorthWest => WindowEdge::NorthWest,
+      ResizeDirection::NorthEast => WindowEdge::NorthEast,
+      ResizeDirection::SouthWest => WindowEdge::SouthWest,
+      ResizeDirection::SouthEast => WindowEdge::SouthEast,
     }
   }
 
-  pub fn attach_resize_handler(webview: &wry::WebView) {
-    use gtk::{
-      gdk::{prelude::*, WindowEdge},
-      glib::Propagation,
-      prelude::*,
-    };
+  fn toggle_maximized(window: &gtk::Window) {
+    if window.is_maximized() {
+      window.unmaximize();
+    } else {
+      window.maximize();
+    }
+  }
+
+  /// The undecorated window of the webview and the area under the given root coordinates.
+  fn area_at(
+    webview: &webkit2gtk::WebView,
+    hit_test: &Mutex<HitTest>,
+    (root_x, root_y): (f64, f64),
+  ) -> Option<(gtk::Window, gtk::gdk::Window, HitTestArea)> {
+    // This one should be GtkBox
+    let window = webview.parent().and_then(|w| w.parent())?;
+    // Safe to unwrap unless this is not from tao
+    let window: gtk::Window = window.downcast().unwrap();
+    if window.is_decorated() {
+      return None;
+    }
+    let gdk_window = window.window()?;
+
+    let (window_x, window_y) = gdk_window.position();
+    let position = LogicalPosition::new(root_x - window_x as f64, root_y - window_y as f64);
+    let size = LogicalSize::new(gdk_window.width() as f64, gdk_window.height() as f64);
+    let resize_border = (window.is_resizable() && !window.is_maximized())
+      .then(|| (gdk_window.scale_factor() * BORDERLESS_RESIZE_INSET) as f64);
+
+    // the handler must not run while the lock is held so it can update the hit-test areas
+    let hit_test = hit_test.lock().unwrap().clone();
+    let area = hit_test.area(position, size, resize_border);
+
+    Some((window, gdk_window, area))
+  }
+
+  pub fn attach_resize_handler(webview: &wry::WebView, hit_test: Arc<Mutex<HitTest>>) {
     use wry::WebViewExtUnix;
 
     let webview = webview.webview();
@@ -406,87 +411,70 @@ mod gtk {
         | gtk::gdk::EventMask::TOUCH_MASK,
     );
 
+    let hit_test_ = hit_test.clone();
     webview.connect_button_press_event(
       move |webview: &webkit2gtk::WebView, event: &gtk::gdk::EventButton| {
-        if event.button() == 1 {
-          // This one should be GtkBox
-          if let Some(window) = webview.parent().and_then(|w| w.parent()) {
-            // Safe to unwrap unless this is not from tao
-            let window: gtk::Window = window.downcast().unwrap();
-            if !window.is_decorated() && window.is_resizable() && !window.is_maximized() {
-              if let Some(window) = window.window() {
-                let (root_x, root_y) = event.root();
-                let (window_x, window_y) = window.position();
-                let (client_x, client_y) = (root_x - window_x as f64, root_y - window_y as f64);
-                let border = window.scale_factor() * BORDERLESS_RESIZE_INSET;
-                let edge = hit_test(
-                  0.0,
-                  0.0,
-                  window.width() as f64,
-                  window.height() as f64,
-                  client_x,
-                  client_y,
-                  border as _,
-                  border as _,
-                )
-                .to_gtk_edge();
-
-                // we ignore the `__Unknown` variant so the webview receives the click correctly if it is not on the edges.
-                match edge {
-                  WindowEdge::__Unknown(_) => (),
-                  _ => {
-                    window.begin_resize_drag(edge, 1, root_x as i32, root_y as i32, event.time())
-                  }
-                }
-              }
+        if event.button() != 1 {
+          return Propagation::Proceed;
+        }
+
+        let (root_x, root_y) = event.root();
+        let Some((window, gdk_window, area)) = area_at(webview, &hit_test_, (root_x, root_y))
+        else {
+          return Propagation::Proceed;
+        };
+
+        match area {
+          HitTestArea::Client => Propagation::Proceed,
+          // the webview still receives the click so it resets its cursor
+          HitTestArea::Resize(direction) => {
+            gdk_window.begin_resize_drag(
+              to_gtk_edge(direction),
+              1,
+              root_x as i32,
+              root_y as i32,
+              event.time(),
+            );
+            Propagation::Proceed
+          }
+          HitTestArea::Drag => {
+            if event.event_type() == EventType::DoubleButtonPress {
+              toggle_maximized(&window);
+            } else {
+              gdk_window.begin_move_drag(1, root_x as i32, root_y as i32, event.time());
             }
+            Propagation::Stop
+          }
+          HitTestArea::Minimize => {
+            window.iconify();
+            Propagation::Stop
+          }
+          HitTestArea::Maximize => {
+            toggle_maximized(&window);
+            Propagation::Stop
+          }
+          HitTestArea::Close => {
+            window.close();
+            Propagation::Stop
           }
         }
-
-        Propagation::Proceed
       },
     );
 
     webview.connect_touch_event(
       move |webview: &webkit2gtk::WebView, event: &gtk::gdk::Event| {
-        // This one should be GtkBox
-        if let Some(window) = webview.parent().and_then(|w| w.parent()) {
-          // Safe to unwrap unless this is not from tao
-          let window: gtk::Window = window.downcast().unwrap();
-          if !window.is_decorated() && window.is_resizable() && !window.is_maximized() {
-            if let Some(window) = window.window() {
-              if let Some((root_x, root_y)) = event.root_coords() {
-                if let Some(device) = event.device() {
-                  let (window_x, window_y) = window.position();
-                  let (client_x, client_y) = (root_x - window_x as f64, root_y - window_y as f64);
-                  let border = window.scale_factor() * BORDERLESS_RESIZE_INSET;
-                  let edge = hit_test(
-                    0.0,
-                    0.0,
-                    window.width() as f64,
-                    window.height() as f64,
-                    client_x,
-                    client_y,
-                    border as _,
-                    border as _,
-                  )
-                  .to_gtk_edge();
-
-                  // we ignore the `__Unknown` variant so the window receives the click correctly if it is not on the edges.
-                  match edge {
-                    WindowEdge::__Unknown(_) => (),
-                    _ => window.begin_resize_drag_for_device(
-                      edge,
-                      &device,
-                      0,
-                      root_x as i32,
-                      root_y as i32,
-                      event.time(),
-                    ),
-                  }
-                }
-              }
-            }
+        if let (Some((root_x, root_y)), Some(device)) = (event.root_coords(), event.device()) {
+          if let Some((_, gdk_window, HitTestArea::Resize(direction))) =
+            area_at(webview, &hit_test, (root_x, root_y))
+          {
+            gdk_window.begin_resize_drag_for_device(
+              to_gtk_edge(direction),
+              &device,
+              0,
+              root_x as i32,
+              root_y as i32,
+              event.time(),
+            );
           }
         }
 
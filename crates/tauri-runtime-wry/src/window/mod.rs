@@ -86,3 +86,90 @@ pub fn calculate_window_center_position(
   let y = (screen_size.height as i32 - window_size.height as i32) / 2 + monitor_pos.y;
   tao::dpi::PhysicalPosition::new(x, y)
 }
+
+/// The area of the monitor not covered by the taskbar, dock or menu bar.
+pub fn monitor_work_area(
+  monitor: &tao::monitor::MonitorHandle,
+) -> (tao::dpi::PhysicalPosition<i32>, tao::dpi::PhysicalSize<u32>) {
+  #[cfg(windows)]
+  {
+    use ::windows::Win32::Graphics::Gdi::{GetMonitorInfoW, HMONITOR, MONITORINFO};
+    use tao::platform::windows::MonitorHandleExtWindows;
+
+    let mut monitor_info = MONITORINFO {
+      cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+      ..Default::default()
+    };
+    let status = unsafe { GetMonitorInfoW(HMONITOR(monitor.hmonitor() as _), &mut monitor_info) };
+    if status.into() {
+      let work = monitor_info.rcWork;
+      return (
+        tao::dpi::PhysicalPosition::new(work.left, work.top),
+        tao::dpi::PhysicalSize::new(
+          (work.right - work.left) as u32,
+          (work.bottom - work.top) as u32,
+        ),
+      );
+    }
+  }
+
+  #[cfg(target_os = "macos")]
+  {
+    use objc2_app_kit::NSScreen;
+    use tao::platform::macos::MonitorHandleExtMacOS;
+
+    if let Some(screen) = monitor.ns_screen() {
+      // SAFETY: tao returns a valid NSScreen pointer for the monitor
+      let screen = unsafe { &*(screen as *const NSScreen) };
+      let frame = screen.frame();
+      let visible = screen.visibleFrame();
+      let scale_factor = monitor.scale_factor();
+      let position = monitor.position();
+      // the AppKit coordinates start at the bottom-left corner of the screen
+      let top = (frame.origin.y + frame.size.height) - (visible.origin.y + visible.size.height);
+      return (
+        tao::dpi::PhysicalPosition::new(
+          position.x + ((visible.origin.x - frame.origin.x) * scale_factor) as i32,
+          position.y + (top * scale_factor) as i32,
+        ),
+        tao::dpi::LogicalSize::new(visible.size.width, visible.size.height)
+          .to_physical(scale_factor),
+      );
+    }
+  }
+
+  #[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+  ))]
+  {
+    use gtk::gdk::{prelude::MonitorExt, Display};
+
+    // tao doesn't expose its GDK monitor, find it from the monitor position
+    let position = monitor.position();
+    let gdk_monitor = Display::default().and_then(|display| {
+      (0..display.n_monitors())
+        .filter_map(|i| display.monitor(i))
+        .find(|m| {
+          let geometry = m.geometry();
+          tao::dpi::LogicalPosition::new(geometry.x(), geometry.y())
+            .to_physical::<i32>(m.scale_factor() as f64)
+            == position
+        })
+    });
+    if let Some(gdk_monitor) = gdk_monitor {
+      let work_area = gdk_monitor.workarea();
+      let scale_factor = gdk_monitor.scale_factor() as f64;
+      return (
+        tao::dpi::LogicalPosition::new(work_area.x(), work_area.y()).to_physical(scale_factor),
+        tao::dpi::LogicalSize::new(work_area.width() as u32, work_area.height() as u32)
+          .to_physical(scale_factor),
+      );
+    }
+  }
+
+  (monitor.position(), monitor.size())
+}
@@ -30,7 +30,7 @@ pub mod window;
 use dpi::{PhysicalPosition, PhysicalSize, Position, Size};
 use monitor::Monitor;
 use window::{
-  CursorIcon, DetachedWindow, PendingWindow, RawWindow, WebviewEvent, WindowEvent,
+  CursorIcon, DetachedWindow, HitTest, PendingWindow, RawWindow, WebviewEvent, WindowEvent,
   WindowSizeConstraints,
 };
 use window::{WindowBuilder, WindowId};
@@ -238,6 +238,11 @@ pub enum RunEvent<T: UserEvent> {
   ///
   /// This event is useful as a place to put your code that should be run after all state-changing events have been handled and you want to do stuff (updating state, performing calculations, etc) that happens as the “main body” of your event loop.
   MainEventsCleared,
+  /// Emitted when a monitor is connected, disconnected or rearranged, or when its work area or scale factor changes.
+  MonitorsChanged {
+    /// The available monitors.
+    monitors: Vec<Monitor>,
+  },
   /// Emitted when the user wants to open the specified resource with the app.
   #[cfg(any(target_os = "macos", target_os = "ios"))]
   Opened { urls: Vec<url::Url> },
@@ -809,6 +814,14 @@ pub trait WindowDispatch<T: UserEvent>: Debug + Clone + Send + Sync + Sized + 's
   /// Starts resize-dragging the window.
   fn start_resize_dragging(&self, direction: ResizeDirection) -> Result<()>;
 
+  /// Sets the drag, caption button and resize areas of the undecorated window.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows**: Only the resize border is handled natively, use the `data-tauri-drag-region` attribute for the other areas.
+  /// - **macOS / Android / iOS**: Unsupported.
+  fn set_hit_test(&self, hit_test: HitTest) -> Result<()>;
+
   /// Sets the taskbar progress state.
   ///
   /// ## Platform-specific
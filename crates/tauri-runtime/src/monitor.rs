@@ -2,10 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
+use serde::Serialize;
+
 use crate::dpi::{PhysicalPosition, PhysicalSize};
 
 /// Monitor descriptor.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct Monitor {
   /// A human-readable name of the monitor.
   /// `None` if the monitor doesn't exist anymore.
@@ -14,6 +17,43 @@ pub struct Monitor {
   pub size: PhysicalSize<u32>,
   /// The top-left corner position of the monitor relative to the larger full screen area.
   pub position: PhysicalPosition<i32>,
+  /// The area of the monitor not covered by the taskbar, dock or menu bar,
+  /// relative to the larger full screen area.
+  pub work_area: PhysicalRect<i32, u32>,
   /// Returns the scale factor that can be used to map logical pixels to physical pixels, and vice versa.
   pub scale_factor: f64,
 }
+
+impl Monitor {
+  /// Creates a monitor descriptor whose work area is the whole monitor, see [`Self::with_work_area`].
+  pub fn new(
+    name: Option<String>,
+    size: PhysicalSize<u32>,
+    position: PhysicalPosition<i32>,
+    scale_factor: f64,
+  ) -> Self {
+    Self {
+      name,
+      size,
+      position,
+      work_area: PhysicalRect { position, size },
+      scale_factor,
+    }
+  }
+
+  /// Sets the area of the monitor not covered by the taskbar, dock or menu bar.
+  #[must_use]
+  pub fn with_work_area(mut self, work_area: PhysicalRect<i32, u32>) -> Self {
+    self.work_area = work_area;
+    self
+  }
+}
+
+/// A rectangular region in physical pixels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PhysicalRect<P, S> {
+  /// The top-left corner position of the rectangle.
+  pub position: PhysicalPosition<P>,
+  /// The size of the rectangle.
+  pub size: PhysicalSize<S>,
+}
@@ -4,10 +4,10 @@
 
 //! A layer between raw [`Runtime`] webviews and Tauri.
 //!
-use crate::{window::is_label_valid, Rect, Runtime, UserEvent};
+use crate::{dpi::Position, window::is_label_valid, Rect, Runtime, UserEvent};
 
 use http::Request;
-use tauri_utils::config::{WebviewUrl, WindowConfig, WindowEffectsConfig};
+use tauri_utils::config::{WebviewPermission, WebviewUrl, WindowConfig, WindowEffectsConfig};
 use url::Url;
 
 use std::{
@@ -28,10 +28,16 @@ type WebResourceRequestHandler =
 
 type NavigationHandler = dyn Fn(&Url) -> bool + Send;
 
+type NewWindowHandler = dyn Fn(Url) -> bool + Send;
+
 type OnPageLoadHandler = dyn Fn(Url, PageLoadEvent) + Send;
 
 type DownloadHandler = dyn Fn(DownloadEvent) -> bool + Send + Sync;
 
+type PermissionRequestHandler = dyn Fn(PermissionRequest) + Send + Sync;
+
+type ContextMenuHandler = dyn Fn(ContextMenuRequest) -> bool + Send + Sync;
+
 /// Download event.
 pub enum DownloadEvent<'a> {
   /// Download requested.
@@ -54,6 +60,57 @@ pub enum DownloadEvent<'a> {
   },
 }
 
+/// A permission requested by the webview content.
+pub struct PermissionRequest {
+  /// The requested permissions.
+  pub permissions: Vec<WebviewPermission>,
+  /// The URL of the page requesting the permissions.
+  pub origin: Url,
+  /// Answers the request.
+  pub responder: PermissionResponder,
+}
+
+/// Answers a [`PermissionRequest`], keeping the default behavior of the webview if dropped without answering.
+pub struct PermissionResponder(Option<Box<dyn FnOnce(Option<bool>) + Send>>);
+
+impl PermissionResponder {
+  /// Creates a responder calling the given closure with whether the permissions are granted,
+  /// or `None` to keep the default behavior of the webview.
+  pub fn new<F: FnOnce(Option<bool>) + Send + 'static>(f: F) -> Self {
+    Self(Some(Box::new(f)))
+  }
+
+  /// Grants or denies the requested permissions.
+  pub fn respond(mut self, allow: bool) {
+    if let Some(f) = self.0.take() {
+      f(Some(allow));
+    }
+  }
+}
+
+impl Drop for PermissionResponder {
+  fn drop(&mut self) {
+    if let Some(f) = self.0.take() {
+      f(None);
+    }
+  }
+}
+
+/// A context menu request of the webview content.
+#[derive(Debug, Clone)]
+pub struct ContextMenuRequest {
+  /// The position of the cursor, relative to the webview.
+  pub position: Position,
+  /// The URL of the link the menu was requested on.
+  pub link_url: Option<Url>,
+  /// The source URL of the image the menu was requested on.
+  pub image_url: Option<Url>,
+  /// The selected text, `None` on Linux.
+  pub selection_text: Option<String>,
+  /// Whether the menu was requested on an editable element.
+  pub is_editable: bool,
+}
+
 #[cfg(target_os = "android")]
 pub struct CreationContext<'a, 'b> {
   pub env: &'a mut jni::JNIEnv<'b>,
@@ -86,6 +143,9 @@ pub struct PendingWebview<T: UserEvent, R: Runtime<T>> {
   /// A handler to decide if incoming url is allowed to navigate.
   pub navigation_handler: Option<Box<NavigationHandler>>,
 
+  /// A handler to decide if the webview content is allowed to open a new window for the url.
+  pub new_window_handler: Option<Box<NewWindowHandler>>,
+
   /// The resolved URL to load on the webview.
   pub url: String,
 
@@ -99,6 +159,11 @@ pub struct PendingWebview<T: UserEvent, R: Runtime<T>> {
   pub on_page_load_handler: Option<Box<OnPageLoadHandler>>,
 
   pub download_handler: Option<Arc<DownloadHandler>>,
+
+  pub permission_request_handler: Option<Arc<PermissionRequestHandler>>,
+
+  /// A handler to decide if the native context menu is shown.
+  pub context_menu_handler: Option<Box<ContextMenuHandler>>,
 }
 
 impl<T: UserEvent, R: Runtime<T>> PendingWebview<T, R> {
@@ -117,12 +182,15 @@ impl<T: UserEvent, R: Runtime<T>> PendingWebview<T, R> {
         label,
         ipc_handler: None,
         navigation_handler: None,
+        new_window_handler: None,
         url: "tauri://localhost".to_string(),
         #[cfg(target_os = "android")]
         on_webview_created: None,
         web_resource_request_handler: None,
         on_page_load_handler: None,
         download_handler: None,
+        permission_request_handler: None,
+        context_menu_handler: None,
       })
     }
   }
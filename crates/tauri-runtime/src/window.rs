@@ -6,7 +6,7 @@
 
 use crate::{
   webview::{DetachedWebview, PendingWebview},
-  Icon, Runtime, UserEvent, WindowDispatch,
+  Icon, ResizeDirection, Runtime, UserEvent, WindowDispatch,
 };
 
 use dpi::PixelUnit;
@@ -16,10 +16,11 @@ use tauri_utils::{config::WindowConfig, Theme};
 use windows::Win32::Foundation::HWND;
 
 use std::{
+  fmt,
   hash::{Hash, Hasher},
   marker::PhantomData,
   path::PathBuf,
-  sync::mpsc::Sender,
+  sync::{mpsc::Sender, Arc},
 };
 
 /// An event from a window.
@@ -565,3 +566,259 @@ pub struct RawWindow<'a> {
   pub default_vbox: Option<&'a gtk::Box>,
   pub _marker: &'a PhantomData<()>,
 }
+
+/// An area of an undecorated window, see [`HitTest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitTestArea {
+  /// The window content, receiving the input.
+  Client,
+  /// Moves the window when dragged and toggles its maximized state when double clicked.
+  Drag,
+  /// Minimizes the window when clicked.
+  Minimize,
+  /// Toggles the maximized state of the window when clicked.
+  Maximize,
+  /// Closes the window when clicked.
+  Close,
+  /// Resizes the window in the given direction when dragged.
+  Resize(ResizeDirection),
+}
+
+/// A region of an undecorated window, in logical pixels relative to the window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HitTestRegion {
+  /// The area of the region.
+  pub area: HitTestArea,
+  /// The top-left corner position of the region.
+  pub position: dpi::LogicalPosition<f64>,
+  /// The size of the region.
+  pub size: dpi::LogicalSize<f64>,
+}
+
+impl HitTestRegion {
+  fn contains(&self, position: dpi::LogicalPosition<f64>) -> bool {
+    position.x >= self.position.x
+      && position.x < self.position.x + self.size.width
+      && position.y >= self.position.y
+      && position.y < self.position.y + self.size.height
+  }
+}
+
+/// A hit-test callback, returning the area under the cursor position in logical pixels relative to the window
+/// or `None` to fall back to the resize border and the regions.
+pub type HitTestHandler = dyn Fn(dpi::LogicalPosition<f64>) -> Option<HitTestArea> + Send + Sync;
+
+/// Describes the drag, caption button and resize areas of an undecorated window.
+///
+/// The area under the cursor is the one returned by the [handler](Self::handler),
+/// then the resize border of resizable windows, then the last [region](Self::region) containing the cursor.
+/// The rest of the window is [`HitTestArea::Client`].
+#[derive(Clone, Default)]
+pub struct HitTest {
+  /// The width of the resize border in logical pixels, or `None` for the platform default.
+  pub resize_border: Option<f64>,
+  /// The regions of the window, the last ones taking precedence.
+  pub regions: Vec<HitTestRegion>,
+  /// The hit-test callback.
+  pub handler: Option<Arc<HitTestHandler>>,
+}
+
+impl fmt::Debug for HitTest {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("HitTest")
+      .field("resize_border", &self.resize_border)
+      .field("regions", &self.regions)
+      .field("handler", &self.handler.is_some())
+      .finish()
+  }
+}
+
+impl HitTest {
+  /// Creates an empty hit-test description, only resizing the window from its borders.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the width of the resize border in logical pixels.
+  #[must_use]
+  pub fn resize_border(mut self, width: f64) -> Self {
+    self.resize_border.replace(width);
+    self
+  }
+
+  /// Adds a region, in logical pixels relative to the window.
+  #[must_use]
+  pub fn region<P: Into<dpi::LogicalPosition<f64>>, S: Into<dpi::LogicalSize<f64>>>(
+    mut self,
+    area: HitTestArea,
+    position: P,
+    size: S,
+  ) -> Self {
+    self.regions.push(HitTestRegion {
+      area,
+      position: position.into(),
+      size: size.into(),
+    });
+    self
+  }
+
+  /// Sets the hit-test callback.
+  #[must_use]
+  pub fn handler<
+    F: Fn(dpi::LogicalPosition<f64>) -> Option<HitTestArea> + Send + Sync + 'static,
+  >(
+    mut self,
+    handler: F,
+  ) -> Self {
+    self.handler.replace(Arc::new(handler));
+    self
+  }
+
+  /// Returns the area at the given position of a window with the given size, in logical pixels.
+  ///
+  /// `resize_border` is the width of the resize border when the [`Self::resize_border`] is not set,
+  /// or `None` if the window cannot be resized.
+  pub fn area(
+    &self,
+    position: dpi::LogicalPosition<f64>,
+    size: dpi::LogicalSize<f64>,
+    resize_border: Option<f64>,
+  ) -> HitTestArea {
+    if let Some(area) = self.handler.as_ref().and_then(|handler| handler(position)) {
+      return area;
+    }
+
+    if let Some(border) = resize_border.map(|border| self.resize_border.unwrap_or(border)) {
+      let left = position.x < border;
+      let right = position.x >= size.width - border;
+      let top = position.y < border;
+      let bottom = position.y >= size.height - border;
+      let direction = match (left, right, top, bottom) {
+        (true, _, true, _) => Some(ResizeDirection::NorthWest),
+        (_, true, true, _) => Some(ResizeDirection::NorthEast),
+        (true, _, _, true) => Some(ResizeDirection::SouthWest),
+        (_, true, _, true) => Some(ResizeDirection::SouthEast),
+        (true, _, _, _) => Some(ResizeDirection::West),
+        (_, true, _, _) => Some(ResizeDirection::East),
+        (_, _, true, _) => Some(ResizeDirection::North),
+        (_, _, _, true) => Some(ResizeDirection::South),
+        _ => None,
+      };
+      if let Some(direction) = direction {
+        return HitTestArea::Resize(direction);
+      }
+    }
+
+    self
+      .regions
+      .iter()
+      .rev()
+      .find(|region| region.contains(position))
+      .map(|region| region.area)
+      .unwrap_or(HitTestArea::Client)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{HitTest, HitTestArea};
+  use crate::ResizeDirection;
+
+  const SIZE: dpi::LogicalSize<f64> = dpi::LogicalSize::new(200.0, 100.0);
+
+  fn area(hit_test: &HitTest, x: f64, y: f64, resize_border: Option<f64>) -> HitTestArea {
+    hit_test.area(dpi::LogicalPosition::new(x, y), SIZE, resize_border)
+  }
+
+  #[test]
+  fn resize_border_corners_and_edges() {
+    let hit_test = HitTest::new();
+    let resize = |x, y| area(&hit_test, x, y, Some(5.0));
+
+    assert_eq!(
+      resize(0.0, 0.0),
+      HitTestArea::Resize(ResizeDirection::NorthWest)
+    );
+    assert_eq!(
+      resize(199.0, 0.0),
+      HitTestArea::Resize(ResizeDirection::NorthEast)
+    );
+    assert_eq!(
+      resize(0.0, 99.0),
+      HitTestArea::Resize(ResizeDirection::SouthWest)
+    );
+    assert_eq!(
+      resize(199.0, 99.0),
+      HitTestArea::Resize(ResizeDirection::SouthEast)
+    );
+    assert_eq!(
+      resize(0.0, 50.0),
+      HitTestArea::Resize(ResizeDirection::West)
+    );
+    assert_eq!(
+      resize(199.0, 50.0),
+      HitTestArea::Resize(ResizeDirection::East)
+    );
+    assert_eq!(
+      resize(100.0, 0.0),
+      HitTestArea::Resize(ResizeDirection::North)
+    );
+    assert_eq!(
+      resize(100.0, 99.0),
+      HitTestArea::Resize(ResizeDirection::South)
+    );
+    assert_eq!(resize(5.0, 5.0), HitTestArea::Client);
+    assert_eq!(resize(194.9, 94.9), HitTestArea::Client);
+  }
+
+  #[test]
+  fn resize_border_overrides_the_platform_default() {
+    let hit_test = HitTest::new().resize_border(10.0);
+
+    assert_eq!(
+      area(&hit_test, 8.0, 50.0, Some(5.0)),
+      HitTestArea::Resize(ResizeDirection::West)
+    );
+    assert_eq!(area(&hit_test, 12.0, 50.0, Some(20.0)), HitTestArea::Client);
+  }
+
+  #[test]
+  fn no_resize_border_for_non_resizable_windows() {
+    let hit_test = HitTest::new().resize_border(10.0);
+
+    assert_eq!(area(&hit_test, 0.0, 0.0, None), HitTestArea::Client);
+    assert_eq!(area(&hit_test, 199.0, 50.0, None), HitTestArea::Client);
+  }
+
+  #[test]
+  fn last_region_wins() {
+    let hit_test = HitTest::new()
+      .region(HitTestArea::Drag, (0.0, 0.0), (200.0, 30.0))
+      .region(HitTestArea::Close, (170.0, 0.0), (30.0, 30.0));
+
+    assert_eq!(area(&hit_test, 100.0, 15.0, None), HitTestArea::Drag);
+    assert_eq!(area(&hit_test, 180.0, 15.0, None), HitTestArea::Close);
+    assert_eq!(area(&hit_test, 100.0, 50.0, None), HitTestArea::Client);
+    // the resize border takes precedence over the regions
+    assert_eq!(
+      area(&hit_test, 180.0, 2.0, Some(5.0)),
+      HitTestArea::Resize(ResizeDirection::North)
+    );
+  }
+
+  #[test]
+  fn handler_takes_precedence() {
+    let hit_test = HitTest::new()
+      .region(HitTestArea::Drag, (0.0, 0.0), (200.0, 30.0))
+      .handler(|position| (position.x < 30.0).then_some(HitTestArea::Minimize));
+
+    assert_eq!(area(&hit_test, 0.0, 0.0, Some(5.0)), HitTestArea::Minimize);
+    assert_eq!(area(&hit_test, 20.0, 15.0, None), HitTestArea::Minimize);
+    // falls back to the resize border and the regions when the handler returns `None`
+    assert_eq!(
+      area(&hit_test, 100.0, 0.0, Some(5.0)),
+      HitTestArea::Resize(ResizeDirection::North)
+    );
+    assert_eq!(area(&hit_test, 100.0, 15.0, Some(5.0)), HitTestArea::Drag);
+  }
+}
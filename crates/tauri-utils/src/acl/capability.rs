@@ -4,7 +4,7 @@
 
 //! End-user abstraction for selecting permissions a window has access to.
 
-use std::{path::Path, str::FromStr};
+use std::{collections::BTreeMap, path::Path, str::FromStr};
 
 use crate::{acl::Identifier, platform::Target};
 use serde::{
@@ -185,6 +185,17 @@ pub struct Capability {
   /// ```
   #[cfg_attr(feature = "schema", schemars(schema_with = "unique_permission"))]
   pub permissions: Vec<PermissionEntry>,
+  /// Identifiers of capabilities whose permissions are merged into this capability.
+  ///
+  /// Only the permissions are inherited, the windows, webviews, remote URLs and platforms
+  /// are always the ones defined on this capability.
+  /// Extended capabilities can extend other capabilities, but cycles are rejected.
+  ///
+  /// ## Example
+  ///
+  /// `["base-window"]`
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub extends: Vec<String>,
   /// Limit which target platforms this capability applies to.
   ///
   /// By default all platforms are targeted.
@@ -207,6 +218,82 @@ impl Capability {
   }
 }
 
+/// Merges the permissions of the capabilities referenced in [`Capability::extends`] into each capability.
+///
+/// Extended capabilities are looked up in the same map. See [`resolve_extends_with`].
+pub fn resolve_extends(
+  capabilities: &mut BTreeMap<String, Capability>,
+) -> Result<(), super::Error> {
+  resolve_extends_with(capabilities, &BTreeMap::new())
+}
+
+/// Merges the permissions of the capabilities referenced in [`Capability::extends`] into each capability,
+/// looking up extended capabilities in `capabilities` first and then in `available`.
+///
+/// The inherited permissions are prepended to the capability's own permissions, without duplicates,
+/// and [`Capability::extends`] is cleared once resolved.
+pub fn resolve_extends_with(
+  capabilities: &mut BTreeMap<String, Capability>,
+  available: &BTreeMap<String, Capability>,
+) -> Result<(), super::Error> {
+  fn collect<'a>(
+    capability: &'a Capability,
+    lookup: &dyn Fn(&str) -> Option<&'a Capability>,
+    stack: &mut Vec<String>,
+    permissions: &mut Vec<PermissionEntry>,
+  ) -> Result<(), super::Error> {
+    if stack.contains(&capability.identifier) {
+      let mut cycle = stack.clone();
+      cycle.push(capability.identifier.clone());
+      return Err(super::Error::CapabilityExtendsCycle(cycle));
+    }
+    stack.push(capability.identifier.clone());
+
+    for extends in &capability.extends {
+      let extended = lookup(extends).ok_or_else(|| super::Error::UnknownExtendedCapability {
+        capability: capability.identifier.clone(),
+        extends: extends.clone(),
+      })?;
+      collect(extended, lookup, stack, permissions)?;
+    }
+
+    for permission in &capability.permissions {
+      if !permissions.contains(permission) {
+        permissions.push(permission.clone());
+      }
+    }
+
+    stack.pop();
+    Ok(())
+  }
+
+  let mut resolved = Vec::new();
+  {
+    let lookup = |identifier: &str| {
+      capabilities
+        .get(identifier)
+        .or_else(|| available.get(identifier))
+    };
+    for (identifier, capability) in capabilities.iter() {
+      if capability.extends.is_empty() {
+        continue;
+      }
+      let mut permissions = Vec::new();
+      collect(capability, &lookup, &mut Vec::new(), &mut permissions)?;
+      resolved.push((identifier.clone(), permissions));
+    }
+  }
+
+  for (identifier, permissions) in resolved {
+    if let Some(capability) = capabilities.get_mut(&identifier) {
+      capability.permissions = permissions;
+      capability.extends.clear();
+    }
+  }
+
+  Ok(())
+}
+
 #[cfg(feature = "schema")]
 fn unique_permission(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
   use schemars::schema;
@@ -359,6 +446,7 @@ mod build {
       let windows = vec_lit(&self.windows, str_lit);
       let webviews = vec_lit(&self.webviews, str_lit);
       let permissions = vec_lit(&self.permissions, identity);
+      let extends = vec_lit(&self.extends, str_lit);
       let platforms = opt_vec_lit(self.platforms.as_ref(), identity);
 
       literal_struct!(
@@ -371,6 +459,7 @@ mod build {
         windows,
         webviews,
         permissions,
+        extends,
         platforms
       );
     }
@@ -381,7 +470,7 @@ mod build {
 mod tests {
   use crate::acl::{Identifier, Scopes};
 
-  use super::{Capability, CapabilityFile, PermissionEntry};
+  use super::{resolve_extends, resolve_extends_with, Capability, CapabilityFile, PermissionEntry};
 
   #[test]
   fn permission_entry_de() {
@@ -419,6 +508,7 @@ mod tests {
       windows: vec![],
       webviews: vec![],
       permissions: vec![],
+      extends: vec![],
       platforms: None,
     };
     let capability_json = serde_json::to_string(&capability).unwrap();
@@ -443,4 +533,88 @@ mod tests {
       }
     );
   }
+
+  fn capability(identifier: &str, permissions: &[&str], extends: &[&str]) -> Capability {
+    Capability {
+      identifier: identifier.into(),
+      description: "".into(),
+      remote: None,
+      local: true,
+      windows: vec![],
+      webviews: vec![],
+      permissions: permissions
+        .iter()
+        .map(|p| PermissionEntry::PermissionRef(Identifier::try_from(p.to_string()).unwrap()))
+        .collect(),
+      extends: extends.iter().map(|e| e.to_string()).collect(),
+      platforms: None,
+    }
+  }
+
+  #[test]
+  fn capability_extends() {
+    let mut capabilities = [
+      capability("base", &["core:default", "fs:read"], &[]),
+      capability("editor", &["fs:write"], &["base"]),
+      capability("main", &["dialog:open", "fs:read"], &["editor"]),
+    ]
+    .into_iter()
+    .map(|c| (c.identifier.clone(), c))
+    .collect();
+
+    resolve_extends(&mut capabilities).unwrap();
+
+    let main = &capabilities["main"];
+    assert!(main.extends.is_empty());
+    assert_eq!(
+      main
+        .permissions
+        .iter()
+        .map(|p| p.identifier().get())
+        .collect::<Vec<_>>(),
+      vec!["core:default", "fs:read", "fs:write", "dialog:open"]
+    );
+    assert_eq!(capabilities["base"].permissions.len(), 2);
+  }
+
+  #[test]
+  fn capability_extends_available() {
+    let available = [capability("base", &["fs:read"], &[])]
+      .into_iter()
+      .map(|c| (c.identifier.clone(), c))
+      .collect();
+    let mut capabilities = [capability("main", &["fs:write"], &["base"])]
+      .into_iter()
+      .map(|c| (c.identifier.clone(), c))
+      .collect();
+
+    resolve_extends_with(&mut capabilities, &available).unwrap();
+    assert_eq!(capabilities["main"].permissions.len(), 2);
+  }
+
+  #[test]
+  fn capability_extends_errors() {
+    let mut capabilities = [
+      capability("a", &[], &["b"]),
+      capability("b", &[], &["c"]),
+      capability("c", &[], &["a"]),
+    ]
+    .into_iter()
+    .map(|c| (c.identifier.clone(), c))
+    .collect();
+    assert!(matches!(
+      resolve_extends(&mut capabilities),
+      Err(crate::acl::Error::CapabilityExtendsCycle(cycle)) if cycle == ["a", "b", "c", "a"]
+    ));
+
+    let mut capabilities = [capability("main", &[], &["missing"])]
+      .into_iter()
+      .map(|c| (c.identifier.clone(), c))
+      .collect();
+    assert!(matches!(
+      resolve_extends(&mut capabilities),
+      Err(crate::acl::Error::UnknownExtendedCapability { capability, extends })
+        if capability == "main" && extends == "missing"
+    ));
+  }
 }
@@ -0,0 +1,734 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Lints for the app capabilities.
+//!
+//! These checks find capability definitions that are valid but most likely not doing what was intended.
+
+use std::{
+  collections::{BTreeMap, BTreeSet},
+  fmt,
+  str::FromStr,
+};
+
+use super::{
+  capability::{resolve_extends_with, Capability},
+  manifest::Manifest,
+  resolved::get_permissions,
+  Identifier, APP_ACL_KEY,
+};
+use crate::{
+  config::{CapabilityEntry, Config},
+  platform::Target,
+};
+
+/// A check performed on the app capabilities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lint {
+  /// A capability grants a permission but all of its commands are denied for the same origins, windows and webviews.
+  ShadowedPermission,
+  /// A capability targets a window or webview label that the app never creates.
+  UnknownWindow,
+  /// A permission set defined by the app is never referenced by a capability.
+  UnusedPermissionSet,
+}
+
+impl Lint {
+  /// All available lints.
+  pub const ALL: [Lint; 3] = [
+    Self::ShadowedPermission,
+    Self::UnknownWindow,
+    Self::UnusedPermissionSet,
+  ];
+
+  /// The lint name.
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::ShadowedPermission => "shadowed-permission",
+      Self::UnknownWindow => "unknown-window",
+      Self::UnusedPermissionSet => "unused-permission-set",
+    }
+  }
+}
+
+impl fmt::Display for Lint {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+impl FromStr for Lint {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::ALL
+      .into_iter()
+      .find(|lint| lint.name() == s)
+      .ok_or_else(|| {
+        format!(
+          "unknown lint `{s}`, expected one of {}",
+          Self::ALL.map(|l| l.name()).join(", ")
+        )
+      })
+  }
+}
+
+/// How a lint is reported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+  /// The lint is not checked.
+  Allow,
+  /// The lint is reported as a warning.
+  #[default]
+  Warn,
+  /// The lint is reported as an error.
+  Deny,
+}
+
+/// Configuration of the capability lints.
+#[derive(Debug, Default, Clone)]
+pub struct LintConfig {
+  levels: BTreeMap<Lint, LintLevel>,
+  deny_warnings: bool,
+  window_labels: Vec<String>,
+  plugins: Vec<String>,
+}
+
+impl LintConfig {
+  /// Creates the default configuration, where all lints are warnings.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the level of the given lint.
+  #[must_use]
+  pub fn level(mut self, lint: Lint, level: LintLevel) -> Self {
+    self.levels.insert(lint, level);
+    self
+  }
+
+  /// Reports all lints that are warnings as errors.
+  #[must_use]
+  pub fn deny_warnings(mut self) -> Self {
+    self.deny_warnings = true;
+    self
+  }
+
+  /// Declares a window or webview label that the app creates at runtime.
+  ///
+  /// Labels of the windows defined in the app configuration are always known.
+  #[must_use]
+  pub fn window_label(mut self, label: impl Into<String>) -> Self {
+    self.window_labels.push(label.into());
+    self
+  }
+
+  /// Declares a list of window or webview labels that the app creates at runtime.
+  ///
+  /// See [`Self::window_label`].
+  #[must_use]
+  pub fn window_labels<I: IntoIterator<Item = S>, S: Into<String>>(mut self, labels: I) -> Self {
+    self
+      .window_labels
+      .extend(labels.into_iter().map(Into::into));
+    self
+  }
+
+  /// Checks the permission sets of the given plugin for [`Lint::UnusedPermissionSet`].
+  ///
+  /// The app permission sets are always checked.
+  /// This is meant for plugins that are part of the app, other plugins usually ship sets that are not needed by every app.
+  #[must_use]
+  pub fn plugin(mut self, name: impl Into<String>) -> Self {
+    self.plugins.push(name.into());
+    self
+  }
+
+  /// The level the given lint is reported at.
+  pub fn level_of(&self, lint: Lint) -> LintLevel {
+    match self.levels.get(&lint).copied().unwrap_or_default() {
+      LintLevel::Warn if self.deny_warnings => LintLevel::Deny,
+      level => level,
+    }
+  }
+}
+
+/// A lint reported on the app capabilities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+  /// The lint that was triggered.
+  pub lint: Lint,
+  /// The level of the lint.
+  pub level: LintLevel,
+  /// Description of the problem.
+  pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} [{}]", self.message, self.lint)
+  }
+}
+
+/// Origins an IPC command is allowed or denied for.
+#[derive(Debug, Default)]
+struct Origins {
+  local: bool,
+  remote: BTreeSet<String>,
+}
+
+impl Origins {
+  fn of(capability: &Capability) -> Self {
+    Self {
+      local: capability.local,
+      remote: capability
+        .remote
+        .as_ref()
+        .map(|remote| remote.urls.iter().cloned().collect())
+        .unwrap_or_default(),
+    }
+  }
+
+  fn is_empty(&self) -> bool {
+    !self.local && self.remote.is_empty()
+  }
+
+  fn extend(&mut self, other: &Self) {
+    self.local |= other.local;
+    self.remote.extend(other.remote.iter().cloned());
+  }
+
+  fn covers(&self, other: &Self) -> bool {
+    (self.local || !other.local) && other.remote.is_subset(&self.remote)
+  }
+}
+
+fn command_name(key: &str, command: &str) -> String {
+  if key == APP_ACL_KEY {
+    command.to_string()
+  } else if let Some(core_plugin_name) = key.strip_prefix("core:") {
+    format!("plugin:{core_plugin_name}|{command}")
+  } else {
+    format!("plugin:{key}|{command}")
+  }
+}
+
+/// Adds the capabilities inlined in the `app > security > capabilities` configuration to the capability files,
+/// so they are linted too.
+///
+/// The inlined capabilities can extend the capability files, which must already have their `extends` resolved.
+pub fn with_inlined_capabilities(
+  capabilities: &BTreeMap<String, Capability>,
+  config: &Config,
+) -> Result<BTreeMap<String, Capability>, super::Error> {
+  let mut inlined = config
+    .app
+    .security
+    .capabilities
+    .iter()
+    .filter_map(|entry| match entry {
+      CapabilityEntry::Inlined(capability) => {
+        Some((capability.identifier.clone(), capability.clone()))
+      }
+      CapabilityEntry::Reference(_) => None,
+    })
+    .collect();
+  resolve_extends_with(&mut inlined, capabilities)?;
+
+  let mut capabilities = capabilities.clone();
+  capabilities.extend(inlined);
+  Ok(capabilities)
+}
+
+/// Runs the lints on the given capabilities.
+///
+/// Permissions that cannot be resolved are ignored, they are reported when resolving the ACL.
+pub fn lint(
+  acl: &BTreeMap<String, Manifest>,
+  capabilities: &BTreeMap<String, Capability>,
+  target: Target,
+  config: &LintConfig,
+) -> Vec<Diagnostic> {
+  let mut diagnostics = Vec::new();
+  let mut report = |lint: Lint, message: String| {
+    let level = config.level_of(lint);
+    if level != LintLevel::Allow {
+      diagnostics.push(Diagnostic {
+        lint,
+        level,
+        message,
+      });
+    }
+  };
+
+  let active = capabilities
+    .values()
+    .filter(|c| c.is_active(&target))
+    .collect::<Vec<_>>();
+
+  if config.level_of(Lint::ShadowedPermission) != LintLevel::Allow {
+    for (capability, permission, commands, denied_by) in shadowed_permissions(acl, &active) {
+      report(
+        Lint::ShadowedPermission,
+        format!(
+          "permission `{permission}` of capability `{capability}` has no effect, its commands ({}) are denied by capability {}",
+          commands.join(", "),
+          denied_by
+            .iter()
+            .map(|c| format!("`{c}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+        ),
+      );
+    }
+  }
+
+  if config.level_of(Lint::UnknownWindow) != LintLevel::Allow && !config.window_labels.is_empty() {
+    for capability in &active {
+      for (kind, label) in capability
+        .windows
+        .iter()
+        .map(|w| ("window", w))
+        .chain(capability.webviews.iter().map(|w| ("webview", w)))
+      {
+        let Ok(pattern) = glob::Pattern::new(label) else {
+          continue;
+        };
+        if !config.window_labels.iter().any(|l| pattern.matches(l)) {
+          report(
+            Lint::UnknownWindow,
+            format!(
+              "capability `{}` targets {kind} `{label}` but no window or webview with a matching label is known, expected one of {}",
+              capability.identifier,
+              config.window_labels.join(", ")
+            ),
+          );
+        }
+      }
+    }
+  }
+
+  if config.level_of(Lint::UnusedPermissionSet) != LintLevel::Allow {
+    let referenced = referenced_permissions(acl, capabilities);
+    for (key, manifest) in acl {
+      if key != APP_ACL_KEY && !config.plugins.contains(key) {
+        continue;
+      }
+      for set in manifest.permission_sets.keys() {
+        if !referenced.contains(&(key.clone(), set.clone())) {
+          let identifier = if key == APP_ACL_KEY {
+            set.clone()
+          } else {
+            format!("{key}:{set}")
+          };
+          report(
+            Lint::UnusedPermissionSet,
+            format!("permission set `{identifier}` is not referenced by any capability"),
+          );
+        }
+      }
+    }
+  }
+
+  diagnostics
+}
+
+/// Window and webview labels a capability applies to.
+#[derive(Debug)]
+struct Targets {
+  windows: Vec<glob::Pattern>,
+  webviews: Vec<glob::Pattern>,
+}
+
+impl Targets {
+  fn of(capability: &Capability) -> Self {
+    let patterns = |labels: &[String]| {
+      labels
+        .iter()
+        .filter_map(|label| glob::Pattern::new(label).ok())
+        .collect()
+    };
+    Self {
+      windows: patterns(&capability.windows),
+      webviews: patterns(&capability.webviews),
+    }
+  }
+
+  /// Whether every label targeted by `other` is also targeted by `self`.
+  ///
+  /// A pattern covers another one when it matches it as a label, so this can miss overlaps but never reports one that does not exist.
+  fn covers(&self, other: &Self) -> bool {
+    fn covered(patterns: &[glob::Pattern], pattern: &glob::Pattern) -> bool {
+      patterns.iter().any(|p| p.matches(pattern.as_str()))
+    }
+    other.windows.iter().all(|w| covered(&self.windows, w))
+      && other.webviews.iter().all(|w| covered(&self.webviews, w))
+  }
+}
+
+/// Finds the permissions whose allowed commands are all denied, returning
+/// the capability, permission, commands and the capabilities denying them.
+fn shadowed_permissions(
+  acl: &BTreeMap<String, Manifest>,
+  capabilities: &[&Capability],
+) -> Vec<(String, String, Vec<String>, BTreeSet<String>)> {
+  let mut denied: BTreeMap<String, Vec<(&str, Origins, Targets)>> = BTreeMap::new();
+  for capability in capabilities {
+    for entry in &capability.permissions {
+      let Ok(permissions) = get_permissions(entry.identifier(), acl) else {
+        continue;
+      };
+      for traversed in permissions {
+        for command in &traversed.permission.commands.deny {
+          denied
+            .entry(command_name(&traversed.key, command))
+            .or_default()
+            .push((
+              &capability.identifier,
+              Origins::of(capability),
+              Targets::of(capability),
+            ));
+        }
+      }
+    }
+  }
+
+  let mut shadowed = Vec::new();
+  for capability in capabilities {
+    let origins = Origins::of(capability);
+    if origins.is_empty() {
+      continue;
+    }
+    let targets = Targets::of(capability);
+
+    for entry in &capability.permissions {
+      let Ok(permissions) = get_permissions(entry.identifier(), acl) else {
+        continue;
+      };
+      let commands = permissions
+        .iter()
+        .flat_map(|p| {
+          p.permission
+            .commands
+            .allow
+            .iter()
+            .map(|c| command_name(&p.key, c))
+        })
+        .collect::<BTreeSet<_>>();
+      if commands.is_empty() {
+        continue;
+      }
+
+      let mut denied_by = BTreeSet::new();
+      let all_denied = commands.iter().all(|command| {
+        let mut denied_origins = Origins::default();
+        // only the denials applying to every window and webview of the capability shadow it
+        for (identifier, deny_origins, _) in denied
+          .get(command)
+          .into_iter()
+          .flatten()
+          .filter(|(_, _, deny_targets)| deny_targets.covers(&targets))
+        {
+          denied_origins.extend(deny_origins);
+          denied_by.insert(identifier.to_string());
+        }
+        denied_origins.covers(&origins)
+      });
+
+      if all_denied {
+        shadowed.push((
+          capability.identifier.clone(),
+          entry.identifier().get().to_string(),
+          commands.into_iter().collect(),
+          denied_by,
+        ));
+      }
+    }
+  }
+
+  shadowed
+}
+
+/// Collects the `(manifest key, set name)` of every permission set referenced by the capabilities, directly or through other sets.
+fn referenced_permissions(
+  acl: &BTreeMap<String, Manifest>,
+  capabilities: &BTreeMap<String, Capability>,
+) -> BTreeSet<(String, String)> {
+  fn visit(
+    acl: &BTreeMap<String, Manifest>,
+    key: &str,
+    name: &str,
+    referenced: &mut BTreeSet<(String, String)>,
+  ) {
+    let Some(manifest) = acl.get(key) else {
+      return;
+    };
+    if !referenced.insert((key.to_string(), name.to_string())) {
+      return;
+    }
+
+    let permissions = if name == "default" {
+      manifest.default_permission.as_ref().map(|d| &d.permissions)
+    } else {
+      manifest.permission_sets.get(name).map(|s| &s.permissions)
+    };
+
+    for permission in permissions.into_iter().flatten() {
+      // same lookup as the ACL resolution: a prefixed permission refers to another manifest if it exists
+      match Identifier::try_from(permission.clone())
+        .ok()
+        .and_then(|id| {
+          id.get_prefix()
+            .filter(|k| acl.contains_key(*k))
+            .map(|k| (k.to_string(), id.get_base().to_string()))
+        }) {
+        Some((key, name)) => visit(acl, &key, &name, referenced),
+        None => visit(acl, key, permission, referenced),
+      }
+    }
+  }
+
+  let mut referenced = BTreeSet::new();
+  for capability in capabilities.values() {
+    for entry in &capability.permissions {
+      let id = entry.identifier();
+      visit(
+        acl,
+        id.get_prefix().unwrap_or(APP_ACL_KEY),
+        id.get_base(),
+        &mut referenced,
+      );
+    }
+  }
+  referenced
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use super::*;
+  use crate::acl::{
+    capability::{CapabilityRemote, PermissionEntry},
+    manifest::{Manifest, PermissionFile},
+    Commands, Permission, PermissionSet,
+  };
+
+  fn permission(identifier: &str, allow: &[&str], deny: &[&str]) -> Permission {
+    Permission {
+      identifier: identifier.into(),
+      commands: Commands {
+        allow: allow.iter().map(ToString::to_string).collect(),
+        deny: deny.iter().map(ToString::to_string).collect(),
+      },
+      ..Default::default()
+    }
+  }
+
+  fn capability(identifier: &str, permissions: &[&str]) -> Capability {
+    Capability {
+      identifier: identifier.into(),
+      description: String::new(),
+      remote: None,
+      local: true,
+      windows: vec!["main".into()],
+      webviews: Vec::new(),
+      permissions: permissions
+        .iter()
+        .map(|p| PermissionEntry::PermissionRef((*p).to_string().try_into().unwrap()))
+        .collect(),
+      extends: Vec::new(),
+      platforms: None,
+    }
+  }
+
+  fn acl() -> BTreeMap<String, Manifest> {
+    let manifest = Manifest::new(
+      vec![PermissionFile {
+        default: None,
+        set: vec![
+          PermissionSet {
+            identifier: "read".into(),
+            description: String::new(),
+            permissions: vec!["allow-read".into()],
+          },
+          PermissionSet {
+            identifier: "all".into(),
+            description: String::new(),
+            permissions: vec!["read".into(), "allow-write".into()],
+          },
+          PermissionSet {
+            identifier: "unused".into(),
+            description: String::new(),
+            permissions: vec!["allow-write".into()],
+          },
+        ],
+        permission: vec![
+          permission("allow-read", &["read"], &[]),
+          permission("allow-write", &["write"], &[]),
+          permission("deny-write", &[], &["write"]),
+        ],
+      }],
+      None,
+    );
+    [("fs".to_string(), manifest)].into_iter().collect()
+  }
+
+  fn run(capabilities: Vec<Capability>, config: &LintConfig) -> Vec<Diagnostic> {
+    let capabilities = capabilities
+      .into_iter()
+      .map(|c| (c.identifier.clone(), c))
+      .collect();
+    lint(&acl(), &capabilities, Target::Linux, config)
+  }
+
+  #[test]
+  fn shadowed_permission() {
+    let config = LintConfig::new()
+      .level(Lint::UnusedPermissionSet, LintLevel::Allow)
+      .deny_warnings();
+    let diagnostics = run(
+      vec![
+        capability("main", &["fs:allow-read", "fs:allow-write"]),
+        capability("lockdown", &["fs:deny-write"]),
+      ],
+      &config,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].lint, Lint::ShadowedPermission);
+    assert_eq!(diagnostics[0].level, LintLevel::Deny);
+    assert!(diagnostics[0].message.contains("`fs:allow-write`"));
+    assert!(diagnostics[0].message.contains("`lockdown`"));
+
+    // a local deny does not shadow a remote grant
+    let mut remote = capability("remote", &["fs:allow-write"]);
+    remote.local = false;
+    remote.remote = Some(CapabilityRemote {
+      urls: vec!["https://tauri.app".into()],
+    });
+    assert!(run(
+      vec![remote, capability("lockdown", &["fs:deny-write"])],
+      &config
+    )
+    .is_empty());
+
+    // a deny on another window does not shadow the grant
+    let mut settings = capability("settings", &["fs:deny-write"]);
+    settings.windows = vec!["settings".into()];
+    assert!(run(
+      vec![
+        capability("main", &["fs:allow-read", "fs:allow-write"]),
+        settings
+      ],
+      &config
+    )
+    .is_empty());
+
+    // but a deny on every window does
+    let mut everywhere = capability("everywhere", &["fs:deny-write"]);
+    everywhere.windows = vec!["*".into()];
+    let diagnostics = run(
+      vec![capability("main", &["fs:allow-write"]), everywhere],
+      &config,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0].message.contains("`everywhere`"));
+  }
+
+  #[test]
+  fn unknown_window() {
+    let config = LintConfig::new()
+      .level(Lint::UnusedPermissionSet, LintLevel::Allow)
+      .window_labels(["main", "settings"]);
+
+    let mut settings = capability("settings", &["fs:read"]);
+    settings.windows = vec!["settings-*".into()];
+    settings.webviews = vec!["sett*".into()];
+
+    let diagnostics = run(vec![capability("main", &["fs:read"]), settings], &config);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].lint, Lint::UnknownWindow);
+    assert!(diagnostics[0].message.contains("`settings-*`"));
+
+    // without known labels there is nothing to check against
+    let mut settings = capability("settings", &["fs:read"]);
+    settings.windows = vec!["settings-*".into()];
+    assert!(run(
+      vec![settings],
+      &LintConfig::new().level(Lint::UnusedPermissionSet, LintLevel::Allow)
+    )
+    .is_empty());
+  }
+
+  #[test]
+  fn unused_permission_set() {
+    let capabilities = vec![capability("main", &["fs:all"])];
+
+    // plugin sets are only checked when requested
+    assert!(run(capabilities.clone(), &LintConfig::new()).is_empty());
+
+    let diagnostics = run(capabilities, &LintConfig::new().plugin("fs"));
+    assert_eq!(
+      diagnostics,
+      vec![Diagnostic {
+        lint: Lint::UnusedPermissionSet,
+        level: LintLevel::Warn,
+        message: "permission set `fs:unused` is not referenced by any capability".into(),
+      }]
+    );
+  }
+
+  #[test]
+  fn inlined_capabilities() {
+    let files = [capability("main", &["fs:allow-read", "fs:allow-write"])]
+      .into_iter()
+      .map(|c| (c.identifier.clone(), c))
+      .collect();
+
+    let mut settings = capability("settings", &[]);
+    settings.windows = vec!["settings".into()];
+    settings.extends = vec!["main".into()];
+    let mut config = Config::default();
+    config.app.security.capabilities = vec![
+      CapabilityEntry::Reference("main".into()),
+      CapabilityEntry::Inlined(settings),
+      CapabilityEntry::Inlined(capability("lockdown", &["fs:deny-write"])),
+    ];
+
+    let capabilities = with_inlined_capabilities(&files, &config).unwrap();
+    assert_eq!(
+      capabilities.keys().collect::<Vec<_>>(),
+      ["lockdown", "main", "settings"]
+    );
+    // the inlined capabilities can extend the capability files
+    assert_eq!(
+      capabilities["settings"].permissions,
+      capabilities["main"].permissions
+    );
+
+    let diagnostics = lint(
+      &acl(),
+      &capabilities,
+      Target::Linux,
+      &LintConfig::new().level(Lint::UnusedPermissionSet, LintLevel::Allow),
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].lint, Lint::ShadowedPermission);
+    assert!(diagnostics[0].message.contains("`lockdown`"));
+
+    let mut config = Config::default();
+    let mut broken = capability("broken", &[]);
+    broken.extends = vec!["missing".into()];
+    config.app.security.capabilities = vec![CapabilityEntry::Inlined(broken)];
+    assert!(with_inlined_capabilities(&files, &config).is_err());
+  }
+
+  #[test]
+  fn parse_lint() {
+    for lint in Lint::ALL {
+      assert_eq!(lint.name().parse::<Lint>(), Ok(lint));
+    }
+    assert!("unknown".parse::<Lint>().is_err());
+  }
+}
@@ -45,6 +45,7 @@ pub const CAPABILITIES_FILE_NAME: &str = "capabilities.json";
 pub mod build;
 pub mod capability;
 pub mod identifier;
+pub mod lint;
 pub mod manifest;
 pub mod resolved;
 #[cfg(feature = "schema")]
@@ -145,6 +146,19 @@ pub enum Error {
     /// Capability identifier.
     identifier: String,
   },
+
+  /// Capability extends a capability that does not exist.
+  #[error("capability `{capability}` extends unknown capability `{extends}`")]
+  UnknownExtendedCapability {
+    /// Capability identifier.
+    capability: String,
+    /// Identifier of the extended capability.
+    extends: String,
+  },
+
+  /// Capabilities extend each other in a cycle.
+  #[error("capability inheritance cycle detected: {}", .0.join(" -> "))]
+  CapabilityExtendsCycle(Vec<String>),
 }
 
 /// Allowed and denied commands inside a permission.
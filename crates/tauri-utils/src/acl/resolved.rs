@@ -84,6 +84,8 @@ impl Resolved {
     mut capabilities: BTreeMap<String, Capability>,
     target: Target,
   ) -> Result<Self, Error> {
+    super::capability::resolve_extends(&mut capabilities)?;
+
     let mut allowed_commands = BTreeMap::new();
     let mut denied_commands = BTreeMap::new();
 
@@ -322,13 +324,13 @@ fn with_resolved_permissions<F: FnMut(ResolvedPermission<'_>) -> Result<(), Erro
 }
 
 #[derive(Debug)]
-struct TraversedPermission<'a> {
-  key: String,
-  permission_name: String,
-  permission: &'a Permission,
+pub(crate) struct TraversedPermission<'a> {
+  pub(crate) key: String,
+  pub(crate) permission_name: String,
+  pub(crate) permission: &'a Permission,
 }
 
-fn get_permissions<'a>(
+pub(crate) fn get_permissions<'a>(
   permission_id: &Identifier,
   acl: &'a BTreeMap<String, Manifest>,
 ) -> Result<Vec<TraversedPermission<'a>>, Error> {
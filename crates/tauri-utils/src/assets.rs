@@ -115,6 +115,8 @@ pub struct EmbeddedAssets {
   global_hashes: &'static [CspHash<'static>],
   // Hashes that are associated to the CSP of the HTML file identified by the map key (the HTML asset key).
   html_hashes: phf::Map<&'static str, &'static [CspHash<'static>]>,
+  // Content hashes of the assets computed at compile time, identified by the asset key.
+  asset_hashes: phf::Map<&'static str, &'static str>,
 }
 
 impl EmbeddedAssets {
@@ -128,6 +130,15 @@ impl EmbeddedAssets {
       assets: map,
       global_hashes,
       html_hashes,
+      asset_hashes: phf::phf_map! {},
+    }
+  }
+
+  /// Sets the content hashes of the assets, used to validate cached responses.
+  pub const fn with_asset_hashes(self, asset_hashes: phf::Map<&'static str, &'static str>) -> Self {
+    Self {
+      asset_hashes,
+      ..self
     }
   }
 
@@ -167,6 +178,11 @@ impl EmbeddedAssets {
     )
   }
 
+  /// Content hash of the given asset, computed at compile time.
+  pub fn hash(&self, key: &AssetKey) -> Option<&str> {
+    self.asset_hashes.get(key.as_ref()).copied()
+  }
+
   /// CSP hashes for the given asset.
   pub fn csp_hashes(&self, html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_> {
     Box::new(
@@ -46,7 +46,9 @@ use std::{
 /// Items to help with parsing content into a [`Config`].
 pub mod parse;
 
-use crate::{acl::capability::Capability, TitleBarStyle, WindowEffect, WindowEffectState};
+use crate::{
+  acl::capability::Capability, platform::Target, TitleBarStyle, WindowEffect, WindowEffectState,
+};
 
 pub use self::parse::parse;
 
@@ -1272,6 +1274,102 @@ pub struct WindowEffectsConfig {
   pub color: Option<Color>,
 }
 
+/// The direction in which a [`LayoutSplitConfig`] places its children.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum SplitDirection {
+  /// The children are placed side by side, from left to right.
+  #[default]
+  Horizontal,
+  /// The children are placed one below the other, from top to bottom.
+  Vertical,
+}
+
+/// The size of a layout item along the direction of its split.
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum LayoutSize {
+  /// A share of the space left by the fixed size items, proportional to the ratios of the other items.
+  Ratio(f64),
+  /// A size in logical pixels.
+  Fixed(f64),
+}
+
+impl Default for LayoutSize {
+  fn default() -> Self {
+    Self::Ratio(1.)
+  }
+}
+
+/// An item of a [window layout](WindowConfig#structfield.layout).
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum WindowLayoutConfig {
+  /// A webview created with the window.
+  Webview(LayoutWebviewConfig),
+  /// Places its children side by side.
+  Split(LayoutSplitConfig),
+  /// Places its children on top of each other and only shows the selected one, for instance for tabs.
+  Stack(LayoutStackConfig),
+}
+
+/// A webview of a [window layout](WindowConfig#structfield.layout).
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LayoutWebviewConfig {
+  /// The webview identifier. It must be alphanumeric.
+  pub label: String,
+  /// The webview URL.
+  #[serde(default)]
+  pub url: WebviewUrl,
+  /// The size of the webview in its split.
+  #[serde(default)]
+  pub size: LayoutSize,
+  /// Whether the webview is visible. Hidden items leave their space to the other items of their split.
+  #[serde(default = "default_true")]
+  pub visible: bool,
+}
+
+/// A split of a [window layout](WindowConfig#structfield.layout), placing its children side by side.
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LayoutSplitConfig {
+  /// The direction in which the children are placed.
+  #[serde(default)]
+  pub direction: SplitDirection,
+  /// The items of the split.
+  pub children: Vec<WindowLayoutConfig>,
+  /// The size of the split in its parent split.
+  #[serde(default)]
+  pub size: LayoutSize,
+  /// Whether the split is visible. Hidden items leave their space to the other items of their split.
+  #[serde(default = "default_true")]
+  pub visible: bool,
+}
+
+/// A stack of a [window layout](WindowConfig#structfield.layout), only showing its selected child.
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LayoutStackConfig {
+  /// The items of the stack.
+  pub children: Vec<WindowLayoutConfig>,
+  /// The index of the visible child.
+  #[serde(default)]
+  pub selected: usize,
+  /// The size of the stack in its parent split.
+  #[serde(default)]
+  pub size: LayoutSize,
+  /// Whether the stack is visible. Hidden items leave their space to the other items of their split.
+  #[serde(default = "default_true")]
+  pub visible: bool,
+}
+
 /// The window configuration object.
 ///
 /// See more: <https://v2.tauri.app/reference/config/#windowconfig>
@@ -1486,6 +1584,27 @@ pub struct WindowConfig {
   /// - **MacOS / Linux / iOS / Android** - Unsupported.
   #[serde(default)]
   pub browser_extensions_enabled: bool,
+  /// Whether the position, size, maximized and fullscreen state of the window are saved
+  /// when it is moved, resized or closed, and restored the next time it is created.
+  ///
+  /// The saved state overrides `x`, `y`, `width`, `height`, `maximized` and `fullscreen`.
+  /// When the monitor the window was on is gone, it is moved on one of the available monitors.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Android / iOS**: Unsupported.
+  #[serde(default, alias = "remember-state")]
+  pub remember_state: bool,
+  /// Places several webviews in the window, in splits and stacks.
+  /// Requires the `unstable` feature of the `tauri` crate, the window is created with its own webview otherwise.
+  ///
+  /// When set, the window is created without its own webview and `url` is ignored:
+  /// the webviews of the layout are created instead, and their bounds are updated when the window is resized.
+  ///
+  /// ## Platform-specific:
+  ///
+  /// - **Android / iOS**: Unsupported.
+  pub layout: Option<WindowLayoutConfig>,
 }
 
 impl Default for WindowConfig {
@@ -1534,6 +1653,8 @@ impl Default for WindowConfig {
       proxy_url: None,
       zoom_hotkeys_enabled: false,
       browser_extensions_enabled: false,
+      remember_state: false,
+      layout: None,
     }
   }
 }
@@ -1761,6 +1882,7 @@ impl FsScope {
 /// Config for the asset custom protocol.
 ///
 /// See more: <https://v2.tauri.app/reference/config/#assetprotocolconfig>
+#[skip_serializing_none]
 #[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -1771,6 +1893,17 @@ pub struct AssetProtocolConfig {
   /// Enables the asset protocol.
   #[serde(default)]
   pub enable: bool,
+  /// Persists the paths allowed or forbidden at runtime, for instance when files are dropped on a window,
+  /// in the app data directory and restores them on the next launch.
+  ///
+  /// The saved paths are signed with a key kept in the credential store of the OS. Not supported on mobile.
+  #[serde(default, alias = "persist-scope")]
+  pub persist_scope: bool,
+  /// The `Cache-Control` header value sent with the asset protocol responses, for instance `max-age=3600`.
+  ///
+  /// Responses always include `ETag` and `Last-Modified` validators so the webview can revalidate cached files.
+  #[serde(alias = "cache-control")]
+  pub cache_control: Option<String>,
 }
 
 /// Security configuration.
@@ -1821,6 +1954,98 @@ pub struct SecurityConfig {
   /// If the list is empty, all capabilities are included.
   #[serde(default)]
   pub capabilities: Vec<CapabilityEntry>,
+  /// Default answers to the permissions requested by the webview content, such as the camera or the geolocation.
+  ///
+  /// They are used when the webview does not have a permission request hook or the hook does not answer the request.
+  /// The first policy listing the permission that applies to the webview and the requesting origin is used,
+  /// and the requests of permissions matching no policy keep the default behavior of the webview.
+  #[serde(default, alias = "permission-requests")]
+  pub permission_requests: Vec<PermissionRequestPolicy>,
+}
+
+impl SecurityConfig {
+  /// Whether the [`permission request policies`](Self::permission_requests) grant the permission
+  /// to the content of the given webview, `remote_origin` being `None` for the app's own content.
+  ///
+  /// Returns `None` if no policy applies.
+  pub fn permission_request_answer(
+    &self,
+    permission: WebviewPermission,
+    webview: &str,
+    remote_origin: Option<&Url>,
+  ) -> Option<bool> {
+    self
+      .permission_requests
+      .iter()
+      .find(|policy| {
+        policy.permissions.contains(&permission) && policy.applies_to(webview, remote_origin)
+      })
+      .map(|policy| policy.allow)
+  }
+}
+
+/// A permission the webview content can request.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum WebviewPermission {
+  /// Access to the camera.
+  Camera,
+  /// Access to the microphone.
+  Microphone,
+  /// Access to the device location.
+  Geolocation,
+  /// Reading the clipboard.
+  ClipboardRead,
+  /// Showing notifications.
+  Notifications,
+}
+
+/// The default answer to permission requests of the webview content, see [`SecurityConfig::permission_requests`].
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PermissionRequestPolicy {
+  /// The permissions answered by this policy.
+  pub permissions: Vec<WebviewPermission>,
+  /// Whether the permissions are granted.
+  #[serde(default = "default_true")]
+  pub allow: bool,
+  /// Whether the policy applies to the app's own content.
+  #[serde(default = "default_true")]
+  pub local: bool,
+  /// Remote origins the policy applies to, using the [URLPattern standard](https://urlpattern.spec.whatwg.org/)
+  /// like the capability remote URLs.
+  ///
+  /// ## Examples
+  ///
+  /// - "https://meet.mydomain.dev": applies to pages of meet.mydomain.dev
+  /// - "https://*.mydomain.dev": applies to pages of mydomain.dev subdomains
+  #[serde(default)]
+  pub remote: Vec<String>,
+  /// Webview labels (glob patterns) the policy applies to. Applies to all webviews if empty.
+  #[serde(default)]
+  pub webviews: Vec<String>,
+}
+
+impl PermissionRequestPolicy {
+  fn applies_to(&self, webview: &str, remote_origin: Option<&Url>) -> bool {
+    let webview_matches = self.webviews.is_empty()
+      || self
+        .webviews
+        .iter()
+        .any(|pattern| glob::Pattern::new(pattern).is_ok_and(|pattern| pattern.matches(webview)));
+    let origin_matches = match remote_origin {
+      Some(origin) => self.remote.iter().any(|url| {
+        url
+          .parse::<crate::acl::RemoteUrlPattern>()
+          .is_ok_and(|pattern| pattern.test(origin))
+      }),
+      None => self.local,
+    };
+    webview_matches && origin_matches
+  }
 }
 
 /// A capability entry which can be either an inlined capability or a reference to a capability defined on its own file.
@@ -1884,6 +2109,8 @@ pub struct AppConfig {
   /// Configuration for app tray icon.
   #[serde(alias = "tray-icon")]
   pub tray_icon: Option<TrayIconConfig>,
+  /// The application menu.
+  pub menu: Option<MenuConfig>,
   /// MacOS private API configuration. Enables the transparent background API and sets the `fullScreenEnabled` preference to `true`.
   #[serde(rename = "macOSPrivateApi", alias = "macos-private-api", default)]
   pub macos_private_api: bool,
@@ -1893,6 +2120,14 @@ pub struct AppConfig {
   /// If set to true "identifier" will be set as GTK app ID (on systems that use GTK).
   #[serde(rename = "enableGTKAppId", alias = "enable-gtk-app-id", default)]
   pub enable_gtk_app_id: bool,
+  /// Stores the app config, data, local data, cache and log directories next to the executable instead of the user directories,
+  /// so the app can run from a removable drive.
+  ///
+  /// The directories are created in a folder named after the app identifier, next to the executable,
+  /// the AppImage on Linux or the `.app` bundle on macOS.
+  /// The portable mode is also enabled when a file named `portable` is placed in that location.
+  #[serde(default)]
+  pub portable: bool,
 }
 
 impl AppConfig {
@@ -1955,6 +2190,226 @@ pub struct TrayIconConfig {
   pub title: Option<String>,
   /// Tray icon tooltip on Windows and macOS
   pub tooltip: Option<String>,
+  /// Animation played instead of showing `iconPath` once the tray icon is created.
+  pub animation: Option<TrayIconAnimationConfig>,
+  /// Badge drawn over the tray icon.
+  pub badge: Option<TrayIconBadgeConfig>,
+}
+
+/// Configuration for a tray icon animation.
+///
+/// Note: like `iconPath`, the frames are stored in raw pixels to the final binary.
+///
+/// See more: <https://v2.tauri.app/reference/config/#trayiconanimationconfig>
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TrayIconAnimationConfig {
+  /// Paths to the icons of the animation frames, in order.
+  pub frames: Vec<PathBuf>,
+  /// Delay between two frames, in milliseconds.
+  #[serde(default = "default_tray_icon_frame_delay")]
+  pub delay: u64,
+  /// Whether the animation restarts after the last frame. When `false`, the last frame stays shown.
+  #[serde(default = "default_true")]
+  pub repeat: bool,
+}
+
+fn default_tray_icon_frame_delay() -> u64 {
+  100
+}
+
+impl TrayIconConfig {
+  /// The icon paths of the tray icon animation frames.
+  pub fn animation_frames(&self) -> &[PathBuf] {
+    self
+      .animation
+      .as_ref()
+      .map(|a| a.frames.as_slice())
+      .unwrap_or_default()
+  }
+}
+
+/// A badge drawn over the top right corner of the tray icon.
+///
+/// See more: <https://v2.tauri.app/reference/config/#trayiconbadgeconfig>
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum TrayIconBadgeConfig {
+  /// A dot, for instance to notify of unread content.
+  Dot,
+  /// A number, for instance the count of unread notifications. Numbers above 99 are shown as `99+`.
+  Count(u32),
+}
+
+/// Configuration for the application menu.
+///
+/// The menu is created when the app starts, unless one is set with [`Builder::menu`](https://docs.rs/tauri/2/tauri/struct.Builder.html#method.menu).
+/// The platform specific lists replace `items` on their platform.
+///
+/// See more: <https://v2.tauri.app/reference/config/#menuconfig>
+#[skip_serializing_none]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MenuConfig {
+  /// The menu items. On macOS, all top-level items must be submenus.
+  #[serde(default)]
+  pub items: Vec<MenuItemConfig>,
+  /// The menu items to use on macOS instead of `items`.
+  #[serde(rename = "macOS", alias = "macos")]
+  pub macos: Option<Vec<MenuItemConfig>>,
+  /// The menu items to use on Windows instead of `items`.
+  pub windows: Option<Vec<MenuItemConfig>>,
+  /// The menu items to use on Linux instead of `items`.
+  pub linux: Option<Vec<MenuItemConfig>>,
+}
+
+impl MenuConfig {
+  /// The menu items for the given target.
+  pub fn platform_items(&self, target: Target) -> &[MenuItemConfig] {
+    let items = match target {
+      Target::MacOS => self.macos.as_deref(),
+      Target::Windows => self.windows.as_deref(),
+      Target::Linux => self.linux.as_deref(),
+      _ => None,
+    };
+    items.unwrap_or(&self.items)
+  }
+
+  /// The icon paths referenced by the menu items of the given target.
+  pub fn icons(&self, target: Target) -> Vec<&PathBuf> {
+    fn collect<'a>(items: &'a [MenuItemConfig], icons: &mut Vec<&'a PathBuf>) {
+      for item in items {
+        match item {
+          MenuItemConfig::Icon { icon, .. } => icons.push(icon),
+          MenuItemConfig::Submenu { items, .. } => collect(items, icons),
+          _ => {}
+        }
+      }
+    }
+
+    let mut icons = Vec::new();
+    collect(self.platform_items(target), &mut icons);
+    icons.sort();
+    icons.dedup();
+    icons
+  }
+}
+
+/// A menu item.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
+pub enum MenuItemConfig {
+  /// A menu item that emits a menu event when clicked.
+  Item {
+    /// The item id, used to identify it in menu events. Defaults to a random id.
+    id: Option<String>,
+    /// The item label.
+    text: String,
+    /// Whether the item is enabled or not.
+    #[serde(default = "default_true")]
+    enabled: bool,
+    /// The keyboard shortcut of the item, for example `CmdOrCtrl+Shift+N`.
+    accelerator: Option<String>,
+  },
+  /// A menu item with a check mark.
+  Check {
+    /// The item id, used to identify it in menu events. Defaults to a random id.
+    id: Option<String>,
+    /// The item label.
+    text: String,
+    /// Whether the item is enabled or not.
+    #[serde(default = "default_true")]
+    enabled: bool,
+    /// The keyboard shortcut of the item, for example `CmdOrCtrl+Shift+N`.
+    accelerator: Option<String>,
+    /// Whether the item is initially checked or not.
+    #[serde(default)]
+    checked: bool,
+  },
+  /// A menu item with an icon.
+  Icon {
+    /// The item id, used to identify it in menu events. Defaults to a random id.
+    id: Option<String>,
+    /// The item label.
+    text: String,
+    /// Whether the item is enabled or not.
+    #[serde(default = "default_true")]
+    enabled: bool,
+    /// The keyboard shortcut of the item, for example `CmdOrCtrl+Shift+N`.
+    accelerator: Option<String>,
+    /// Path to the icon, relative to the configuration file.
+    ///
+    /// Note: this stores the image in raw pixels to the final binary,
+    /// so keep the icon size (width and height) small.
+    icon: PathBuf,
+  },
+  /// A submenu.
+  Submenu {
+    /// The submenu id. Defaults to a random id.
+    id: Option<String>,
+    /// The submenu label.
+    text: String,
+    /// Whether the submenu is enabled or not.
+    #[serde(default = "default_true")]
+    enabled: bool,
+    /// The submenu items.
+    #[serde(default)]
+    items: Vec<MenuItemConfig>,
+  },
+  /// A predefined menu item, which has a native action on most platforms.
+  Predefined {
+    /// The predefined item.
+    item: PredefinedMenuItemConfig,
+    /// Overrides the default label of the item. Ignored for separators.
+    text: Option<String>,
+  },
+}
+
+/// A predefined menu item.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PredefinedMenuItemConfig {
+  /// A separator.
+  Separator,
+  /// Copies the selected text.
+  Copy,
+  /// Cuts the selected text.
+  Cut,
+  /// Pastes text from the clipboard.
+  Paste,
+  /// Selects all the text.
+  SelectAll,
+  /// Undoes the last action.
+  Undo,
+  /// Redoes the last undone action.
+  Redo,
+  /// Minimizes the window.
+  Minimize,
+  /// Maximizes the window.
+  Maximize,
+  /// Toggles the window fullscreen mode.
+  Fullscreen,
+  /// Hides the application.
+  Hide,
+  /// Hides the other applications.
+  HideOthers,
+  /// Shows all the applications.
+  ShowAll,
+  /// Closes the window.
+  CloseWindow,
+  /// Quits the application.
+  Quit,
+  /// Shows the about dialog, filled with the package information.
+  About,
+  /// The macOS services menu.
+  Services,
 }
 
 /// General configuration for the iOS target.
@@ -2505,6 +2960,8 @@ mod build {
       let parent = opt_str_lit(self.parent.as_ref());
       let zoom_hotkeys_enabled = self.zoom_hotkeys_enabled;
       let browser_extensions_enabled = self.browser_extensions_enabled;
+      let remember_state = self.remember_state;
+      let layout = opt_lit(self.layout.as_ref());
 
       literal_struct!(
         tokens,
@@ -2551,7 +3008,97 @@ mod build {
         incognito,
         parent,
         zoom_hotkeys_enabled,
-        browser_extensions_enabled
+        browser_extensions_enabled,
+        remember_state,
+        layout
+      );
+    }
+  }
+
+  impl ToTokens for SplitDirection {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::SplitDirection };
+
+      tokens.append_all(match self {
+        Self::Horizontal => quote! { #prefix::Horizontal },
+        Self::Vertical => quote! { #prefix::Vertical },
+      })
+    }
+  }
+
+  impl ToTokens for LayoutSize {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::LayoutSize };
+
+      tokens.append_all(match self {
+        Self::Ratio(ratio) => quote! { #prefix::Ratio(#ratio) },
+        Self::Fixed(size) => quote! { #prefix::Fixed(#size) },
+      })
+    }
+  }
+
+  impl ToTokens for WindowLayoutConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::WindowLayoutConfig };
+
+      tokens.append_all(match self {
+        Self::Webview(webview) => quote! { #prefix::Webview(#webview) },
+        Self::Split(split) => quote! { #prefix::Split(#split) },
+        Self::Stack(stack) => quote! { #prefix::Stack(#stack) },
+      })
+    }
+  }
+
+  impl ToTokens for LayoutWebviewConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let label = str_lit(&self.label);
+      let url = &self.url;
+      let size = &self.size;
+      let visible = self.visible;
+
+      literal_struct!(
+        tokens,
+        ::tauri::utils::config::LayoutWebviewConfig,
+        label,
+        url,
+        size,
+        visible
+      );
+    }
+  }
+
+  impl ToTokens for LayoutSplitConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let direction = &self.direction;
+      let children = vec_lit(&self.children, identity);
+      let size = &self.size;
+      let visible = self.visible;
+
+      literal_struct!(
+        tokens,
+        ::tauri::utils::config::LayoutSplitConfig,
+        direction,
+        children,
+        size,
+        visible
+      );
+    }
+  }
+
+  impl ToTokens for LayoutStackConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let children = vec_lit(&self.children, identity);
+      let selected = self.selected;
+      let size = &self.size;
+      let visible = self.visible;
+
+      literal_struct!(
+        tokens,
+        ::tauri::utils::config::LayoutStackConfig,
+        children,
+        selected,
+        size,
+        visible
       );
     }
   }
@@ -2783,6 +3330,7 @@ mod build {
       let asset_protocol = &self.asset_protocol;
       let pattern = &self.pattern;
       let capabilities = vec_lit(&self.capabilities, identity);
+      let permission_requests = vec_lit(&self.permission_requests, identity);
 
       literal_struct!(
         tokens,
@@ -2793,7 +3341,42 @@ mod build {
         dangerous_disable_asset_csp_modification,
         asset_protocol,
         pattern,
-        capabilities
+        capabilities,
+        permission_requests
+      );
+    }
+  }
+
+  impl ToTokens for WebviewPermission {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::WebviewPermission };
+
+      tokens.append_all(match self {
+        Self::Camera => quote! { #prefix::Camera },
+        Self::Microphone => quote! { #prefix::Microphone },
+        Self::Geolocation => quote! { #prefix::Geolocation },
+        Self::ClipboardRead => quote! { #prefix::ClipboardRead },
+        Self::Notifications => quote! { #prefix::Notifications },
+      })
+    }
+  }
+
+  impl ToTokens for PermissionRequestPolicy {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let permissions = vec_lit(&self.permissions, identity);
+      let allow = self.allow;
+      let local = self.local;
+      let remote = vec_lit(&self.remote, str_lit);
+      let webviews = vec_lit(&self.webviews, str_lit);
+
+      literal_struct!(
+        tokens,
+        ::tauri::utils::config::PermissionRequestPolicy,
+        permissions,
+        allow,
+        local,
+        remote,
+        webviews
       );
     }
   }
@@ -2806,6 +3389,8 @@ mod build {
       let icon_path = path_buf_lit(&self.icon_path);
       let title = opt_str_lit(self.title.as_ref());
       let tooltip = opt_str_lit(self.tooltip.as_ref());
+      let animation = opt_lit(self.animation.as_ref());
+      let badge = opt_lit(self.badge.as_ref());
       literal_struct!(
         tokens,
         ::tauri::utils::config::TrayIconConfig,
@@ -2814,11 +3399,143 @@ mod build {
         icon_as_template,
         menu_on_left_click,
         title,
-        tooltip
+        tooltip,
+        animation,
+        badge
+      );
+    }
+  }
+
+  impl ToTokens for TrayIconAnimationConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let frames = vec_lit(&self.frames, path_buf_lit);
+      let delay = self.delay;
+      let repeat = self.repeat;
+      literal_struct!(
+        tokens,
+        ::tauri::utils::config::TrayIconAnimationConfig,
+        frames,
+        delay,
+        repeat
       );
     }
   }
 
+  impl ToTokens for TrayIconBadgeConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::TrayIconBadgeConfig };
+
+      tokens.append_all(match self {
+        Self::Dot => quote! { #prefix::Dot },
+        Self::Count(count) => quote! { #prefix::Count(#count) },
+      })
+    }
+  }
+
+  impl ToTokens for MenuConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let items = vec_lit(&self.items, identity);
+      let macos = opt_vec_lit(self.macos.as_ref(), identity);
+      let windows = opt_vec_lit(self.windows.as_ref(), identity);
+      let linux = opt_vec_lit(self.linux.as_ref(), identity);
+
+      literal_struct!(
+        tokens,
+        ::tauri::utils::config::MenuConfig,
+        items,
+        macos,
+        windows,
+        linux
+      );
+    }
+  }
+
+  impl ToTokens for MenuItemConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::MenuItemConfig };
+
+      tokens.append_all(match self {
+        Self::Item {
+          id,
+          text,
+          enabled,
+          accelerator,
+        } => {
+          let id = opt_str_lit(id.as_ref());
+          let text = str_lit(text);
+          let accelerator = opt_str_lit(accelerator.as_ref());
+          quote! { #prefix::Item { id: #id, text: #text, enabled: #enabled, accelerator: #accelerator } }
+        }
+        Self::Check {
+          id,
+          text,
+          enabled,
+          accelerator,
+          checked,
+        } => {
+          let id = opt_str_lit(id.as_ref());
+          let text = str_lit(text);
+          let accelerator = opt_str_lit(accelerator.as_ref());
+          quote! { #prefix::Check { id: #id, text: #text, enabled: #enabled, accelerator: #accelerator, checked: #checked } }
+        }
+        Self::Icon {
+          id,
+          text,
+          enabled,
+          accelerator,
+          icon,
+        } => {
+          let id = opt_str_lit(id.as_ref());
+          let text = str_lit(text);
+          let accelerator = opt_str_lit(accelerator.as_ref());
+          let icon = path_buf_lit(icon);
+          quote! { #prefix::Icon { id: #id, text: #text, enabled: #enabled, accelerator: #accelerator, icon: #icon } }
+        }
+        Self::Submenu {
+          id,
+          text,
+          enabled,
+          items,
+        } => {
+          let id = opt_str_lit(id.as_ref());
+          let text = str_lit(text);
+          let items = vec_lit(items, identity);
+          quote! { #prefix::Submenu { id: #id, text: #text, enabled: #enabled, items: #items } }
+        }
+        Self::Predefined { item, text } => {
+          let text = opt_str_lit(text.as_ref());
+          quote! { #prefix::Predefined { item: #item, text: #text } }
+        }
+      })
+    }
+  }
+
+  impl ToTokens for PredefinedMenuItemConfig {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+      let prefix = quote! { ::tauri::utils::config::PredefinedMenuItemConfig };
+
+      tokens.append_all(match self {
+        Self::Separator => quote! { #prefix::Separator },
+        Self::Copy => quote! { #prefix::Copy },
+        Self::Cut => quote! { #prefix::Cut },
+        Self::Paste => quote! { #prefix::Paste },
+        Self::SelectAll => quote! { #prefix::SelectAll },
+        Self::Undo => quote! { #prefix::Undo },
+        Self::Redo => quote! { #prefix::Redo },
+        Self::Minimize => quote! { #prefix::Minimize },
+        Self::Maximize => quote! { #prefix::Maximize },
+        Self::Fullscreen => quote! { #prefix::Fullscreen },
+        Self::Hide => quote! { #prefix::Hide },
+        Self::HideOthers => quote! { #prefix::HideOthers },
+        Self::ShowAll => quote! { #prefix::ShowAll },
+        Self::CloseWindow => quote! { #prefix::CloseWindow },
+        Self::Quit => quote! { #prefix::Quit },
+        Self::About => quote! { #prefix::About },
+        Self::Services => quote! { #prefix::Services },
+      })
+    }
+  }
+
   impl ToTokens for FsScope {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let prefix = quote! { ::tauri::utils::config::FsScope };
@@ -2841,7 +3558,9 @@ mod build {
   impl ToTokens for AssetProtocolConfig {
     fn to_tokens(&self, tokens: &mut TokenStream) {
       let scope = &self.scope;
-      tokens.append_all(quote! { ::tauri::utils::config::AssetProtocolConfig { scope: #scope, ..Default::default() } })
+      let persist_scope = self.persist_scope;
+      let cache_control = opt_str_lit(self.cache_control.as_ref());
+      tokens.append_all(quote! { ::tauri::utils::config::AssetProtocolConfig { scope: #scope, persist_scope: #persist_scope, cache_control: #cache_control, ..Default::default() } })
     }
   }
 
@@ -2850,9 +3569,11 @@ mod build {
       let windows = vec_lit(&self.windows, identity);
       let security = &self.security;
       let tray_icon = opt_lit(self.tray_icon.as_ref());
+      let menu = opt_lit(self.menu.as_ref());
       let macos_private_api = self.macos_private_api;
       let with_global_tauri = self.with_global_tauri;
       let enable_gtk_app_id = self.enable_gtk_app_id;
+      let portable = self.portable;
 
       literal_struct!(
         tokens,
@@ -2860,9 +3581,11 @@ mod build {
         windows,
         security,
         tray_icon,
+        menu,
         macos_private_api,
         with_global_tauri,
-        enable_gtk_app_id
+        enable_gtk_app_id,
+        portable
       );
     }
   }
@@ -2937,11 +3660,14 @@ mod test {
         asset_protocol: AssetProtocolConfig::default(),
         pattern: Default::default(),
         capabilities: Vec::new(),
+        permission_requests: Vec::new(),
       },
       tray_icon: None,
+      menu: None,
       macos_private_api: false,
       with_global_tauri: false,
       enable_gtk_app_id: false,
+      portable: false,
     };
 
     // create a build config
@@ -2986,4 +3712,154 @@ mod test {
     assert_eq!(d_bundle, bundle);
     assert_eq!(d_windows, app.windows);
   }
+
+  #[test]
+  fn menu_config() {
+    let menu: MenuConfig = serde_json::from_value(serde_json::json!({
+      "items": [{
+        "type": "submenu",
+        "text": "File",
+        "items": [
+          { "type": "item", "id": "new", "text": "New", "accelerator": "CmdOrCtrl+N" },
+          { "type": "icon", "id": "open", "text": "Open", "icon": "icons/open.png" },
+          { "type": "predefined", "item": "separator" },
+          { "type": "check", "id": "autosave", "text": "Autosave", "checked": true }
+        ]
+      }],
+      "macOS": [{
+        "type": "submenu",
+        "text": "App",
+        "items": [{ "type": "predefined", "item": "hideOthers", "text": "Hide Others" }]
+      }]
+    }))
+    .unwrap();
+
+    assert_eq!(
+      menu.platform_items(Target::MacOS),
+      [MenuItemConfig::Submenu {
+        id: None,
+        text: "App".into(),
+        enabled: true,
+        items: vec![MenuItemConfig::Predefined {
+          item: PredefinedMenuItemConfig::HideOthers,
+          text: Some("Hide Others".into()),
+        }],
+      }]
+    );
+    assert_eq!(menu.platform_items(Target::Linux), menu.items.as_slice());
+    assert_eq!(
+      menu.icons(Target::Windows),
+      [&PathBuf::from("icons/open.png")]
+    );
+    assert!(menu.icons(Target::MacOS).is_empty());
+  }
+
+  #[test]
+  fn tray_icon_animation_config() {
+    let tray: TrayIconConfig = serde_json::from_value(serde_json::json!({
+      "iconPath": "icons/tray.png",
+      "animation": { "frames": ["icons/sync-1.png", "icons/sync-2.png"] },
+      "badge": { "count": 3 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+      tray.animation,
+      Some(TrayIconAnimationConfig {
+        frames: vec!["icons/sync-1.png".into(), "icons/sync-2.png".into()],
+        delay: 100,
+        repeat: true,
+      })
+    );
+    assert_eq!(tray.animation_frames().len(), 2);
+    assert_eq!(tray.badge, Some(TrayIconBadgeConfig::Count(3)));
+
+    let tray: TrayIconConfig = serde_json::from_value(serde_json::json!({
+      "iconPath": "icons/tray.png",
+      "badge": "dot"
+    }))
+    .unwrap();
+    assert!(tray.animation_frames().is_empty());
+    assert_eq!(tray.badge, Some(TrayIconBadgeConfig::Dot));
+  }
+
+  #[test]
+  fn window_layout_config() {
+    let window: WindowConfig = serde_json::from_value(serde_json::json!({
+      "layout": {
+        "split": {
+          "children": [
+            { "webview": { "label": "sidebar", "url": "sidebar.html", "size": { "fixed": 240 } } },
+            {
+              "stack": {
+                "selected": 1,
+                "children": [
+                  { "webview": { "label": "editor" } },
+                  { "webview": { "label": "preview", "visible": false } }
+                ]
+              }
+            }
+          ]
+        }
+      }
+    }))
+    .unwrap();
+
+    let Some(WindowLayoutConfig::Split(split)) = window.layout else {
+      panic!("expected a split layout");
+    };
+    assert_eq!(split.direction, SplitDirection::Horizontal);
+    assert_eq!(
+      split.children[0],
+      WindowLayoutConfig::Webview(LayoutWebviewConfig {
+        label: "sidebar".into(),
+        url: WebviewUrl::App("sidebar.html".into()),
+        size: LayoutSize::Fixed(240.),
+        visible: true,
+      })
+    );
+    let WindowLayoutConfig::Stack(stack) = &split.children[1] else {
+      panic!("expected a stack");
+    };
+    assert_eq!(stack.selected, 1);
+    assert_eq!(stack.size, LayoutSize::Ratio(1.));
+    assert_eq!(stack.children.len(), 2);
+  }
+
+  #[test]
+  fn permission_request_policies() {
+    let security: SecurityConfig = serde_json::from_value(serde_json::json!({
+      "permissionRequests": [
+        { "permissions": ["camera", "microphone"], "local": false, "remote": ["https://*.tauri.app"] },
+        { "permissions": ["notifications"], "webviews": ["main"] },
+        { "permissions": ["camera"], "allow": false }
+      ]
+    }))
+    .unwrap();
+
+    let remote: Url = "https://meet.tauri.app/room".parse().unwrap();
+    let other: Url = "https://example.com".parse().unwrap();
+
+    let answer = |permission, webview, remote_origin| {
+      security.permission_request_answer(permission, webview, remote_origin)
+    };
+    assert_eq!(
+      answer(WebviewPermission::Camera, "main", Some(&remote)),
+      Some(true)
+    );
+    assert_eq!(
+      answer(WebviewPermission::Camera, "main", Some(&other)),
+      None
+    );
+    assert_eq!(answer(WebviewPermission::Camera, "main", None), Some(false));
+    assert_eq!(
+      answer(WebviewPermission::Notifications, "main", None),
+      Some(true)
+    );
+    assert_eq!(
+      answer(WebviewPermission::Notifications, "other", None),
+      None
+    );
+    assert_eq!(answer(WebviewPermission::Geolocation, "main", None), None);
+  }
 }
@@ -0,0 +1,492 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Image decoding and resizing, shared by `tauri` and `tauri-codegen`.
+//!
+//! The decoders are enabled with the `image-png`, `image-ico`, `image-icns` and `image-svg` features.
+
+use std::f32::consts::PI;
+
+/// An error decoding an image.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+  /// The image format is unknown or its decoder is not enabled.
+  #[error("unsupported image format")]
+  UnsupportedFormat,
+  /// The icon file has no image.
+  #[error("the icon has no image")]
+  Empty,
+  /// The image can't be created with this size.
+  #[error("invalid image size {0}x{1}")]
+  InvalidSize(u32, u32),
+  /// Failed to read an ICO or ICNS icon.
+  #[error(transparent)]
+  Io(#[from] std::io::Error),
+  /// Failed to decode a PNG image.
+  #[cfg(feature = "image-png")]
+  #[error("failed to decode PNG: {0}")]
+  Png(#[from] png::DecodingError),
+  /// Failed to parse an SVG image.
+  #[cfg(feature = "image-svg")]
+  #[error("failed to parse SVG: {0}")]
+  Svg(#[from] resvg::usvg::Error),
+}
+
+/// Result type for image decoding.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The image formats that can be decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ImageFormat {
+  /// PNG image.
+  Png,
+  /// Windows icon, which can contain several sizes.
+  Ico,
+  /// macOS icon, which can contain several sizes.
+  Icns,
+  /// SVG image, rasterized at the requested size.
+  Svg,
+}
+
+impl ImageFormat {
+  /// The format of a file extension.
+  pub fn from_extension(extension: &str) -> Option<Self> {
+    match extension.to_ascii_lowercase().as_str() {
+      "png" => Some(Self::Png),
+      "ico" => Some(Self::Ico),
+      "icns" => Some(Self::Icns),
+      "svg" => Some(Self::Svg),
+      _ => None,
+    }
+  }
+
+  /// Guesses the format from the content of an image file.
+  pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+      Some(Self::Png)
+    } else if bytes.starts_with(&[0, 0, 1, 0]) {
+      Some(Self::Ico)
+    } else if bytes.starts_with(b"icns") {
+      Some(Self::Icns)
+    } else {
+      let text = String::from_utf8_lossy(&bytes[..bytes.len().min(1024)]);
+      let text = text.trim_start_matches('\u{feff}').trim_start();
+      (text.starts_with("<svg") || (text.starts_with("<?xml") && text.contains("<svg")))
+        .then_some(Self::Svg)
+    }
+  }
+}
+
+/// An RGBA image in row-major order from top to bottom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+  /// The RGBA pixels, not premultiplied.
+  pub rgba: Vec<u8>,
+  /// The width of the image.
+  pub width: u32,
+  /// The height of the image.
+  pub height: u32,
+}
+
+/// Decodes an image.
+///
+/// When `size` is set, the image is scaled to fit in a square of `size` physical pixels, keeping its aspect ratio:
+/// the smallest image at least as large is picked from the ICO and ICNS icons,
+/// the SVG images are rasterized at this size and the other images are resized with [`RgbaImage::resize`].
+/// Otherwise the largest image of the icons is used and the SVG images are rasterized at their own size.
+#[cfg_attr(
+  not(any(
+    feature = "image-png",
+    feature = "image-ico",
+    feature = "image-icns",
+    feature = "image-svg"
+  )),
+  allow(unused_variables)
+)]
+pub fn decode(bytes: &[u8], format: ImageFormat, size: Option<u32>) -> Result<RgbaImage> {
+  let image: Option<RgbaImage> = match format {
+    #[cfg(feature = "image-png")]
+    ImageFormat::Png => Some(decode_png(bytes)?),
+    #[cfg(feature = "image-ico")]
+    ImageFormat::Ico => Some(decode_ico(bytes, size)?),
+    #[cfg(feature = "image-icns")]
+    ImageFormat::Icns => Some(decode_icns(bytes, size)?),
+    #[cfg(feature = "image-svg")]
+    ImageFormat::Svg => return rasterize_svg(bytes, size),
+    #[allow(unreachable_patterns)]
+    _ => None,
+  };
+  let image = image.ok_or(Error::UnsupportedFormat)?;
+
+  Ok(match size {
+    Some(size) => {
+      let (width, height) = fit(image.width as f32, image.height as f32, size);
+      image.resize(width, height)
+    }
+    None => image,
+  })
+}
+
+/// The size of an image scaled to fit in a `size` square.
+fn fit(width: f32, height: f32, size: u32) -> (u32, u32) {
+  let scale = size as f32 / width.max(height);
+  (
+    ((width * scale).round() as u32).max(1),
+    ((height * scale).round() as u32).max(1),
+  )
+}
+
+/// The smallest image at least as large as `size`, or the largest one.
+#[cfg(any(feature = "image-ico", feature = "image-icns", test))]
+fn best_fit<T>(
+  images: Vec<T>,
+  dimensions: impl Fn(&T) -> (u32, u32),
+  size: Option<u32>,
+) -> Option<T> {
+  let edge = |image: &T| {
+    let (width, height) = dimensions(image);
+    width.max(height)
+  };
+  match size {
+    Some(size) if images.iter().any(|i| edge(i) >= size) => images
+      .into_iter()
+      .filter(|i| edge(i) >= size)
+      .min_by_key(|i| edge(i)),
+    _ => images.into_iter().max_by_key(|i| edge(i)),
+  }
+}
+
+#[cfg(feature = "image-png")]
+fn decode_png(bytes: &[u8]) -> Result<RgbaImage> {
+  let mut decoder = png::Decoder::new(std::io::Cursor::new(bytes));
+  // expands the palettes and the low bit depths, and strips 16 bit channels
+  decoder.set_transformations(png::Transformations::normalize_to_color8());
+  let mut reader = decoder.read_info()?;
+  let mut buf = vec![0; reader.output_buffer_size()];
+  let info = reader.next_frame(&mut buf)?;
+  buf.truncate(info.buffer_size());
+
+  let rgba = match info.color_type {
+    png::ColorType::Rgb => buf
+      .chunks_exact(3)
+      .flat_map(|p| [p[0], p[1], p[2], 0xFF])
+      .collect(),
+    png::ColorType::Grayscale => buf.iter().flat_map(|&l| [l, l, l, 0xFF]).collect(),
+    png::ColorType::GrayscaleAlpha => buf
+      .chunks_exact(2)
+      .flat_map(|p| [p[0], p[0], p[0], p[1]])
+      .collect(),
+    png::ColorType::Rgba | png::ColorType::Indexed => buf,
+  };
+
+  Ok(RgbaImage {
+    rgba,
+    width: info.width,
+    height: info.height,
+  })
+}
+
+#[cfg(feature = "image-ico")]
+fn decode_ico(bytes: &[u8], size: Option<u32>) -> Result<RgbaImage> {
+  let icon_dir = ico::IconDir::read(std::io::Cursor::new(bytes))?;
+  let entry = best_fit(
+    icon_dir.entries().iter().collect(),
+    |e| (e.width(), e.height()),
+    size,
+  )
+  .ok_or(Error::Empty)?;
+  let image = entry.decode()?;
+
+  Ok(RgbaImage {
+    width: image.width(),
+    height: image.height(),
+    rgba: image.rgba_data().to_vec(),
+  })
+}
+
+#[cfg(feature = "image-icns")]
+fn decode_icns(bytes: &[u8], size: Option<u32>) -> Result<RgbaImage> {
+  let family = icns::IconFamily::read(std::io::Cursor::new(bytes))?;
+  let icon_type = best_fit(
+    family.available_icons(),
+    |t| (t.pixel_width(), t.pixel_height()),
+    size,
+  )
+  .ok_or(Error::Empty)?;
+  let image = family
+    .get_icon_with_type(icon_type)?
+    .convert_to(icns::PixelFormat::RGBA);
+
+  Ok(RgbaImage {
+    width: image.width(),
+    height: image.height(),
+    rgba: image.into_data().into_vec(),
+  })
+}
+
+#[cfg(feature = "image-svg")]
+fn rasterize_svg(bytes: &[u8], size: Option<u32>) -> Result<RgbaImage> {
+  use resvg::{tiny_skia, usvg};
+
+  let tree = usvg::Tree::from_data(bytes, &usvg::Options::default())?;
+  let svg_size = tree.size();
+  let (width, height) = match size {
+    Some(size) => fit(svg_size.width(), svg_size.height(), size),
+    None => (
+      svg_size.width().ceil() as u32,
+      svg_size.height().ceil() as u32,
+    ),
+  };
+
+  let mut pixmap =
+    tiny_skia::Pixmap::new(width, height).ok_or(Error::InvalidSize(width, height))?;
+  let transform = tiny_skia::Transform::from_scale(
+    width as f32 / svg_size.width(),
+    height as f32 / svg_size.height(),
+  );
+  resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+  let mut rgba = pixmap.take();
+  // tiny-skia pixels are premultiplied
+  for pixel in rgba.chunks_exact_mut(4) {
+    let alpha = pixel[3] as u32;
+    if alpha != 0 && alpha != 255 {
+      for channel in &mut pixel[..3] {
+        *channel = ((*channel as u32 * 255 + alpha / 2) / alpha).min(255) as u8;
+      }
+    }
+  }
+
+  Ok(RgbaImage {
+    rgba,
+    width,
+    height,
+  })
+}
+
+impl RgbaImage {
+  /// Resizes the image with a Lanczos filter, which keeps the downscaled icons sharp.
+  ///
+  /// The aspect ratio is not preserved.
+  pub fn resize(&self, width: u32, height: u32) -> RgbaImage {
+    if (width, height) == (self.width, self.height) || width == 0 || height == 0 {
+      return RgbaImage {
+        rgba: if width == 0 || height == 0 {
+          Vec::new()
+        } else {
+          self.rgba.clone()
+        },
+        width,
+        height,
+      };
+    }
+
+    // the channels are premultiplied so the transparent pixels don't bleed their color
+    let premultiplied = self
+      .rgba
+      .chunks_exact(4)
+      .flat_map(|p| {
+        let alpha = p[3] as f32 / 255.;
+        [
+          p[0] as f32 * alpha,
+          p[1] as f32 * alpha,
+          p[2] as f32 * alpha,
+          p[3] as f32,
+        ]
+      })
+      .collect::<Vec<_>>();
+
+    let horizontal = resample(
+      &premultiplied,
+      (self.width, self.height),
+      width,
+      Axis::Horizontal,
+    );
+    let resized = resample(&horizontal, (width, self.height), height, Axis::Vertical);
+
+    let rgba = resized
+      .chunks_exact(4)
+      .flat_map(|p| {
+        let alpha = p[3].clamp(0., 255.);
+        let unpremultiply = |c: f32| {
+          if alpha > 0. {
+            (c * 255. / alpha).round().clamp(0., 255.) as u8
+          } else {
+            0
+          }
+        };
+        [
+          unpremultiply(p[0]),
+          unpremultiply(p[1]),
+          unpremultiply(p[2]),
+          alpha.round() as u8,
+        ]
+      })
+      .collect();
+
+    RgbaImage {
+      rgba,
+      width,
+      height,
+    }
+  }
+}
+
+#[derive(Clone, Copy)]
+enum Axis {
+  Horizontal,
+  Vertical,
+}
+
+fn lanczos3(x: f32) -> f32 {
+  if x == 0. {
+    1.
+  } else if x.abs() < 3. {
+    let x = x * PI;
+    3. * x.sin() * (x / 3.).sin() / (x * x)
+  } else {
+    0.
+  }
+}
+
+/// The first source pixel and the normalized weights of the source pixels of each destination pixel.
+fn weights(source_len: u32, len: u32) -> Vec<(usize, Vec<f32>)> {
+  let scale = source_len as f32 / len as f32;
+  // the filter is stretched when downscaling so every source pixel contributes
+  let filter_scale = scale.max(1.);
+  let support = 3. * filter_scale;
+
+  (0..len)
+    .map(|i| {
+      let center = (i as f32 + 0.5) * scale;
+      let start = (center - support).floor().max(0.) as usize;
+      let end = ((center + support).ceil() as usize).min(source_len as usize);
+      let mut weights = (start..end)
+        .map(|j| lanczos3((j as f32 + 0.5 - center) / filter_scale))
+        .collect::<Vec<_>>();
+      let sum = weights.iter().sum::<f32>();
+      if sum != 0. {
+        weights.iter_mut().for_each(|w| *w /= sum);
+      }
+      (start, weights)
+    })
+    .collect()
+}
+
+/// Resamples the 4 channel pixels along one axis.
+fn resample(pixels: &[f32], (width, height): (u32, u32), len: u32, axis: Axis) -> Vec<f32> {
+  let (source_len, lines, out_width, out_height) = match axis {
+    Axis::Horizontal => (width, height, len, height),
+    Axis::Vertical => (height, width, width, len),
+  };
+  let index = |along: usize, line: usize, row_width: u32| match axis {
+    Axis::Horizontal => (line * row_width as usize + along) * 4,
+    Axis::Vertical => (along * row_width as usize + line) * 4,
+  };
+
+  let weights = weights(source_len, len);
+  let mut out = vec![0.; out_width as usize * out_height as usize * 4];
+  for line in 0..lines as usize {
+    for (i, (start, weights)) in weights.iter().enumerate() {
+      let o = index(i, line, out_width);
+      for (k, weight) in weights.iter().enumerate() {
+        let s = index(start + k, line, width);
+        for c in 0..4 {
+          out[o + c] += pixels[s + c] * weight;
+        }
+      }
+    }
+  }
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn solid(width: u32, height: u32, pixel: [u8; 4]) -> RgbaImage {
+    RgbaImage {
+      rgba: pixel.repeat((width * height) as usize),
+      width,
+      height,
+    }
+  }
+
+  #[test]
+  fn format_detection() {
+    assert_eq!(ImageFormat::from_extension("PNG"), Some(ImageFormat::Png));
+    assert_eq!(ImageFormat::from_extension("jpg"), None);
+    assert_eq!(
+      ImageFormat::from_bytes(b"\x89PNG\r\n\x1a\n..."),
+      Some(ImageFormat::Png)
+    );
+    assert_eq!(
+      ImageFormat::from_bytes(&[0, 0, 1, 0, 2, 0]),
+      Some(ImageFormat::Ico)
+    );
+    assert_eq!(
+      ImageFormat::from_bytes(b"icns...."),
+      Some(ImageFormat::Icns)
+    );
+    assert_eq!(
+      ImageFormat::from_bytes(
+        b"<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\"/>"
+      ),
+      Some(ImageFormat::Svg)
+    );
+    assert_eq!(ImageFormat::from_bytes(b"GIF89a"), None);
+  }
+
+  #[test]
+  fn best_fit_frame() {
+    let sizes = vec![16, 32, 48, 256];
+    let pick = |size| best_fit(sizes.clone(), |s| (*s, *s), size);
+    assert_eq!(pick(None), Some(256));
+    assert_eq!(pick(Some(32)), Some(32));
+    assert_eq!(pick(Some(40)), Some(48));
+    assert_eq!(pick(Some(512)), Some(256));
+    assert_eq!(fit(256., 128., 32), (32, 16));
+  }
+
+  #[test]
+  fn resize() {
+    let image = solid(64, 32, [10, 200, 30, 255]);
+    let resized = image.resize(16, 8);
+    assert_eq!((resized.width, resized.height), (16, 8));
+    assert_eq!(resized.rgba, [10, 200, 30, 255].repeat(16 * 8));
+
+    let upscaled = image.resize(100, 50);
+    assert_eq!(upscaled.rgba.len(), 100 * 50 * 4);
+    assert!(upscaled.rgba.chunks(4).all(|p| p == [10, 200, 30, 255]));
+
+    // transparent pixels don't darken the edges
+    let mut image = solid(4, 4, [0, 0, 0, 0]);
+    image.rgba[..8].copy_from_slice(&[255, 0, 0, 255, 255, 0, 0, 255]);
+    let resized = image.resize(2, 2);
+    assert_eq!(&resized.rgba[..3], &[255, 0, 0]);
+  }
+
+  #[cfg(feature = "image-png")]
+  #[test]
+  fn decode_png_sized() {
+    let mut bytes = Vec::new();
+    {
+      let mut encoder = png::Encoder::new(&mut bytes, 8, 4);
+      encoder.set_color(png::ColorType::Rgb);
+      encoder.set_depth(png::BitDepth::Eight);
+      let mut writer = encoder.write_header().unwrap();
+      writer.write_image_data(&[40, 80, 120].repeat(32)).unwrap();
+    }
+
+    let format = ImageFormat::from_bytes(&bytes).unwrap();
+    let image = decode(&bytes, format, None).unwrap();
+    assert_eq!((image.width, image.height), (8, 4));
+    assert_eq!(image.rgba, [40, 80, 120, 255].repeat(32));
+
+    let image = decode(&bytes, format, Some(4)).unwrap();
+    assert_eq!((image.width, image.height), (4, 2));
+  }
+}
@@ -26,6 +26,7 @@ pub mod acl;
 pub mod assets;
 pub mod config;
 pub mod html;
+pub mod image;
 pub mod io;
 pub mod mime_type;
 pub mod platform;
@@ -275,6 +276,12 @@ pub struct Env {
   /// The APPDIR environment variable.
   #[cfg(target_os = "linux")]
   pub appdir: Option<std::ffi::OsString>,
+  /// The sandbox the app is running in.
+  #[cfg(target_os = "linux")]
+  pub sandbox: Option<platform::Sandbox>,
+  /// The SNAP environment variable, the directory the snap is mounted on.
+  #[cfg(target_os = "linux")]
+  pub snap: Option<std::ffi::OsString>,
   /// The command line arguments of the current process.
   pub args_os: Vec<OsString>,
 }
@@ -290,6 +297,10 @@ impl Default for Env {
         appimage: std::env::var_os("APPIMAGE"),
         #[cfg(target_os = "linux")]
         appdir: std::env::var_os("APPDIR"),
+        #[cfg(target_os = "linux")]
+        sandbox: platform::sandbox(),
+        #[cfg(target_os = "linux")]
+        snap: std::env::var_os("SNAP"),
         args_os,
       };
       if env.appimage.is_some() || env.appdir.is_some() {
@@ -55,6 +55,40 @@ impl Display for Target {
   }
 }
 
+/// A Linux application sandbox.
+#[derive(PartialEq, Eq, Copy, Debug, Clone)]
+#[non_exhaustive]
+pub enum Sandbox {
+  /// The app is running inside a [Flatpak](https://flatpak.org) sandbox.
+  ///
+  /// The app is installed under `/app` and `$XDG_*` directories point to `~/.var/app/$FLATPAK_ID`.
+  Flatpak,
+  /// The app is running inside a [Snap](https://snapcraft.io) sandbox.
+  ///
+  /// The app is installed under `$SNAP` and `$HOME` points to `$SNAP_USER_DATA`.
+  Snap,
+}
+
+/// Detects the sandbox the current process runs in, if any.
+///
+/// This is also available as [`Env::sandbox`].
+///
+/// ## Platform-specific
+///
+/// - **Windows / macOS / Android / iOS:** Always returns [`None`].
+pub fn sandbox() -> Option<Sandbox> {
+  #[cfg(target_os = "linux")]
+  {
+    if std::path::Path::new("/.flatpak-info").is_file() {
+      return Some(Sandbox::Flatpak);
+    }
+    if std::env::var_os("SNAP").is_some() && std::env::var_os("SNAP_NAME").is_some() {
+      return Some(Sandbox::Snap);
+    }
+  }
+  None
+}
+
 impl Target {
   /// Parses the target from the given target triple.
   pub fn from_triple(target: &str) -> Self {
@@ -309,7 +343,13 @@ fn resource_dir_from<P: AsRef<std::path::Path>>(
 
   #[cfg(target_os = "linux")]
   {
-    res = if curr_dir.ends_with("/data/usr/bin") {
+    res = if env.sandbox == Some(Sandbox::Flatpak) {
+      // flatpak apps are installed with the `/app` prefix
+      Ok(PathBuf::from(format!("/app/lib/{}", package_info.name)))
+    } else if let (Some(Sandbox::Snap), Some(snap)) = (env.sandbox, &env.snap) {
+      let snap: &std::path::Path = snap.as_ref();
+      Ok(snap.join("usr/lib").join(&package_info.name))
+    } else if curr_dir.ends_with("/data/usr/bin") {
       // running from the deb bundle dir
       exe_dir
         .join(format!("../lib/{}", package_info.name))
@@ -404,4 +444,32 @@ mod tests {
     #[cfg(windows)]
     assert_eq!(resource_dir.unwrap(), path.parent().unwrap());
   }
+
+  #[cfg(target_os = "linux")]
+  #[test]
+  fn resolve_sandboxed_resource_dir() {
+    let package_info = PackageInfo {
+      name: "MyApp".into(),
+      version: "1.0.0".parse().unwrap(),
+      authors: "",
+      description: "",
+      crate_name: "my-app",
+    };
+    let path = PathBuf::from("/app/bin/my-app");
+
+    let env = Env {
+      sandbox: Some(super::Sandbox::Flatpak),
+      ..Default::default()
+    };
+    let resource_dir = super::resource_dir_from(&path, &package_info, &env).unwrap();
+    assert_eq!(resource_dir, PathBuf::from("/app/lib/MyApp"));
+
+    let env = Env {
+      sandbox: Some(super::Sandbox::Snap),
+      snap: Some("/snap/my-app/12".into()),
+      ..Default::default()
+    };
+    let resource_dir = super::resource_dir_from(&path, &package_info, &env).unwrap();
+    assert_eq!(resource_dir, PathBuf::from("/snap/my-app/12/usr/lib/MyApp"));
+  }
 }
@@ -81,6 +81,7 @@ const PLUGINS: &[(&str, &[(&str, bool)])] = &[
       ("show", false),
       ("hide", false),
       ("close", false),
+      ("close_with_result", false),
       ("destroy", false),
       ("set_decorations", false),
       ("set_shadow", false),
@@ -242,6 +243,10 @@ fn main() {
   alias("desktop", !mobile);
   alias("mobile", mobile);
 
+  // every feature is checked so they are all validated by the `aliased_features_exist` test
+  let image_features = ["image-ico", "image-png", "image-icns", "image-svg"].map(has_feature);
+  alias("image_decoding", image_features.contains(&true));
+
   let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
 
   let checked_features_out_path = out_dir.join("checked_features");
@@ -25,7 +25,7 @@ use crate::{
 #[cfg(desktop)]
 use crate::menu::{Menu, MenuEvent};
 #[cfg(all(desktop, feature = "tray-icon"))]
-use crate::tray::{TrayIcon, TrayIconBuilder, TrayIconEvent, TrayIconId};
+use crate::tray::{TrayIcon, TrayIconAnimation, TrayIconBuilder, TrayIconEvent, TrayIconId};
 use raw_window_handle::HasDisplayHandle;
 use serialize_to_javascript::{default_template, DefaultTemplate, Template};
 use tauri_macros::default_runtime;
@@ -72,6 +72,11 @@ pub type ChannelInterceptor<R> =
 /// The exit code on [`RunEvent::ExitRequested`] when [`AppHandle#method.restart`] is called.
 pub const RESTART_EXIT_CODE: i32 = i32::MAX;
 
+/// File in the app data directory where the asset protocol scope grants are saved
+/// when [`AssetProtocolConfig::persist_scope`](crate::utils::config::AssetProtocolConfig::persist_scope) is enabled.
+#[cfg(all(desktop, feature = "protocol-asset"))]
+const ASSET_PROTOCOL_SCOPE_FILE: &str = "asset-protocol-scope.json";
+
 /// Api exposed on the `ExitRequested` event.
 #[derive(Debug)]
 pub struct ExitRequestApi(Sender<ExitRequestedEventAction>);
@@ -223,6 +228,14 @@ pub enum RunEvent {
   ///
   /// This event is useful as a place to put your code that should be run after all state-changing events have been handled and you want to do stuff (updating state, performing calculations, etc) that happens as the “main body” of your event loop.
   MainEventsCleared,
+  /// Emitted when a monitor is connected, disconnected or rearranged, or when its work area or scale factor changes.
+  ///
+  /// Use it to move the windows that ended up outside of the available monitors back on screen.
+  #[non_exhaustive]
+  MonitorsChanged {
+    /// The available monitors.
+    monitors: Vec<crate::window::Monitor>,
+  },
   /// Emitted when the user wants to open the specified resource with the app.
   #[cfg(any(target_os = "macos", target_os = "ios"))]
   #[cfg_attr(docsrs, doc(cfg(any(target_os = "macos", feature = "ios"))))]
@@ -301,6 +314,7 @@ impl<R: Runtime> AssetResolver<R> {
             bytes,
             mime_type,
             csp_header: None,
+            etag: None,
           }
         });
       }
@@ -762,6 +776,81 @@ macro_rules! shared_app_impl {
         Ok(prev_menu)
       }
 
+      /// The accelerators bound to the menu items, which can be queried and rebound.
+      ///
+      /// # Examples
+      /// ```
+      /// use tauri::menu::{MenuBuilder, MenuItemBuilder};
+      ///
+      /// tauri::Builder::default()
+      ///   .setup(|app| {
+      ///     let save = MenuItemBuilder::with_id("save", "Save")
+      ///       .accelerator("CmdOrCtrl+S")
+      ///       .build(app)?;
+      ///     let menu = MenuBuilder::new(app).item(&save).build()?;
+      ///     app.set_menu(menu)?;
+      ///
+      ///     // load the user keymap
+      ///     app.accelerators().rebind("save", Some("CmdOrCtrl+Shift+S"))?;
+      ///     assert_eq!(app.accelerators().items("Shift+CmdOrCtrl+S")?, ["save"]);
+      ///     Ok(())
+      ///   });
+      /// ```
+      #[cfg(desktop)]
+      pub fn accelerators(&self) -> &crate::menu::AcceleratorRegistry<R> {
+        &self.manager.menu.accelerators
+      }
+
+      /// Sets the provider resolving the translation keys of the menu items and relabels them.
+      ///
+      /// See [`MenuItemBuilder::text_key`](crate::menu::MenuItemBuilder::text_key).
+      #[cfg(desktop)]
+      pub fn set_locale_provider<P: crate::menu::LocaleProvider + 'static>(
+        &self,
+        provider: P,
+      ) -> crate::Result<()> {
+        self
+          .manager
+          .menu
+          .localization
+          .set_provider(Box::new(provider))
+      }
+
+      /// Sets the locale used to translate the menu items
+      /// and relabels the app, window, tray and context menus.
+      ///
+      /// # Examples
+      /// ```
+      /// use std::collections::HashMap;
+      /// use tauri::menu::{MenuBuilder, MenuItemBuilder};
+      ///
+      /// tauri::Builder::default()
+      ///   .setup(|app| {
+      ///     let locales = HashMap::from([(
+      ///       "fr".to_string(),
+      ///       HashMap::from([("open".to_string(), "Ouvrir".to_string())]),
+      ///     )]);
+      ///     app.set_locale_provider(locales)?;
+      ///
+      ///     let open = MenuItemBuilder::new("Open").text_key("open").build(app)?;
+      ///     let menu = MenuBuilder::new(app).item(&open).build()?;
+      ///     app.set_menu(menu)?;
+      ///
+      ///     app.set_locale("fr")?;
+      ///     Ok(())
+      ///   });
+      /// ```
+      #[cfg(desktop)]
+      pub fn set_locale<L: Into<String>>(&self, locale: L) -> crate::Result<()> {
+        self.manager.menu.localization.set_locale(locale.into())
+      }
+
+      /// The locale used to translate the menu items.
+      #[cfg(desktop)]
+      pub fn locale(&self) -> Option<String> {
+        self.manager.menu.localization.locale()
+      }
+
       /// Remove the app-wide menu and returns it.
       ///
       /// If a window was not created with an explicit menu or had one set explicitly,
@@ -871,6 +960,8 @@ macro_rules! shared_app_impl {
         #[cfg(all(desktop, feature = "tray-icon"))]
         self.manager.tray.icons.lock().unwrap().clear();
         self.manager.resources_table().clear();
+        #[cfg(desktop)]
+        self.manager.window.state.save(self);
         for (_, window) in self.manager.windows() {
           window.resources_table().clear();
           #[cfg(windows)]
@@ -1236,6 +1327,10 @@ pub struct Builder<R: Runtime> {
   #[allow(unused)]
   enable_macos_default_menu: bool,
 
+  /// How the conflicting menu accelerators are reported.
+  #[cfg(desktop)]
+  accelerator_conflicts: crate::menu::AcceleratorConflicts,
+
   /// Window event handlers that listens to all windows.
   window_event_listeners: Vec<GlobalWindowEventListener<R>>,
 
@@ -1305,6 +1400,8 @@ impl<R: Runtime> Builder<R> {
       #[cfg(desktop)]
       menu_event_listeners: Vec::new(),
       enable_macos_default_menu: true,
+      #[cfg(desktop)]
+      accelerator_conflicts: Default::default(),
       window_event_listeners: Vec::new(),
       webview_event_listeners: Vec::new(),
       device_event_filter: Default::default(),
@@ -1614,6 +1711,8 @@ tauri::Builder::default()
 
   /// Sets the menu to use on all windows.
   ///
+  /// This takes precedence over the menu declared in the `app > menu` configuration.
+  ///
   /// # Examples
   /// ```
   /// use tauri::menu::{Menu, MenuItem, PredefinedMenuItem, Submenu};
@@ -1678,6 +1777,24 @@ tauri::Builder::default()
     self
   }
 
+  /// Sets how the menu accelerators conflicts are reported. Defaults to [`AcceleratorConflicts::Warn`](crate::menu::AcceleratorConflicts::Warn).
+  ///
+  /// The menus are checked when they are built, and the items when they are rebound with [`AcceleratorRegistry::rebind`](crate::menu::AcceleratorRegistry::rebind).
+  ///
+  /// # Examples
+  /// ```
+  /// use tauri::menu::AcceleratorConflicts;
+  ///
+  /// tauri::Builder::default()
+  ///   .accelerator_conflicts(AcceleratorConflicts::Error);
+  /// ```
+  #[must_use]
+  #[cfg(desktop)]
+  pub fn accelerator_conflicts(mut self, conflicts: crate::menu::AcceleratorConflicts) -> Self {
+    self.accelerator_conflicts = conflicts;
+    self
+  }
+
   /// Registers a window event handler for all windows.
   ///
   /// # Examples
@@ -1855,7 +1972,17 @@ tauri::Builder::default()
     feature = "tracing",
     tracing::instrument(name = "app::build", skip_all)
   )]
-  pub fn build(mut self, context: Context<R>) -> crate::Result<App<R>> {
+  pub fn build(mut self, #[allow(unused_mut)] mut context: Context<R>) -> crate::Result<App<R>> {
+    #[cfg(desktop)]
+    if self.menu.is_none() {
+      if let Some(menu_config) = context.config.app.menu.clone() {
+        let icons = std::mem::take(&mut context.menu_icons);
+        self.menu = Some(Box::new(move |app_handle| {
+          crate::menu::Menu::from_config(app_handle, &menu_config, &icons)
+        }));
+      }
+    }
+
     #[cfg(target_os = "macos")]
     if self.menu.is_none() && self.enable_macos_default_menu {
       self.menu = Some(Box::new(|app_handle| {
@@ -1881,6 +2008,12 @@ tauri::Builder::default()
       self.invoke_key,
     ));
 
+    #[cfg(desktop)]
+    manager
+      .menu
+      .accelerators
+      .set_conflicts(self.accelerator_conflicts);
+
     #[cfg(any(
       target_os = "linux",
       target_os = "dragonfly",
@@ -1988,12 +2121,33 @@ tauri::Builder::default()
     let env = Env::default();
     app.manage(env);
 
+    #[cfg(feature = "protocol-asset")]
+    let asset_protocol = {
+      let config = &app.config().app.security.asset_protocol;
+      let scope = crate::scope::fs::Scope::new(&app, &config.scope)?;
+      #[cfg(desktop)]
+      let scope = if config.persist_scope {
+        let path = app.path().app_data_dir()?.join(ASSET_PROTOCOL_SCOPE_FILE);
+        match scope.clone().persisted(path) {
+          Ok(scope) => scope,
+          Err(e) => {
+            log::error!("failed to restore the persisted asset protocol scope: {e}");
+            scope
+          }
+        }
+      } else {
+        scope
+      };
+      #[cfg(mobile)]
+      if config.persist_scope {
+        log::warn!("persisting the asset protocol scope is not supported on mobile");
+      }
+      scope
+    };
+
     app.manage(Scopes {
       #[cfg(feature = "protocol-asset")]
-      asset_protocol: crate::scope::fs::Scope::new(
-        &app,
-        &app.config().app.security.asset_protocol.scope,
-      )?,
+      asset_protocol,
     });
 
     app.manage(ChannelDataIpcQueue::default());
@@ -2038,6 +2192,15 @@ tauri::Builder::default()
         if let Some(tooltip) = &tray_config.tooltip {
           tray = tray.tooltip(tooltip);
         }
+        if let Some(badge) = tray_config.badge {
+          tray = tray.badge(badge.into());
+        }
+        if let Some(animation) = &tray_config.animation {
+          let frames = app.manager.tray.animation.iter().cloned();
+          let delay = std::time::Duration::from_millis(animation.delay);
+          tray =
+            tray.animation(TrayIconAnimation::from_frames(frames, delay).repeat(animation.repeat));
+        }
         tray.build(handle)?;
       }
     }
@@ -2124,6 +2287,19 @@ fn setup<R: Runtime>(app: &mut App<R>) -> crate::Result<()> {
   app.ran_setup = true;
 
   for window_config in app.config().app.windows.iter().filter(|w| w.create) {
+    #[cfg(all(desktop, feature = "unstable"))]
+    if window_config.layout.is_some() {
+      crate::window::WindowBuilder::from_config(app.handle(), window_config)?.build()?;
+      continue;
+    }
+    #[cfg(not(all(desktop, feature = "unstable")))]
+    if window_config.layout.is_some() {
+      log::warn!(
+        "the layout of the `{}` window requires the `unstable` feature, creating it with its own webview",
+        window_config.label
+      );
+    }
+
     WebviewWindowBuilder::from_config(app.handle(), window_config)?.build()?;
   }
 
@@ -2184,10 +2360,18 @@ fn on_event_loop_event<R: Runtime>(
     }
     RuntimeRunEvent::Resumed => RunEvent::Resumed,
     RuntimeRunEvent::MainEventsCleared => RunEvent::MainEventsCleared,
+    RuntimeRunEvent::MonitorsChanged { monitors } => RunEvent::MonitorsChanged {
+      monitors: monitors.into_iter().map(Into::into).collect(),
+    },
     RuntimeRunEvent::UserEvent(t) => {
       match t {
         #[cfg(desktop)]
         EventLoopMessage::MenuEvent(ref e) => {
+          // the bound values and the radio groups are updated before the listeners run
+          let bindings = &app_handle.manager.menu.bindings;
+          if let Err(err) = bindings.handle_click(app_handle, e.id()) {
+            log::error!("failed to update the menu item bindings: {err}");
+          }
           for listener in &*app_handle
             .manager
             .menu
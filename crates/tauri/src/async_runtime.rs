@@ -223,6 +223,9 @@ fn default_runtime() -> GlobalRuntime {
 /// For convenience, this method takes a [`TokioHandle`].
 /// Note that you cannot drop the underlying [`TokioRuntime`].
 ///
+/// The runtime must have its time driver enabled, with `enable_time` or `enable_all` on the runtime builder,
+/// since it runs the command timeouts. Otherwise invoking a command with a timeout panics.
+///
 /// # Examples
 ///
 /// ```rust
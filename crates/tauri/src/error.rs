@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::fmt;
+use std::{fmt, path::PathBuf};
 
 /// A generic boxed error.
 #[derive(Debug)]
@@ -81,10 +81,9 @@ pub enum Error {
   /// Invalid glob pattern.
   #[error("invalid glob pattern: {0}")]
   GlobPattern(#[from] glob::PatternError),
-  /// Image error.
-  #[cfg(any(feature = "image-png", feature = "image-ico"))]
-  #[error("failed to process image: {0}")]
-  Image(#[from] image::error::ImageError),
+  /// Image decoding error.
+  #[error("failed to decode image: {0}")]
+  ImageDecoding(#[from] tauri_utils::image::Error),
   /// The Window's raw handle is invalid for the platform.
   #[error("Unexpected `raw_window_handle` for the current platform")]
   InvalidWindowHandle,
@@ -103,6 +102,22 @@ pub enum Error {
   #[error(transparent)]
   #[cfg(desktop)]
   BadMenuIcon(#[from] muda::BadIcon),
+  /// Invalid menu accelerator.
+  #[error("invalid menu accelerator: {0}")]
+  #[cfg(desktop)]
+  InvalidAccelerator(muda::accelerator::AcceleratorParseError),
+  /// Menu accelerators are conflicting, see [`crate::menu::AcceleratorConflicts`].
+  #[error("accelerator conflict: {0}")]
+  #[cfg(desktop)]
+  AcceleratorConflict(String),
+  /// The menu item was not found.
+  #[error("unknown menu item `{0}`")]
+  #[cfg(desktop)]
+  UnknownMenuItem(String),
+  /// The state bound to a menu item is not managed, see [`crate::menu::MenuBinding::state`].
+  #[error("state `{0}` is not managed")]
+  #[cfg(desktop)]
+  StateNotManaged(&'static str),
   /// Tray icon error.
   #[error("tray icon error: {0}")]
   #[cfg(all(desktop, feature = "tray-icon"))]
@@ -113,6 +128,11 @@ pub enum Error {
   #[cfg(all(desktop, feature = "tray-icon"))]
   #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "tray-icon"))))]
   BadTrayIcon(#[from] tray_icon::BadIcon),
+  /// The key of a persisted scope could not be read from or saved to the credential store of the OS.
+  #[error("failed to access the key of the persisted scope: {0}")]
+  #[cfg(all(desktop, feature = "protocol-asset"))]
+  #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "protocol-asset"))))]
+  ScopeKey(#[from] keyring::Error),
   /// Path does not have a parent.
   #[error("path does not have a parent")]
   NoParent,
@@ -122,6 +142,9 @@ pub enum Error {
   /// Path does not have a basename.
   #[error("path does not have a basename")]
   NoBasename,
+  /// The path is not allowed by the file system scope.
+  #[error("path not allowed on the configured scope: {0}")]
+  PathNotAllowed(PathBuf),
   /// Cannot resolve current directory.
   #[error("failed to read current dir: {0}")]
   CurrentDir(std::io::Error),
@@ -129,6 +152,12 @@ pub enum Error {
   #[cfg(not(target_os = "android"))]
   #[error("unknown path")]
   UnknownPath,
+  /// The base directory name or path is invalid.
+  #[error("invalid base directory `{0}`: {1}")]
+  InvalidBaseDirectory(String, &'static str),
+  /// The base directory was not registered.
+  #[error("unknown base directory `{0}`")]
+  UnknownBaseDirectory(String),
   /// Failed to invoke mobile plugin.
   #[cfg(target_os = "android")]
   #[error(transparent)]
@@ -160,6 +189,12 @@ pub enum Error {
   /// Bad `__TAURI_INVOKE_KEY__` value received in ipc message.
   #[error("bad __TAURI_INVOKE_KEY__ value received in ipc message")]
   InvokeKey,
+  /// Failed to resolve a capability.
+  #[error("failed to resolve capability: {0}")]
+  Capability(#[from] tauri_utils::acl::Error),
+  /// The command did not respond before its deadline.
+  #[error("command `{0}` timed out")]
+  InvokeTimeout(String),
 }
 
 impl From<getrandom::Error> for Error {
@@ -46,32 +46,103 @@ impl<'a> Image<'a> {
 
   /// Creates a new image using the provided bytes.
   ///
-  /// Only `ico` and `png` are supported (based on activated feature flag).
-  #[cfg(any(feature = "image-ico", feature = "image-png"))]
-  #[cfg_attr(docsrs, doc(cfg(any(feature = "image-ico", feature = "image-png"))))]
+  /// The `png`, `ico`, `icns` and `svg` formats are supported, based on the activated feature flags.
+  /// The largest image of `ico` and `icns` icons is used and `svg` images are rasterized at their own size.
+  #[cfg(image_decoding)]
+  #[cfg_attr(
+    docsrs,
+    doc(cfg(any(
+      feature = "image-ico",
+      feature = "image-png",
+      feature = "image-icns",
+      feature = "image-svg"
+    )))
+  )]
   pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
-    use image::GenericImageView;
+    Self::decode(bytes, None, None)
+  }
 
-    let img = image::load_from_memory(bytes)?;
-    let pixels = img
-      .pixels()
-      .flat_map(|(_, _, pixel)| pixel.0)
-      .collect::<Vec<_>>();
-    Ok(Self {
-      rgba: Cow::Owned(pixels),
-      width: img.width(),
-      height: img.height(),
-    })
+  /// Creates a new image using the provided bytes, fitting in a square of `size` logical pixels.
+  ///
+  /// The image is decoded for the `scale_factor` of the monitor it is shown on:
+  /// the best fitting image of `ico` and `icns` icons is used, `svg` images are rasterized at the target size
+  /// and the other images are resized, see [`Self::resize`].
+  #[cfg(image_decoding)]
+  #[cfg_attr(
+    docsrs,
+    doc(cfg(any(
+      feature = "image-ico",
+      feature = "image-png",
+      feature = "image-icns",
+      feature = "image-svg"
+    )))
+  )]
+  pub fn from_bytes_scaled(bytes: &[u8], size: u32, scale_factor: f64) -> crate::Result<Self> {
+    Self::decode(bytes, None, Some(physical_size(size, scale_factor)))
   }
 
   /// Creates a new image using the provided path.
   ///
-  /// Only `ico` and `png` are supported (based on activated feature flag).
-  #[cfg(any(feature = "image-ico", feature = "image-png"))]
-  #[cfg_attr(docsrs, doc(cfg(any(feature = "image-ico", feature = "image-png"))))]
+  /// The `png`, `ico`, `icns` and `svg` formats are supported, based on the activated feature flags.
+  /// The largest image of `ico` and `icns` icons is used and `svg` images are rasterized at their own size.
+  #[cfg(image_decoding)]
+  #[cfg_attr(
+    docsrs,
+    doc(cfg(any(
+      feature = "image-ico",
+      feature = "image-png",
+      feature = "image-icns",
+      feature = "image-svg"
+    )))
+  )]
   pub fn from_path<P: AsRef<std::path::Path>>(path: P) -> crate::Result<Self> {
-    let bytes = std::fs::read(path)?;
-    Self::from_bytes(&bytes)
+    let bytes = std::fs::read(path.as_ref())?;
+    Self::decode(&bytes, Some(path.as_ref()), None)
+  }
+
+  /// Creates a new image using the provided path, fitting in a square of `size` logical pixels.
+  ///
+  /// See [`Self::from_bytes_scaled`].
+  #[cfg(image_decoding)]
+  #[cfg_attr(
+    docsrs,
+    doc(cfg(any(
+      feature = "image-ico",
+      feature = "image-png",
+      feature = "image-icns",
+      feature = "image-svg"
+    )))
+  )]
+  pub fn from_path_scaled<P: AsRef<std::path::Path>>(
+    path: P,
+    size: u32,
+    scale_factor: f64,
+  ) -> crate::Result<Self> {
+    let bytes = std::fs::read(path.as_ref())?;
+    Self::decode(
+      &bytes,
+      Some(path.as_ref()),
+      Some(physical_size(size, scale_factor)),
+    )
+  }
+
+  #[cfg(image_decoding)]
+  fn decode(
+    bytes: &[u8],
+    path: Option<&std::path::Path>,
+    size: Option<u32>,
+  ) -> crate::Result<Self> {
+    use tauri_utils::image::{Error, ImageFormat};
+
+    let format = ImageFormat::from_bytes(bytes)
+      .or_else(|| {
+        path
+          .and_then(|p| p.extension())
+          .and_then(|e| ImageFormat::from_extension(&e.to_string_lossy()))
+      })
+      .ok_or(Error::UnsupportedFormat)?;
+    let image = tauri_utils::image::decode(bytes, format, size)?;
+    Ok(Image::new_owned(image.rgba, image.width, image.height))
   }
 
   /// Returns the RGBA data for this image, in row-major order from top to bottom.
@@ -89,6 +160,19 @@ impl<'a> Image<'a> {
     self.height
   }
 
+  /// Returns a copy of this image resized to `width` and `height`, with a Lanczos filter.
+  ///
+  /// The aspect ratio is not preserved.
+  pub fn resize(&self, width: u32, height: u32) -> Image<'static> {
+    let image = tauri_utils::image::RgbaImage {
+      rgba: self.rgba.to_vec(),
+      width: self.width,
+      height: self.height,
+    }
+    .resize(width, height);
+    Image::new_owned(image.rgba, image.width, image.height)
+  }
+
   /// Convert into a 'static owned [`Image`].
   /// This will allocate.
   pub fn to_owned(self) -> Image<'static> {
@@ -103,6 +187,11 @@ impl<'a> Image<'a> {
   }
 }
 
+#[cfg(image_decoding)]
+fn physical_size(size: u32, scale_factor: f64) -> u32 {
+  ((size as f64 * scale_factor).round() as u32).max(1)
+}
+
 impl<'a> From<Image<'a>> for crate::runtime::Icon<'a> {
   fn from(img: Image<'a>) -> Self {
     Self {
@@ -173,10 +262,10 @@ impl JsImage {
   pub fn into_img(self, resources_table: &ResourceTable) -> crate::Result<Arc<Image<'_>>> {
     match self {
       Self::Resource(rid) => resources_table.get::<Image<'static>>(rid),
-      #[cfg(any(feature = "image-ico", feature = "image-png"))]
+      #[cfg(image_decoding)]
       Self::Path(path) => Image::from_path(path).map(Arc::new).map_err(Into::into),
 
-      #[cfg(any(feature = "image-ico", feature = "image-png"))]
+      #[cfg(image_decoding)]
       Self::Bytes(bytes) => Image::from_bytes(&bytes).map(Arc::new).map_err(Into::into),
 
       Self::Rgba {
@@ -185,7 +274,7 @@ impl JsImage {
         height,
       } => Ok(Arc::new(Image::new_owned(rgba, width, height))),
 
-      #[cfg(not(any(feature = "image-ico", feature = "image-png")))]
+      #[cfg(not(image_decoding))]
       _ => Err(
         std::io::Error::new(
           std::io::ErrorKind::InvalidInput,
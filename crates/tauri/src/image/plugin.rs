@@ -21,7 +21,7 @@ fn new<R: Runtime>(
   Ok(rid)
 }
 
-#[cfg(any(feature = "image-ico", feature = "image-png"))]
+#[cfg(image_decoding)]
 #[command(root = "crate")]
 fn from_bytes<R: Runtime>(webview: Webview<R>, bytes: Vec<u8>) -> crate::Result<ResourceId> {
   let image = Image::from_bytes(&bytes)?.to_owned();
@@ -30,13 +30,13 @@ fn from_bytes<R: Runtime>(webview: Webview<R>, bytes: Vec<u8>) -> crate::Result<
   Ok(rid)
 }
 
-#[cfg(not(any(feature = "image-ico", feature = "image-png")))]
+#[cfg(not(image_decoding))]
 #[command(root = "crate")]
 fn from_bytes() -> std::result::Result<(), &'static str> {
-  Err("from_bytes is only supported if the `image-ico` or `image-png` Cargo features are enabled")
+  Err("from_bytes is only supported if one of the `image-png`, `image-ico`, `image-icns` or `image-svg` Cargo features is enabled")
 }
 
-#[cfg(any(feature = "image-ico", feature = "image-png"))]
+#[cfg(image_decoding)]
 #[command(root = "crate")]
 fn from_path<R: Runtime>(
   webview: Webview<R>,
@@ -48,10 +48,10 @@ fn from_path<R: Runtime>(
   Ok(rid)
 }
 
-#[cfg(not(any(feature = "image-ico", feature = "image-png")))]
+#[cfg(not(image_decoding))]
 #[command(root = "crate")]
 fn from_path() -> std::result::Result<(), &'static str> {
-  Err("from_path is only supported if the `image-ico` or `image-png` Cargo features are enabled")
+  Err("from_path is only supported if one of the `image-png`, `image-ico`, `image-icns` or `image-svg` Cargo features is enabled")
 }
 
 #[command(root = "crate")]
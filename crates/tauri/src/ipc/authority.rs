@@ -10,7 +10,7 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use tauri_utils::acl::{
-  capability::{Capability, CapabilityFile, PermissionEntry},
+  capability::{resolve_extends_with, Capability, CapabilityFile, PermissionEntry},
   manifest::Manifest,
   Value, APP_ACL_KEY,
 };
@@ -23,7 +23,7 @@ use tauri_utils::platform::Target;
 use url::Url;
 
 use crate::{ipc::InvokeError, sealed::ManagerBase, Runtime};
-use crate::{AppHandle, Manager, StateManager, Webview};
+use crate::{AppHandle, Manager, StateManager};
 
 use super::{CommandArg, CommandItem};
 
@@ -32,6 +32,8 @@ pub struct RuntimeAuthority {
   acl: BTreeMap<String, crate::utils::acl::manifest::Manifest>,
   allowed_commands: BTreeMap<String, Vec<ResolvedCommand>>,
   denied_commands: BTreeMap<String, Vec<ResolvedCommand>>,
+  /// The capabilities that runtime capabilities can extend.
+  capabilities: BTreeMap<String, Capability>,
   pub(crate) scope_manager: ScopeManager,
 }
 
@@ -93,6 +95,7 @@ impl CapabilityBuilder {
       windows: Vec::new(),
       webviews: Vec::new(),
       permissions: Vec::new(),
+      extends: Vec::new(),
       platforms: None,
     })
   }
@@ -237,6 +240,7 @@ impl RuntimeAuthority {
       acl,
       allowed_commands: resolved_acl.allowed_commands,
       denied_commands: resolved_acl.denied_commands,
+      capabilities: BTreeMap::new(),
       scope_manager: ScopeManager {
         command_scope: resolved_acl.command_scope,
         global_scope: resolved_acl.global_scope,
@@ -246,6 +250,13 @@ impl RuntimeAuthority {
     }
   }
 
+  /// Sets the capabilities of the app, which can be referenced by the `extends` field of runtime capabilities.
+  #[doc(hidden)]
+  pub fn with_capabilities(mut self, capabilities: BTreeMap<String, Capability>) -> Self {
+    self.capabilities = capabilities;
+    self
+  }
+
   pub(crate) fn has_app_manifest(&self) -> bool {
     self.acl.contains_key(APP_ACL_KEY)
   }
@@ -282,12 +293,14 @@ impl RuntimeAuthority {
       }
     }
 
+    // runtime capabilities can extend the app capabilities and the ones added before
+    resolve_extends_with(&mut capabilities, &self.capabilities)?;
     let resolved = Resolved::resolve(
       &self.acl,
-      capabilities,
+      capabilities.clone(),
       tauri_utils::platform::Target::current(),
-    )
-    .unwrap();
+    )?;
+    self.capabilities.extend(capabilities);
 
     // fill global scope
     for (plugin, global_scope) in resolved.global_scope {
@@ -615,20 +628,20 @@ pub struct CommandScope<T: ScopeObject> {
 
 impl<T: ScopeObject> CommandScope<T> {
   pub(crate) fn resolve<R: Runtime>(
-    webview: &Webview<R>,
+    app: &AppHandle<R>,
     scope_ids: Vec<u64>,
   ) -> crate::Result<Self> {
     let mut allow = Vec::new();
     let mut deny = Vec::new();
 
     for scope_id in scope_ids {
-      let scope = webview
+      let scope = app
         .manager()
         .runtime_authority
         .lock()
         .unwrap()
         .scope_manager
-        .get_command_scope_typed::<R, T>(webview.app_handle(), &scope_id)?;
+        .get_command_scope_typed::<R, T>(app, &scope_id)?;
 
       for s in scope.allows() {
         allow.push(s.clone());
@@ -725,7 +738,7 @@ impl<'a, R: Runtime, T: ScopeObject> CommandArg<'a, R> for CommandScope<T> {
         .collect::<Vec<_>>()
     });
     if let Some(scope_ids) = scope_ids {
-      CommandScope::resolve(&command.message.webview, scope_ids).map_err(Into::into)
+      CommandScope::resolve(command.message.webview.app_handle(), scope_ids).map_err(Into::into)
     } else {
       Ok(CommandScope {
         allow: Default::default(),
@@ -740,14 +753,14 @@ impl<'a, R: Runtime, T: ScopeObject> CommandArg<'a, R> for CommandScope<T> {
 pub struct GlobalScope<T: ScopeObject>(ScopeValue<T>);
 
 impl<T: ScopeObject> GlobalScope<T> {
-  pub(crate) fn resolve<R: Runtime>(webview: &Webview<R>, plugin: &str) -> crate::Result<Self> {
-    webview
+  pub(crate) fn resolve<R: Runtime>(app: &AppHandle<R>, plugin: &str) -> crate::Result<Self> {
+    app
       .manager()
       .runtime_authority
       .lock()
       .unwrap()
       .scope_manager
-      .get_global_scope_typed(webview.app_handle(), plugin)
+      .get_global_scope_typed(app, plugin)
       .map(Self)
   }
 
@@ -766,7 +779,7 @@ impl<'a, R: Runtime, T: ScopeObject> CommandArg<'a, R> for GlobalScope<T> {
   /// Grabs the [`ResolvedScope`] from the [`CommandItem`] and returns the associated [`GlobalScope`].
   fn from_command(command: CommandItem<'a, R>) -> Result<Self, InvokeError> {
     GlobalScope::resolve(
-      &command.message.webview,
+      command.message.webview.app_handle(),
       command.plugin.unwrap_or(APP_ACL_KEY),
     )
     .map_err(InvokeError::from_error)
@@ -1299,4 +1312,77 @@ mod tests {
       "myplugin.my-command-webview-window not allowed on window \"main-*\", webview \"webview-*\", URL: http://localhost:123/\n\nallowed on: [windows: \"main-*\", webviews: \"webview-*\", URL: local], [windows: \"main-*\", webviews: \"webview-*\", URL: http://localhost:8080]\n\nreferenced by: capability: maincap, permission: allow-command || capability: maincap, permission: allow-command"
     );
   }
+
+  #[test]
+  fn runtime_capability_extends_app_capability() {
+    use tauri_utils::acl::{
+      capability::{Capability, PermissionEntry},
+      manifest::Manifest,
+      Commands, Permission,
+    };
+
+    let acl = [(
+      "fs".to_string(),
+      Manifest {
+        permissions: [(
+          "allow-read".to_string(),
+          Permission {
+            identifier: "allow-read".into(),
+            commands: Commands {
+              allow: vec!["read".into()],
+              deny: Vec::new(),
+            },
+            ..Default::default()
+          },
+        )]
+        .into_iter()
+        .collect(),
+        ..Default::default()
+      },
+    )]
+    .into_iter()
+    .collect();
+    let main = Capability {
+      identifier: "main".into(),
+      description: String::new(),
+      remote: None,
+      local: true,
+      windows: vec!["main".into()],
+      webviews: Vec::new(),
+      permissions: vec![PermissionEntry::PermissionRef(
+        "fs:allow-read".parse().unwrap(),
+      )],
+      extends: Vec::new(),
+      platforms: None,
+    };
+
+    let mut authority = RuntimeAuthority::new(acl, Resolved::default())
+      .with_capabilities([("main".to_string(), main)].into_iter().collect());
+
+    authority
+      .add_capability(
+        r#"{ "identifier": "settings", "windows": ["settings"], "extends": ["main"], "permissions": [] }"#,
+      )
+      .unwrap();
+    assert!(authority
+      .resolve_access("plugin:fs|read", "settings", "settings", &Origin::Local)
+      .is_some());
+
+    // capabilities added at runtime can be extended too
+    authority
+      .add_capability(
+        r#"{ "identifier": "about", "windows": ["about"], "extends": ["settings"], "permissions": [] }"#,
+      )
+      .unwrap();
+    assert!(authority
+      .resolve_access("plugin:fs|read", "about", "about", &Origin::Local)
+      .is_some());
+
+    assert!(matches!(
+      authority.add_capability(
+        r#"{ "identifier": "broken", "windows": ["broken"], "extends": ["missing"], "permissions": [] }"#,
+      ),
+      Err(crate::Error::Capability(_))
+    ));
+  }
 }
@@ -0,0 +1,333 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Runs several IPC invocations sent in a single request.
+//!
+//! Each invocation is dispatched through [`Webview::on_message`] like a regular invoke,
+//! so it is checked individually against the [`super::RuntimeAuthority`].
+
+use std::{
+  collections::HashMap,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+  },
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+
+use crate::{
+  webview::{InvokeRequest, Webview},
+  Runtime,
+};
+
+use super::{
+  InvokeBody, InvokeError, InvokeResponse, InvokeResponseBody, JavaScriptChannelId,
+  OwnedInvokeResponder,
+};
+
+/// The command used to submit a batch of invocations.
+pub const BATCH_COMMAND: &str = "__TAURI_BATCH__";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchRequest {
+  invocations: Vec<BatchInvocation>,
+  /// When set, each result is sent through this channel as soon as it completes.
+  #[serde(default)]
+  channel: Option<JavaScriptChannelId>,
+}
+
+#[derive(Deserialize)]
+struct BatchInvocation {
+  cmd: String,
+  #[serde(default)]
+  payload: serde_json::Value,
+  #[serde(default)]
+  headers: HashMap<String, String>,
+}
+
+/// The outcome of a single invocation of a batch.
+#[derive(Serialize)]
+#[serde(tag = "status", content = "data", rename_all = "camelCase")]
+enum BatchResult {
+  Ok(Box<RawValue>),
+  Error(serde_json::Value),
+}
+
+impl From<InvokeResponse> for BatchResult {
+  fn from(response: InvokeResponse) -> Self {
+    let raw = match response {
+      InvokeResponse::Ok(InvokeResponseBody::Json(json)) => RawValue::from_string(json),
+      InvokeResponse::Ok(InvokeResponseBody::Raw(bytes)) => serde_json::value::to_raw_value(&bytes),
+      InvokeResponse::Err(InvokeError(error)) => return Self::Error(error),
+    };
+    raw
+      .map(Self::Ok)
+      .unwrap_or_else(|e| Self::Error(serde_json::Value::String(e.to_string())))
+  }
+}
+
+/// A streamed batch result, tagged with the position of its invocation.
+#[derive(Serialize)]
+struct IndexedBatchResult {
+  index: usize,
+  #[serde(flatten)]
+  result: BatchResult,
+}
+
+/// Runs each invocation of the batch request and responds once all of them are resolved.
+pub(crate) fn handle<R: Runtime>(
+  webview: Webview<R>,
+  request: InvokeRequest,
+  responder: Box<OwnedInvokeResponder<R>>,
+) {
+  let batch = match &request.body {
+    InvokeBody::Json(json) => BatchRequest::deserialize(json).map_err(|e| e.to_string()),
+    InvokeBody::Raw(_) => Err("batch requests must have a JSON payload".into()),
+  };
+  let batch = match batch {
+    Ok(batch) => batch,
+    Err(e) => {
+      responder(
+        webview,
+        request.cmd,
+        InvokeResponse::Err(InvokeError::from(e)),
+        request.callback,
+        request.error,
+      );
+      return;
+    }
+  };
+
+  let count = batch.invocations.len();
+  let channel = batch
+    .channel
+    .map(|id| Arc::new(id.channel_on::<R, IndexedBatchResult>(webview.clone())));
+  let results = Arc::new(Mutex::new(
+    std::iter::repeat_with(|| None)
+      .take(count)
+      .collect::<Vec<Option<BatchResult>>>(),
+  ));
+  let remaining = Arc::new(AtomicUsize::new(count));
+  let sent = Arc::new(AtomicUsize::new(0));
+  let responder = Arc::new(Mutex::new(Some(responder)));
+
+  let finish = {
+    let webview = webview.clone();
+    let cmd = request.cmd.clone();
+    let (callback, error) = (request.callback, request.error);
+    let streamed = channel.is_some();
+    let results = results.clone();
+    let sent = sent.clone();
+    move || {
+      let Some(responder) = responder.lock().unwrap().take() else {
+        return;
+      };
+      let response = if streamed {
+        // the channel messages are delivered asynchronously, possibly after this response,
+        // so the frontend waits until it has received this many results before resolving
+        InvokeResponse::Ok(InvokeResponseBody::Json(
+          sent.load(Ordering::Acquire).to_string(),
+        ))
+      } else {
+        let results = std::mem::take(&mut *results.lock().unwrap());
+        serde_json::to_string(&results)
+          .map(|json| InvokeResponse::Ok(InvokeResponseBody::Json(json)))
+          .unwrap_or_else(|e| InvokeResponse::Err(InvokeError::from_error(e)))
+      };
+      responder(webview, cmd, response, callback, error);
+    }
+  };
+  let finish = Arc::new(Mutex::new(Some(finish)));
+
+  if count == 0 {
+    if let Some(finish) = finish.lock().unwrap().take() {
+      finish();
+    }
+    return;
+  }
+
+  for (index, invocation) in batch.invocations.into_iter().enumerate() {
+    let mut headers = request.headers.clone();
+    for (key, value) in invocation.headers {
+      if let (Ok(key), Ok(value)) = (
+        http::header::HeaderName::from_bytes(key.as_bytes()),
+        http::HeaderValue::from_str(&value),
+      ) {
+        headers.insert(key, value);
+      }
+    }
+
+    let sub_request = InvokeRequest {
+      cmd: invocation.cmd,
+      callback: request.callback,
+      error: request.error,
+      url: request.url.clone(),
+      body: invocation.payload.into(),
+      headers,
+      invoke_key: request.invoke_key.clone(),
+    };
+
+    let results = results.clone();
+    let remaining = remaining.clone();
+    let finish = finish.clone();
+    let channel = channel.clone();
+    let sent = sent.clone();
+    let on_response: Box<OwnedInvokeResponder<R>> =
+      Box::new(move |_webview, _cmd, response, _callback, _error| {
+        let result = BatchResult::from(response);
+        match &channel {
+          Some(channel) => {
+            if channel.send(IndexedBatchResult { index, result }).is_ok() {
+              sent.fetch_add(1, Ordering::AcqRel);
+            }
+          }
+          None => results.lock().unwrap()[index] = Some(result),
+        }
+
+        if remaining.fetch_sub(1, Ordering::AcqRel) == 1 {
+          if let Some(finish) = finish.lock().unwrap().take() {
+            finish();
+          }
+        }
+      });
+
+    if sub_request.cmd == BATCH_COMMAND {
+      on_response(
+        webview.clone(),
+        sub_request.cmd,
+        InvokeResponse::Err(InvokeError::from("batch requests cannot be nested")),
+        sub_request.callback,
+        sub_request.error,
+      );
+    } else {
+      webview.clone().on_message(sub_request, on_response);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  use crate::{
+    ipc::CallbackFn,
+    test::{get_ipc_response, mock_builder, mock_context, noop_assets, INVOKE_KEY},
+  };
+
+  #[crate::command(root = "crate")]
+  fn double(value: u32) -> u32 {
+    value * 2
+  }
+
+  #[test]
+  fn run_batch() {
+    let app = mock_builder()
+      .invoke_handler(crate::generate_handler![double])
+      .build(mock_context(noop_assets()))
+      .unwrap();
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    let response = get_ipc_response(
+      &webview,
+      InvokeRequest {
+        cmd: BATCH_COMMAND.into(),
+        callback: CallbackFn(0),
+        error: CallbackFn(1),
+        url: "http://tauri.localhost".parse().unwrap(),
+        body: serde_json::json!({
+          "invocations": [
+            { "cmd": "double", "payload": { "value": 2 } },
+            { "cmd": "missing" },
+            { "cmd": BATCH_COMMAND },
+            { "cmd": "double", "payload": { "value": 21 } }
+          ]
+        })
+        .into(),
+        headers: Default::default(),
+        invoke_key: INVOKE_KEY.to_string(),
+      },
+    )
+    .unwrap()
+    .deserialize::<serde_json::Value>()
+    .unwrap();
+
+    assert_eq!(
+      response,
+      serde_json::json!([
+        { "status": "ok", "data": 4 },
+        { "status": "error", "data": "Command missing not found" },
+        { "status": "error", "data": "batch requests cannot be nested" },
+        { "status": "ok", "data": 42 }
+      ])
+    );
+  }
+
+  #[test]
+  fn run_streamed_batch() {
+    let app = mock_builder()
+      .invoke_handler(crate::generate_handler![double])
+      .build(mock_context(noop_assets()))
+      .unwrap();
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    let response = get_ipc_response(
+      &webview,
+      InvokeRequest {
+        cmd: BATCH_COMMAND.into(),
+        callback: CallbackFn(0),
+        error: CallbackFn(1),
+        url: "http://tauri.localhost".parse().unwrap(),
+        body: serde_json::json!({
+          "invocations": [
+            { "cmd": "double", "payload": { "value": 2 } },
+            { "cmd": "missing" }
+          ],
+          "channel": "__CHANNEL__:7"
+        })
+        .into(),
+        headers: Default::default(),
+        invoke_key: INVOKE_KEY.to_string(),
+      },
+    )
+    .unwrap()
+    .deserialize::<usize>()
+    .unwrap();
+
+    // resolves with the number of results sent through the channel
+    assert_eq!(response, 2);
+  }
+
+  #[test]
+  fn serialize_batch_result() {
+    let ok = BatchResult::from(InvokeResponse::Ok(InvokeResponseBody::Json(
+      "{\"a\":1}".into(),
+    )));
+    assert_eq!(
+      serde_json::to_string(&ok).unwrap(),
+      r#"{"status":"ok","data":{"a":1}}"#
+    );
+
+    let raw = BatchResult::from(InvokeResponse::Ok(InvokeResponseBody::Raw(vec![1, 2])));
+    assert_eq!(
+      serde_json::to_string(&raw).unwrap(),
+      r#"{"status":"ok","data":[1,2]}"#
+    );
+
+    let err = IndexedBatchResult {
+      index: 3,
+      result: BatchResult::from(InvokeResponse::Err(InvokeError::from("not allowed"))),
+    };
+    assert_eq!(
+      serde_json::to_string(&err).unwrap(),
+      r#"{"index":3,"status":"error","data":"not allowed"}"#
+    );
+  }
+}
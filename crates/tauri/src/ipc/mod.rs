@@ -6,7 +6,10 @@
 //!
 //! This module includes utilities to send messages to the JS layer of the webview.
 
-use std::sync::{Arc, Mutex};
+use std::{
+  sync::{Arc, Mutex},
+  time::{Duration, Instant},
+};
 
 use futures_util::Future;
 use http::HeaderMap;
@@ -22,6 +25,7 @@ use tauri_utils::acl::resolved::ResolvedCommand;
 use crate::{webview::Webview, Runtime, StateManager};
 
 mod authority;
+pub(crate) mod batch;
 pub(crate) mod channel;
 mod command;
 pub(crate) mod format_callback;
@@ -34,6 +38,9 @@ pub use authority::{
 pub use channel::{Channel, JavaScriptChannelId};
 pub use command::{private, CommandArg, CommandItem};
 
+/// The request header used to set a timeout, in milliseconds, for an IPC call.
+pub(crate) const INVOKE_TIMEOUT_HEADER_NAME: &str = "Tauri-Invoke-Timeout";
+
 /// A closure that is run every time Tauri receives a message it doesn't explicitly handle.
 pub type InvokeHandler<R> = dyn Fn(Invoke<R>) -> bool + Send + Sync + 'static;
 
@@ -167,6 +174,41 @@ impl<'a, R: Runtime> CommandArg<'a, R> for Request<'a> {
   }
 }
 
+/// The deadline of an IPC request.
+///
+/// Set by the `timeout` attribute of the command, by [`crate::plugin::Builder::invoke_timeout`]
+/// or by the `Tauri-Invoke-Timeout` request header, whichever expires first.
+/// Once it is reached the command future is dropped and the promise is rejected.
+/// Only `async` commands are interrupted, the timeout of a sync command is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline(Option<Instant>);
+
+impl Deadline {
+  /// The instant the request times out, if it has a timeout.
+  pub fn instant(&self) -> Option<Instant> {
+    self.0
+  }
+
+  /// The time left before the request times out, if it has a timeout.
+  pub fn remaining(&self) -> Option<Duration> {
+    self
+      .0
+      .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+  }
+
+  /// Whether the deadline has already been reached.
+  pub fn is_expired(&self) -> bool {
+    self.0.is_some_and(|deadline| deadline <= Instant::now())
+  }
+}
+
+impl<'a, R: Runtime> CommandArg<'a, R> for Deadline {
+  /// Returns the [`Deadline`] of the invoke request.
+  fn from_command(command: CommandItem<'a, R>) -> Result<Self, InvokeError> {
+    Ok(Self(command.message.deadline))
+  }
+}
+
 /// Marks a type as a response to an IPC call.
 pub trait IpcResponse {
   /// Resolve the IPC response body.
@@ -214,6 +256,24 @@ pub struct Invoke<R: Runtime> {
   pub acl: Option<Vec<ResolvedCommand>>,
 }
 
+impl<R: Runtime> Invoke<R> {
+  /// Sets the maximum duration of this invoke.
+  ///
+  /// When the timeout elapses the async task responding to the invoke is dropped
+  /// and the promise is rejected. If a deadline was already set, the earliest one is kept.
+  pub fn set_timeout(&mut self, timeout: Duration) {
+    let Some(deadline) = Instant::now().checked_add(timeout) else {
+      return;
+    };
+    let deadline = match self.message.deadline {
+      Some(current) if current <= deadline => current,
+      _ => deadline,
+    };
+    self.message.deadline.replace(deadline);
+    self.resolver.deadline.replace(deadline);
+  }
+}
+
 /// Error response from an [`InvokeMessage`].
 #[derive(Debug)]
 pub struct InvokeError(pub serde_json::Value);
@@ -284,6 +344,7 @@ pub struct InvokeResolver<R: Runtime> {
   cmd: String,
   pub(crate) callback: CallbackFn,
   pub(crate) error: CallbackFn,
+  pub(crate) deadline: Option<Instant>,
 }
 
 impl<R: Runtime> Clone for InvokeResolver<R> {
@@ -294,6 +355,7 @@ impl<R: Runtime> Clone for InvokeResolver<R> {
       cmd: self.cmd.clone(),
       callback: self.callback,
       error: self.error,
+      deadline: self.deadline,
     }
   }
 }
@@ -312,15 +374,19 @@ impl<R: Runtime> InvokeResolver<R> {
       cmd,
       callback,
       error,
+      deadline: None,
     }
   }
 
   /// Reply to the invoke promise with an async task.
+  ///
+  /// If the invoke has a deadline, the task is dropped and the promise rejected when it is reached.
   pub fn respond_async<T, F>(self, task: F)
   where
     T: IpcResponse,
     F: Future<Output = Result<T, InvokeError>> + Send + 'static,
   {
+    let task = with_deadline(self.deadline, self.cmd.clone(), task);
     crate::async_runtime::spawn(async move {
       Self::return_task(
         self.webview,
@@ -335,10 +401,13 @@ impl<R: Runtime> InvokeResolver<R> {
   }
 
   /// Reply to the invoke promise with an async task which is already serialized.
+  ///
+  /// If the invoke has a deadline, the task is dropped and the promise rejected when it is reached.
   pub fn respond_async_serialized<F>(self, task: F)
   where
     F: Future<Output = Result<InvokeResponseBody, InvokeError>> + Send + 'static,
   {
+    let task = with_deadline(self.deadline, self.cmd.clone(), task);
     crate::async_runtime::spawn(async move {
       let response = match task.await {
         Ok(ok) => InvokeResponse::Ok(ok),
@@ -356,7 +425,15 @@ impl<R: Runtime> InvokeResolver<R> {
   }
 
   /// Reply to the invoke promise with a serializable value.
+  ///
+  /// The deadline of the invoke is not enforced on values computed synchronously, use [`Self::respond_async`] instead.
   pub fn respond<T: IpcResponse>(self, value: Result<T, InvokeError>) {
+    if self.deadline.is_some() {
+      log::warn!(
+        "the timeout of command `{}` is ignored because it is not async",
+        self.cmd
+      );
+    }
     Self::return_result(
       self.webview,
       self.responder,
@@ -459,6 +536,25 @@ impl<R: Runtime> InvokeResolver<R> {
   }
 }
 
+/// Races the task against the invoke deadline, rejecting with [`crate::Error::InvokeTimeout`] if it elapses first.
+///
+/// The timer requires the time driver of the async runtime, see [`crate::async_runtime::set`].
+async fn with_deadline<T, F>(
+  deadline: Option<Instant>,
+  cmd: String,
+  task: F,
+) -> Result<T, InvokeError>
+where
+  F: Future<Output = Result<T, InvokeError>>,
+{
+  match deadline {
+    Some(deadline) => tokio::time::timeout_at(deadline.into(), task)
+      .await
+      .unwrap_or_else(|_| Err(crate::Error::InvokeTimeout(cmd).into())),
+    None => task.await,
+  }
+}
+
 /// An invoke message.
 #[default_runtime(crate::Wry, wry)]
 #[derive(Debug)]
@@ -473,6 +569,8 @@ pub struct InvokeMessage<R: Runtime> {
   pub(crate) payload: InvokeBody,
   /// The request headers.
   pub(crate) headers: HeaderMap,
+  /// The instant this invoke times out.
+  pub(crate) deadline: Option<Instant>,
 }
 
 impl<R: Runtime> Clone for InvokeMessage<R> {
@@ -483,6 +581,7 @@ impl<R: Runtime> Clone for InvokeMessage<R> {
       command: self.command.clone(),
       payload: self.payload.clone(),
       headers: self.headers.clone(),
+      deadline: self.deadline,
     }
   }
 }
@@ -502,6 +601,7 @@ impl<R: Runtime> InvokeMessage<R> {
       command,
       payload,
       headers,
+      deadline: None,
     }
   }
 
@@ -546,6 +646,12 @@ impl<R: Runtime> InvokeMessage<R> {
   pub fn headers(&self) -> &HeaderMap {
     &self.headers
   }
+
+  /// The deadline of this invoke.
+  #[inline(always)]
+  pub fn deadline(&self) -> Deadline {
+    Deadline(self.deadline)
+  }
 }
 
 /// The `Callback` type is the return value of the `transformCallback` JavaScript function.
@@ -556,6 +662,84 @@ pub struct CallbackFn(pub u32);
 mod tests {
   use super::*;
 
+  use crate::test::{get_ipc_response, mock_builder, mock_context, noop_assets, INVOKE_KEY};
+  use crate::webview::InvokeRequest;
+
+  #[crate::command(root = "crate")]
+  async fn sleep(millis: u64) -> Result<(), String> {
+    tokio::time::sleep(Duration::from_millis(millis)).await;
+    Ok(())
+  }
+
+  #[crate::command(root = "crate", timeout = 50)]
+  async fn sleep_with_timeout(millis: u64) -> Result<(), String> {
+    tokio::time::sleep(Duration::from_millis(millis)).await;
+    Ok(())
+  }
+
+  #[crate::command(root = "crate")]
+  async fn remaining(deadline: Deadline) -> Result<Option<u128>, String> {
+    Ok(deadline.remaining().map(|r| r.as_millis()))
+  }
+
+  fn invoke_request(cmd: &str, millis: u64, timeout: Option<&str>) -> InvokeRequest {
+    let mut headers = HeaderMap::new();
+    if let Some(timeout) = timeout {
+      headers.insert(INVOKE_TIMEOUT_HEADER_NAME, timeout.parse().unwrap());
+    }
+    InvokeRequest {
+      cmd: cmd.into(),
+      callback: CallbackFn(0),
+      error: CallbackFn(1),
+      url: "http://tauri.localhost".parse().unwrap(),
+      body: serde_json::json!({ "millis": millis }).into(),
+      headers,
+      invoke_key: INVOKE_KEY.to_string(),
+    }
+  }
+
+  #[test]
+  fn invoke_timeout() {
+    let app = mock_builder()
+      .invoke_handler(crate::generate_handler![
+        sleep,
+        sleep_with_timeout,
+        remaining
+      ])
+      .build(mock_context(noop_assets()))
+      .unwrap();
+    let webview = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    assert!(get_ipc_response(&webview, invoke_request("sleep", 10, None)).is_ok());
+    assert!(get_ipc_response(&webview, invoke_request("sleep", 10, Some("1000"))).is_ok());
+    assert_eq!(
+      get_ipc_response(&webview, invoke_request("sleep", 1000, Some("50"))),
+      Err(serde_json::Value::String(
+        "command `sleep` timed out".into()
+      ))
+    );
+    assert_eq!(
+      get_ipc_response(&webview, invoke_request("sleep_with_timeout", 1000, None)),
+      Err(serde_json::Value::String(
+        "command `sleep_with_timeout` timed out".into()
+      ))
+    );
+
+    let remaining = get_ipc_response(&webview, invoke_request("remaining", 0, Some("1000")))
+      .unwrap()
+      .deserialize::<Option<u128>>()
+      .unwrap()
+      .unwrap();
+    assert!(remaining <= 1000);
+    let remaining = get_ipc_response(&webview, invoke_request("remaining", 0, None))
+      .unwrap()
+      .deserialize::<Option<u128>>()
+      .unwrap();
+    assert!(remaining.is_none());
+  }
+
   #[test]
   fn deserialize_invoke_response_body() {
     let json = InvokeResponseBody::Json("[1, 123, 1231]".to_string());
@@ -35,6 +35,8 @@
 //! - **config-toml**: Adds support to TOML format for the configuration `Tauri.toml`.
 //! - **image-ico**: Adds support to parse `.ico` image, see [`Image`].
 //! - **image-png**: Adds support to parse `.png` image, see [`Image`].
+//! - **image-icns**: Adds support to parse `.icns` image, see [`Image`].
+//! - **image-svg**: Adds support to rasterize `.svg` image, see [`Image`].
 //! - **macos-proxy**: Adds support for [`WebviewBuilder::proxy_url`] on macOS. Requires macOS 14+.
 //! - **specta**: Add support for [`specta::specta`](https://docs.rs/specta/%5E2.0.0-rc.9/specta/attr.specta.html) with Tauri arguments such as [`State`](crate::State), [`Window`](crate::Window) and [`AppHandle`](crate::AppHandle)
 //!
@@ -219,6 +221,7 @@ pub use {
   self::manager::Asset,
   self::runtime::{
     dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Pixel, Position, Size},
+    monitor::PhysicalRect,
     webview::WebviewAttributes,
     window::{CursorIcon, DragDropEvent, WindowSizeConstraints},
     DeviceEventFilter, Rect, UserAttentionType,
@@ -353,6 +356,12 @@ pub trait Assets<R: Runtime>: Send + Sync + 'static {
 
   /// Gets the hashes for the CSP tag of the HTML on the given path.
   fn csp_hashes(&self, html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_>;
+
+  /// Gets the content hash of the given asset, used to answer conditional requests.
+  fn hash(&self, key: &AssetKey) -> Option<Cow<'_, str>> {
+    let _ = key;
+    None
+  }
 }
 
 impl<R: Runtime> Assets<R> for EmbeddedAssets {
@@ -367,6 +376,10 @@ impl<R: Runtime> Assets<R> for EmbeddedAssets {
   fn csp_hashes(&self, html_path: &AssetKey) -> Box<dyn Iterator<Item = CspHash<'_>> + '_> {
     EmbeddedAssets::csp_hashes(self, html_path)
   }
+
+  fn hash(&self, key: &AssetKey) -> Option<Cow<'_, str>> {
+    EmbeddedAssets::hash(self, key).map(Cow::Borrowed)
+  }
 }
 
 /// User supplied data required inside of a Tauri application.
@@ -385,6 +398,10 @@ pub struct Context<R: Runtime> {
   pub(crate) app_icon: Option<Vec<u8>>,
   #[cfg(all(desktop, feature = "tray-icon"))]
   pub(crate) tray_icon: Option<image::Image<'static>>,
+  #[cfg(all(desktop, feature = "tray-icon"))]
+  pub(crate) tray_icon_animation: Vec<image::Image<'static>>,
+  #[cfg(desktop)]
+  pub(crate) menu_icons: HashMap<std::path::PathBuf, image::Image<'static>>,
   pub(crate) package_info: PackageInfo,
   pub(crate) pattern: Pattern,
   pub(crate) runtime_authority: RuntimeAuthority,
@@ -403,6 +420,11 @@ impl<R: Runtime> fmt::Debug for Context<R> {
 
     #[cfg(all(desktop, feature = "tray-icon"))]
     d.field("tray_icon", &self.tray_icon);
+    #[cfg(all(desktop, feature = "tray-icon"))]
+    d.field("tray_icon_animation", &self.tray_icon_animation);
+
+    #[cfg(desktop)]
+    d.field("menu_icons", &self.menu_icons);
 
     d.finish()
   }
@@ -461,6 +483,42 @@ impl<R: Runtime> Context<R> {
     self.tray_icon = icon;
   }
 
+  /// The frames of the tray icon animation declared in the configuration.
+  #[cfg(all(desktop, feature = "tray-icon"))]
+  #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "tray-icon"))))]
+  #[inline(always)]
+  pub fn tray_icon_animation(&self) -> &[image::Image<'static>] {
+    &self.tray_icon_animation
+  }
+
+  /// Set the frames of the tray icon animation declared in the configuration.
+  #[cfg(all(desktop, feature = "tray-icon"))]
+  #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "tray-icon"))))]
+  #[inline(always)]
+  pub fn set_tray_icon_animation(&mut self, frames: Vec<image::Image<'static>>) {
+    self.tray_icon_animation = frames;
+  }
+
+  /// The icons of the menu items declared in the configuration, keyed by their configured path.
+  #[cfg(desktop)]
+  #[cfg_attr(docsrs, doc(cfg(desktop)))]
+  #[inline(always)]
+  pub fn menu_icons(&self) -> &HashMap<std::path::PathBuf, image::Image<'static>> {
+    &self.menu_icons
+  }
+
+  /// Set the icon of the menu items declared in the configuration with the given path.
+  #[cfg(desktop)]
+  #[cfg_attr(docsrs, doc(cfg(desktop)))]
+  #[inline(always)]
+  pub fn set_menu_icon<P: Into<std::path::PathBuf>>(
+    &mut self,
+    path: P,
+    icon: image::Image<'static>,
+  ) {
+    self.menu_icons.insert(path.into(), icon);
+  }
+
   /// Package information.
   #[inline(always)]
   pub fn package_info(&self) -> &PackageInfo {
@@ -512,6 +570,10 @@ impl<R: Runtime> Context<R> {
       app_icon,
       #[cfg(all(desktop, feature = "tray-icon"))]
       tray_icon: None,
+      #[cfg(all(desktop, feature = "tray-icon"))]
+      tray_icon_animation: Vec::new(),
+      #[cfg(desktop)]
+      menu_icons: HashMap::new(),
       package_info,
       pattern,
       runtime_authority,
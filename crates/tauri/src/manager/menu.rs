@@ -8,7 +8,9 @@ use std::{
 };
 
 use crate::{
-  menu::{Menu, MenuEvent, MenuId},
+  menu::{
+    binding::MenuBindings, locale::MenuLocalization, AcceleratorRegistry, Menu, MenuEvent, MenuId,
+  },
   AppHandle, Runtime, Window,
 };
 
@@ -25,6 +27,12 @@ pub struct MenuManager<R: Runtime> {
   pub global_event_listeners: Mutex<Vec<crate::app::GlobalMenuEventListener<AppHandle<R>>>>,
   /// Menu event listeners to specific windows.
   pub event_listeners: Mutex<HashMap<String, crate::app::GlobalMenuEventListener<Window<R>>>>,
+  /// The locale and the menu items labeled with a translation key.
+  pub localization: MenuLocalization<R>,
+  /// The accelerators of the menu items.
+  pub accelerators: AcceleratorRegistry<R>,
+  /// The check items bound to a value and the radio items.
+  pub bindings: MenuBindings<R>,
 }
 
 impl<R: Runtime> MenuManager<R> {
@@ -167,6 +167,8 @@ pub struct Asset {
   pub mime_type: String,
   /// The `Content-Security-Policy` header value.
   pub csp_header: Option<String>,
+  /// The `ETag` header value, derived from the asset hash.
+  pub etag: Option<String>,
 }
 
 impl Asset {
@@ -184,6 +186,11 @@ impl Asset {
   pub fn csp_header(&self) -> Option<&str> {
     self.csp_header.as_deref()
   }
+
+  /// The `ETag` header value.
+  pub fn etag(&self) -> Option<&str> {
+    self.etag.as_deref()
+  }
 }
 
 #[default_runtime(crate::Wry, wry)]
@@ -276,6 +283,12 @@ impl<R: Runtime> AppManager<R> {
         windows: Mutex::default(),
         default_icon: context.default_window_icon,
         event_listeners: Arc::new(window_event_listeners),
+        #[cfg(desktop)]
+        state: Default::default(),
+        #[cfg(desktop)]
+        modals: Default::default(),
+        #[cfg(desktop)]
+        groups: Default::default(),
       },
       webview: webview::WebviewManager {
         webviews: Mutex::default(),
@@ -289,9 +302,11 @@ impl<R: Runtime> AppManager<R> {
       #[cfg(all(desktop, feature = "tray-icon"))]
       tray: tray::TrayManager {
         icon: context.tray_icon,
+        animation: context.tray_icon_animation,
         icons: Default::default(),
         global_event_listeners: Default::default(),
         event_listeners: Default::default(),
+        states: Default::default(),
       },
       #[cfg(desktop)]
       menu: menu::MenuManager {
@@ -299,6 +314,9 @@ impl<R: Runtime> AppManager<R> {
         menu: Default::default(),
         global_event_listeners: Mutex::new(menu_event_listener),
         event_listeners: Mutex::new(window_menu_event_listeners),
+        localization: Default::default(),
+        accelerators: Default::default(),
+        bindings: Default::default(),
       },
       plugins: Mutex::new(plugins),
       listeners: Listeners::default(),
@@ -446,10 +464,17 @@ impl<R: Runtime> AppManager<R> {
           asset
         };
         let mime_type = tauri_utils::mime_type::MimeType::parse(&final_data, &path);
+        // HTML files get a new CSP nonce on every request so their embedded hash cannot validate them
+        let etag = if is_html {
+          None
+        } else {
+          assets.hash(&asset_path).map(|hash| format!("\"{hash}\""))
+        };
         Ok(Asset {
           bytes: final_data.to_vec(),
           mime_type,
           csp_header,
+          etag,
         })
       }
       Err(e) => {
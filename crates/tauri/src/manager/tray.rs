@@ -7,18 +7,22 @@ use std::{collections::HashMap, fmt, sync::Mutex};
 use crate::{
   app::GlobalTrayIconEventListener,
   image::Image,
-  tray::{TrayIcon, TrayIconEvent, TrayIconId},
+  tray::{TrayIcon, TrayIconEvent, TrayIconId, TrayIconState},
   AppHandle, Runtime,
 };
 
 pub struct TrayManager<R: Runtime> {
   pub(crate) icon: Option<Image<'static>>,
+  /// The frames of the configured tray icon animation.
+  pub(crate) animation: Vec<Image<'static>>,
   /// Tray icons
   pub(crate) icons: Mutex<Vec<TrayIcon<R>>>,
   /// Global Tray icon event listeners.
   pub(crate) global_event_listeners: Mutex<Vec<GlobalTrayIconEventListener<AppHandle<R>>>>,
   /// Tray icon event listeners.
   pub(crate) event_listeners: Mutex<HashMap<TrayIconId, GlobalTrayIconEventListener<TrayIcon<R>>>>,
+  /// The icon state of the tray icons.
+  pub(crate) states: Mutex<HashMap<TrayIconId, TrayIconState>>,
 }
 
 impl<R: Runtime> fmt::Debug for TrayManager<R> {
@@ -55,6 +59,16 @@ impl<R: Runtime> TrayManager<R> {
       .cloned()
   }
 
+  /// Whether the animation of the given generation is still running on the tray icon.
+  pub(crate) fn is_animating(&self, id: &TrayIconId, generation: u64) -> bool {
+    self
+      .states
+      .lock()
+      .unwrap()
+      .get(id)
+      .map_or(false, |s| s.animation == generation)
+  }
+
   pub fn remove_tray_by_id<'a, I>(&self, id: &'a I) -> Option<TrayIcon<R>>
   where
     I: ?Sized,
@@ -63,7 +77,10 @@ impl<R: Runtime> TrayManager<R> {
     let mut icons = self.icons.lock().unwrap();
     let idx = icons.iter().position(|t| t.id() == &id);
     if let Some(idx) = idx {
-      return Some(icons.swap_remove(idx));
+      let icon = icons.swap_remove(idx);
+      // also stops its animation
+      self.states.lock().unwrap().remove(icon.id());
+      return Some(icon);
     }
     None
   }
@@ -300,7 +300,15 @@ impl<R: Runtime> WebviewManager<R> {
         .get::<crate::Scopes>()
         .asset_protocol
         .clone();
-      let protocol = crate::protocol::asset::get(asset_scope.clone(), window_origin.clone());
+      let cache_control = app_manager
+        .config()
+        .app
+        .security
+        .asset_protocol
+        .cache_control
+        .clone();
+      let protocol =
+        crate::protocol::asset::get(asset_scope.clone(), window_origin.clone(), cache_control);
       pending.register_uri_scheme_protocol("asset", move |webview_id, request, responder| {
         protocol(webview_id, request, UriSchemeResponder(responder))
       });
@@ -498,13 +506,10 @@ impl<R: Runtime> WebviewManager<R> {
 
     // in `windows`, we need to force a data_directory
     // but we do respect user-specification
+    // this also keeps the webview data next to the executable in portable mode
     #[cfg(any(target_os = "linux", target_os = "windows"))]
     if pending.webview_attributes.data_directory.is_none() {
-      let local_app_data = manager.path().resolve(
-        &app_manager.config.identifier,
-        crate::path::BaseDirectory::LocalData,
-      );
-      if let Ok(user_data_dir) = local_app_data {
+      if let Ok(user_data_dir) = manager.path().app_local_data_dir() {
         pending.webview_attributes.data_directory = Some(user_data_dir);
       }
     }
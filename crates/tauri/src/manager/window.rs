@@ -39,6 +39,15 @@ pub struct WindowManager<R: Runtime> {
   pub default_icon: Option<Image<'static>>,
   /// Window event listeners to all windows.
   pub event_listeners: Arc<Vec<GlobalWindowEventListener<R>>>,
+  /// The saved window states.
+  #[cfg(desktop)]
+  pub state: crate::window::state::WindowStateStore,
+  /// The modal windows and the windows waiting to be closed.
+  #[cfg(desktop)]
+  pub modals: crate::window::modal::ModalStore,
+  /// The window groups.
+  #[cfg(desktop)]
+  pub groups: crate::window::group::GroupStore,
 }
 
 impl<R: Runtime> fmt::Debug for WindowManager<R> {
@@ -151,6 +160,35 @@ pub(crate) struct DragDropPayload<'a> {
 }
 
 fn on_window_event<R: Runtime>(window: &Window<R>, event: &WindowEvent) -> crate::Result<()> {
+  #[cfg(desktop)]
+  window.manager.window.state.on_window_event(window, event);
+  #[cfg(desktop)]
+  window.manager.window.groups.on_window_event(window, event);
+  #[cfg(desktop)]
+  match event {
+    WindowEvent::CloseRequested { .. } => window
+      .manager
+      .window
+      .modals
+      .on_close_requested(window.label()),
+    WindowEvent::Destroyed => window.manager.window.modals.on_destroyed(window),
+    _ => (),
+  }
+
+  #[cfg(any(test, all(desktop, feature = "unstable")))]
+  if matches!(
+    event,
+    WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. }
+  ) {
+    // the other listeners still need the event if the webviews can't be laid out
+    if let Err(e) = window.apply_layout() {
+      log::error!(
+        "failed to apply the layout of the `{}` window: {e}",
+        window.label()
+      );
+    }
+  }
+
   match event {
     WindowEvent::Resized(size) => window.emit_to_window(WINDOW_RESIZED_EVENT, size)?,
     WindowEvent::Moved(position) => window.emit_to_window(WINDOW_MOVED_EVENT, position)?,
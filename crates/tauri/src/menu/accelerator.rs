@@ -0,0 +1,382 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, sync::Mutex};
+
+use muda::accelerator::Accelerator;
+
+use super::{MenuId, MenuItemKind, WeakMenuItem};
+use crate::Runtime;
+
+/// Shortcuts handled by the webview to edit text, which menu items should not override.
+///
+/// The predefined menu items are not tracked by the registry so they never conflict with these.
+const EDITING_SHORTCUTS: &[&str] = &[
+  "CmdOrCtrl+A",
+  "CmdOrCtrl+C",
+  "CmdOrCtrl+V",
+  "CmdOrCtrl+X",
+  "CmdOrCtrl+Z",
+  "CmdOrCtrl+Shift+Z",
+  #[cfg(not(target_os = "macos"))]
+  "CmdOrCtrl+Y",
+];
+
+/// How conflicting accelerators are reported, see [`crate::Builder::accelerator_conflicts`].
+///
+/// Accelerators conflict when several items of the same menu are bound to the same shortcut,
+/// or when an item is bound to a shortcut the webview uses to edit text, such as `CmdOrCtrl+C`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AcceleratorConflicts {
+  /// The conflicts are not checked.
+  Ignore,
+  /// The conflicts are logged as warnings.
+  #[default]
+  Warn,
+  /// The conflicts fail building the menu or rebinding the item with [`crate::Error::AcceleratorConflict`].
+  Error,
+}
+
+struct Binding<R: Runtime> {
+  accelerator: Option<(String, Accelerator)>,
+  item: WeakMenuItem<R>,
+}
+
+/// The accelerators bound to the menu items of the app.
+///
+/// The menu items with a text register into it when they are created,
+/// and the menus check their accelerators for conflicts when they are built.
+/// Use [`AppHandle::accelerators`](crate::AppHandle::accelerators) to query the bindings
+/// and rebind them at runtime, for instance to implement a customizable keymap.
+///
+/// Items can share an id, for instance a `quit` item in both the app menu and the tray menu,
+/// in which case they are queried and rebound together.
+pub struct AcceleratorRegistry<R: Runtime> {
+  conflicts: Mutex<AcceleratorConflicts>,
+  bindings: Mutex<HashMap<MenuId, Vec<Binding<R>>>>,
+}
+
+impl<R: Runtime> Default for AcceleratorRegistry<R> {
+  fn default() -> Self {
+    Self {
+      conflicts: Default::default(),
+      bindings: Default::default(),
+    }
+  }
+}
+
+impl<R: Runtime> AcceleratorRegistry<R> {
+  pub(crate) fn set_conflicts(&self, conflicts: AcceleratorConflicts) {
+    *self.conflicts.lock().unwrap() = conflicts;
+  }
+
+  /// Tracks a menu item and its accelerator.
+  ///
+  /// Invalid accelerators are ignored, like the menu items do.
+  pub(crate) fn bind(&self, item: MenuItemKind<R>, accelerator: Option<String>) {
+    let accelerator =
+      accelerator.and_then(|a| a.parse().ok().map(|parsed: Accelerator| (a, parsed)));
+    let item_ref = item.downgrade();
+    let mut bindings = self.bindings.lock().unwrap();
+    let items = bindings.entry(item.id().clone()).or_default();
+    // an item already tracked is updated, the other items with the same id are kept
+    match items.iter_mut().find(|b| b.item.ptr_eq(&item_ref)) {
+      Some(binding) => binding.accelerator = accelerator,
+      None => items.push(Binding {
+        accelerator,
+        item: item_ref,
+      }),
+    }
+  }
+
+  /// The accelerator bound to the menu item with the given id.
+  ///
+  /// When several items share the id, the accelerator of the first one bound to an accelerator is returned.
+  pub fn accelerator<I: Into<MenuId>>(&self, id: I) -> Option<String> {
+    self
+      .bindings
+      .lock()
+      .unwrap()
+      .get(&id.into())?
+      .iter()
+      .filter(|b| b.item.upgrade().is_some())
+      .find_map(|b| b.accelerator.as_ref())
+      .map(|(accelerator, _)| accelerator.clone())
+  }
+
+  /// The ids of the menu items bound to the given accelerator.
+  ///
+  /// Equivalent shortcuts match, for instance `CmdOrCtrl+Shift+S` and `Shift+CommandOrControl+S`.
+  pub fn items(&self, accelerator: &str) -> crate::Result<Vec<MenuId>> {
+    let parsed = parse(accelerator)?;
+    self.prune();
+    Ok(
+      self
+        .bindings
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(_, items)| {
+          items
+            .iter()
+            .any(|b| matches!(&b.accelerator, Some((_, p)) if p == &parsed))
+        })
+        .map(|(id, _)| id.clone())
+        .collect(),
+    )
+  }
+
+  /// The menu items bound to an accelerator, with their accelerator.
+  ///
+  /// When several items share an id, the accelerator of the first one bound to an accelerator is returned.
+  pub fn bindings(&self) -> HashMap<MenuId, String> {
+    self.prune();
+    self
+      .bindings
+      .lock()
+      .unwrap()
+      .iter()
+      .filter_map(|(id, items)| {
+        let (accelerator, _) = items.iter().find_map(|b| b.accelerator.as_ref())?;
+        Some((id.clone(), accelerator.clone()))
+      })
+      .collect()
+  }
+
+  /// Binds the menu items with the given id to another accelerator, or removes their accelerator with `None`.
+  ///
+  /// The new accelerator is checked against all the other menu items,
+  /// since the registry does not know which menus the items belong to.
+  pub fn rebind<I: Into<MenuId>>(&self, id: I, accelerator: Option<&str>) -> crate::Result<()> {
+    let id = id.into();
+    let items = self
+      .bindings
+      .lock()
+      .unwrap()
+      .get(&id)
+      .map(|items| {
+        items
+          .iter()
+          .filter_map(|b| b.item.upgrade())
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    if items.is_empty() {
+      return Err(crate::Error::UnknownMenuItem(id.0.clone()));
+    }
+
+    if let Some(accelerator) = accelerator {
+      let parsed = parse(accelerator)?;
+      let others = self
+        .items(accelerator)?
+        .into_iter()
+        .filter(|other| other != &id)
+        .collect::<Vec<_>>();
+      self.report(rebind_conflicts(accelerator, &parsed, &others))?;
+    }
+
+    // the items update their binding
+    for item in items {
+      match item {
+        MenuItemKind::MenuItem(i) => i.set_accelerator(accelerator)?,
+        MenuItemKind::Check(i) => i.set_accelerator(accelerator)?,
+        MenuItemKind::Icon(i) => i.set_accelerator(accelerator)?,
+        MenuItemKind::Submenu(_) | MenuItemKind::Predefined(_) => (),
+      }
+    }
+    Ok(())
+  }
+
+  /// Checks the accelerators of the items of a menu, including its submenus.
+  pub(crate) fn check(&self, items: &[MenuItemKind<R>]) -> crate::Result<()> {
+    if *self.conflicts.lock().unwrap() == AcceleratorConflicts::Ignore {
+      return Ok(());
+    }
+
+    let mut item_refs = Vec::new();
+    collect_items(items, &mut item_refs)?;
+    self.prune();
+
+    let mut bound = Vec::new();
+    {
+      let bindings = self.bindings.lock().unwrap();
+      for (id, item_ref) in &item_refs {
+        let accelerator = bindings
+          .get(id)
+          .and_then(|items| items.iter().find(|b| b.item.ptr_eq(item_ref)))
+          .and_then(|b| b.accelerator.as_ref());
+        if let Some((accelerator, parsed)) = accelerator {
+          bound.push((id.clone(), accelerator.clone(), *parsed));
+        }
+      }
+    }
+
+    self.report(menu_conflicts(&bound))
+  }
+
+  fn report(&self, conflicts: Vec<String>) -> crate::Result<()> {
+    if conflicts.is_empty() {
+      return Ok(());
+    }
+    match *self.conflicts.lock().unwrap() {
+      AcceleratorConflicts::Ignore => Ok(()),
+      AcceleratorConflicts::Warn => {
+        for conflict in conflicts {
+          log::warn!("menu accelerator conflict: {conflict}");
+        }
+        Ok(())
+      }
+      AcceleratorConflicts::Error => Err(crate::Error::AcceleratorConflict(conflicts.join("; "))),
+    }
+  }
+
+  /// Stops tracking the dropped items.
+  fn prune(&self) {
+    let mut bindings = self.bindings.lock().unwrap();
+    for items in bindings.values_mut() {
+      items.retain(|b| b.item.upgrade().is_some());
+    }
+    bindings.retain(|_, items| !items.is_empty());
+  }
+}
+
+/// The conflicts between the accelerators bound to the items of a menu.
+fn menu_conflicts(bound: &[(MenuId, String, Accelerator)]) -> Vec<String> {
+  let mut by_accelerator: Vec<(&str, &Accelerator, Vec<&MenuId>)> = Vec::new();
+  for (id, accelerator, parsed) in bound {
+    match by_accelerator.iter_mut().find(|(_, p, _)| *p == parsed) {
+      Some((_, _, ids)) => ids.push(id),
+      None => by_accelerator.push((accelerator, parsed, vec![id])),
+    }
+  }
+
+  let mut conflicts = Vec::new();
+  for (accelerator, parsed, ids) in by_accelerator {
+    if ids.len() > 1 {
+      conflicts.push(format!(
+        "`{accelerator}` is bound to {}",
+        join_ids(ids.into_iter())
+      ));
+    } else if is_editing_shortcut(parsed) {
+      conflicts.push(format!(
+        "`{accelerator}` bound to {} is a text editing shortcut",
+        join_ids(ids.into_iter())
+      ));
+    }
+  }
+  conflicts
+}
+
+/// The conflicts of binding an item to `accelerator`, which `others` are already bound to.
+fn rebind_conflicts(accelerator: &str, parsed: &Accelerator, others: &[MenuId]) -> Vec<String> {
+  let mut conflicts = Vec::new();
+  if !others.is_empty() {
+    conflicts.push(format!(
+      "`{accelerator}` is already bound to {}",
+      join_ids(others.iter())
+    ));
+  }
+  if is_editing_shortcut(parsed) {
+    conflicts.push(format!("`{accelerator}` is a text editing shortcut"));
+  }
+  conflicts
+}
+
+fn parse(accelerator: &str) -> crate::Result<Accelerator> {
+  accelerator
+    .parse()
+    .map_err(crate::Error::InvalidAccelerator)
+}
+
+fn is_editing_shortcut(accelerator: &Accelerator) -> bool {
+  EDITING_SHORTCUTS
+    .iter()
+    .any(|s| s.parse::<Accelerator>().ok().as_ref() == Some(accelerator))
+}
+
+fn join_ids<'a>(ids: impl Iterator<Item = &'a MenuId>) -> String {
+  ids
+    .map(|id| format!("`{}`", id.0))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
+/// Collects the items of a menu and its submenus, each item once even if it is appended several times.
+fn collect_items<R: Runtime>(
+  items: &[MenuItemKind<R>],
+  item_refs: &mut Vec<(MenuId, WeakMenuItem<R>)>,
+) -> crate::Result<()> {
+  for item in items {
+    match item {
+      MenuItemKind::Submenu(submenu) => collect_items(&submenu.items()?, item_refs)?,
+      item => {
+        let item_ref = item.downgrade();
+        if !item_refs.iter().any(|(_, i)| i.ptr_eq(&item_ref)) {
+          item_refs.push((item.id().clone(), item_ref));
+        }
+      }
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn bound(items: &[(&str, &str)]) -> Vec<(MenuId, String, Accelerator)> {
+    items
+      .iter()
+      .map(|(id, accelerator)| {
+        (
+          MenuId::new(id),
+          accelerator.to_string(),
+          accelerator.parse().unwrap(),
+        )
+      })
+      .collect()
+  }
+
+  #[test]
+  fn menu_conflicts_are_detected() {
+    assert!(menu_conflicts(&bound(&[("open", "CmdOrCtrl+O"), ("save", "CmdOrCtrl+S")])).is_empty());
+
+    // equivalent shortcuts conflict
+    let conflicts = menu_conflicts(&bound(&[
+      ("save", "CmdOrCtrl+Shift+S"),
+      ("save-as", "Shift+CommandOrControl+S"),
+      ("open", "CmdOrCtrl+O"),
+    ]));
+    assert_eq!(
+      conflicts,
+      vec!["`CmdOrCtrl+Shift+S` is bound to `save`, `save-as`".to_string()]
+    );
+
+    let conflicts = menu_conflicts(&bound(&[("duplicate", "CmdOrCtrl+C")]));
+    assert_eq!(
+      conflicts,
+      vec!["`CmdOrCtrl+C` bound to `duplicate` is a text editing shortcut".to_string()]
+    );
+  }
+
+  #[test]
+  fn rebind_conflicts_are_detected() {
+    let parsed = "CmdOrCtrl+Q".parse().unwrap();
+    assert!(rebind_conflicts("CmdOrCtrl+Q", &parsed, &[]).is_empty());
+    assert_eq!(
+      rebind_conflicts("CmdOrCtrl+Q", &parsed, &[MenuId::new("quit")]),
+      vec!["`CmdOrCtrl+Q` is already bound to `quit`".to_string()]
+    );
+
+    let parsed = "CmdOrCtrl+V".parse().unwrap();
+    assert_eq!(
+      rebind_conflicts("CmdOrCtrl+V", &parsed, &[MenuId::new("paste-special")]),
+      vec![
+        "`CmdOrCtrl+V` is already bound to `paste-special`".to_string(),
+        "`CmdOrCtrl+V` is a text editing shortcut".to_string()
+      ]
+    );
+  }
+}
@@ -0,0 +1,505 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  any::TypeId,
+  collections::{HashMap, HashSet},
+  sync::{Arc, Mutex, Weak},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use super::{CheckMenuItem, CheckMenuItemInner, MenuId};
+use crate::{sealed::ManagerBase, AppHandle, Emitter, Listener, Runtime, StateManager};
+
+type Observer<T> = Arc<dyn Fn(&T) + Send + Sync>;
+
+/// A value that notifies its observers when it changes.
+///
+/// Manage it with [`Manager::manage`](crate::Manager::manage) to bind menu items to it with [`MenuBinding::state`],
+/// so that commands setting the value update the menu and clicking the items update the value.
+///
+/// # Examples
+///
+/// ```no_run
+/// use tauri::{menu::{CheckMenuItemBuilder, MenuBinding, MenuBuilder, Observable}, State};
+///
+/// #[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+/// #[serde(transparent)]
+/// struct AlwaysOnTop(bool);
+///
+/// #[tauri::command]
+/// fn set_always_on_top(always_on_top: State<'_, Observable<AlwaysOnTop>>, enabled: bool) {
+///   // the bound menu item is checked or unchecked accordingly
+///   always_on_top.set(AlwaysOnTop(enabled));
+/// }
+///
+/// tauri::Builder::default()
+///   .manage(Observable::new(AlwaysOnTop(false)))
+///   .setup(|app| {
+///     let handle = app.handle();
+///     let item = CheckMenuItemBuilder::new("Always on Top")
+///       .bind(MenuBinding::state::<AlwaysOnTop>())
+///       .build(handle)?;
+///     app.set_menu(MenuBuilder::new(handle).item(&item).build()?)?;
+///     Ok(())
+///   })
+///   .invoke_handler(tauri::generate_handler![set_always_on_top]);
+/// ```
+pub struct Observable<T> {
+  value: Mutex<T>,
+  observers: Mutex<Vec<Observer<T>>>,
+}
+
+impl<T: Clone + PartialEq> Observable<T> {
+  /// Creates a new observable value.
+  pub fn new(value: T) -> Self {
+    Self {
+      value: Mutex::new(value),
+      observers: Default::default(),
+    }
+  }
+
+  /// The current value.
+  pub fn get(&self) -> T {
+    self.value.lock().unwrap().clone()
+  }
+
+  /// Sets the value, notifying the observers if it changed.
+  pub fn set(&self, value: T) {
+    {
+      let mut current = self.value.lock().unwrap();
+      if *current == value {
+        return;
+      }
+      *current = value.clone();
+    }
+
+    // the observers may read the value so they're called without holding the locks
+    let observers = self.observers.lock().unwrap().clone();
+    for observer in observers {
+      observer(&value);
+    }
+  }
+
+  /// Calls `f` with the new value whenever it changes.
+  pub fn observe<F: Fn(&T) + Send + Sync + 'static>(&self, f: F) {
+    self.observers.lock().unwrap().push(Arc::new(f));
+  }
+}
+
+/// The source of truth of a check or radio menu item, see [`CheckMenuItemBuilder::bind`](super::CheckMenuItemBuilder::bind).
+///
+/// A check item is checked when the value is `true`,
+/// and a radio item is checked when the value is equal to the value of the item.
+#[derive(Clone)]
+pub struct MenuBinding(Source);
+
+impl MenuBinding {
+  /// Binds the item to the payload of an event.
+  ///
+  /// The item is updated whenever the event is emitted, from Rust or from the frontend,
+  /// and clicking the item emits the event with its new value.
+  pub fn event<S: Into<String>>(event: S) -> Self {
+    Self(Source::Event(event.into()))
+  }
+
+  /// Binds the item to the [`Observable<T>`] managed state.
+  ///
+  /// The item is updated whenever the value changes, and clicking the item sets the value.
+  /// The state must be managed before the item is built.
+  pub fn state<T>() -> Self
+  where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + Send + Sync + 'static,
+  {
+    Self(Source::State(StateSource {
+      type_id: TypeId::of::<T>(),
+      type_name: std::any::type_name::<T>(),
+      get: get_state::<T>,
+      set: set_state::<T>,
+      observe: observe_state::<T>,
+    }))
+  }
+}
+
+#[derive(Clone)]
+enum Source {
+  Event(String),
+  State(StateSource),
+}
+
+#[derive(Clone, Copy)]
+struct StateSource {
+  type_id: TypeId,
+  type_name: &'static str,
+  get: fn(&StateManager) -> Option<Value>,
+  set: fn(&StateManager, Value) -> crate::Result<()>,
+  observe: fn(&StateManager, Box<dyn Fn(Value) + Send + Sync>) -> bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum SourceKey {
+  Event(String),
+  State(TypeId),
+}
+
+impl Source {
+  fn key(&self) -> SourceKey {
+    match self {
+      Self::Event(event) => SourceKey::Event(event.clone()),
+      Self::State(state) => SourceKey::State(state.type_id),
+    }
+  }
+}
+
+fn get_state<T>(state: &StateManager) -> Option<Value>
+where
+  T: Clone + PartialEq + Serialize + Send + Sync + 'static,
+{
+  let observable = state.try_get::<Observable<T>>()?;
+  serde_json::to_value(observable.get()).ok()
+}
+
+fn set_state<T>(state: &StateManager, value: Value) -> crate::Result<()>
+where
+  T: Clone + PartialEq + DeserializeOwned + Send + Sync + 'static,
+{
+  let observable = state
+    .try_get::<Observable<T>>()
+    .ok_or(crate::Error::StateNotManaged(std::any::type_name::<T>()))?;
+  observable.set(serde_json::from_value(value)?);
+  Ok(())
+}
+
+fn observe_state<T>(state: &StateManager, f: Box<dyn Fn(Value) + Send + Sync>) -> bool
+where
+  T: Clone + PartialEq + Serialize + Send + Sync + 'static,
+{
+  let Some(observable) = state.try_get::<Observable<T>>() else {
+    return false;
+  };
+  observable.observe(move |value| {
+    if let Ok(value) = serde_json::to_value(value) {
+      f(value);
+    }
+  });
+  true
+}
+
+struct BoundItem<R: Runtime> {
+  item: Weak<CheckMenuItemInner<R>>,
+  /// The group and the value of a radio item.
+  radio: Option<(String, Value)>,
+  /// The binding of a check item, radio items use the binding of their group.
+  source: Option<Source>,
+  /// The checked state the item was last synced to.
+  checked: bool,
+}
+
+/// The check items bound to a value and the radio items.
+///
+/// Several items can share an id, for instance the same entry built for the app menu and a context menu.
+pub(crate) struct MenuBindings<R: Runtime> {
+  items: Mutex<HashMap<MenuId, Vec<BoundItem<R>>>>,
+  groups: Mutex<HashMap<String, Source>>,
+  observed: Mutex<HashSet<SourceKey>>,
+}
+
+impl<R: Runtime> Default for MenuBindings<R> {
+  fn default() -> Self {
+    Self {
+      items: Default::default(),
+      groups: Default::default(),
+      observed: Default::default(),
+    }
+  }
+}
+
+impl<R: Runtime> MenuBindings<R> {
+  /// Tracks a check item bound to a value, or a radio item with its group and value.
+  ///
+  /// Binding a radio item binds its whole group.
+  pub fn register(
+    &self,
+    app_handle: &AppHandle<R>,
+    item: &CheckMenuItem<R>,
+    radio: Option<(String, Value)>,
+    binding: Option<MenuBinding>,
+  ) -> crate::Result<()> {
+    let source = binding.map(|b| b.0);
+    let group = radio.as_ref().map(|(group, _)| group.clone());
+    if let (Some(group), Some(source)) = (&group, &source) {
+      self
+        .groups
+        .lock()
+        .unwrap()
+        .insert(group.clone(), source.clone());
+    }
+
+    let checked = item.is_checked()?;
+    {
+      let mut items = self.items.lock().unwrap();
+      // the items that were dropped are no longer tracked
+      items.retain(|_, bound| {
+        bound.retain(|i| i.item.strong_count() > 0);
+        !bound.is_empty()
+      });
+      items.entry(item.id().clone()).or_default().push(BoundItem {
+        item: Arc::downgrade(&item.0),
+        radio,
+        source: if group.is_some() {
+          None
+        } else {
+          source.clone()
+        },
+        checked,
+      });
+    }
+
+    let source = source.or_else(|| {
+      let groups = self.groups.lock().unwrap();
+      group.as_ref().and_then(|g| groups.get(g).cloned())
+    });
+
+    match (source, group) {
+      (Some(source), _) => {
+        self.observe(app_handle, &source)?;
+        if let Source::State(state) = &source {
+          if let Some(value) = (state.get)(&app_handle.manager().state()) {
+            self.apply(&source.key(), &value)?;
+          }
+        }
+        Ok(())
+      }
+      (None, Some(group)) if checked => self.select(&group, item.id()),
+      _ => Ok(()),
+    }
+  }
+
+  /// Updates the bound value and the radio group of a clicked item.
+  ///
+  /// The check items are toggled by the OS before the menu event is emitted.
+  pub fn handle_click(&self, app_handle: &AppHandle<R>, id: &MenuId) -> crate::Result<()> {
+    let mut bound = {
+      let items = self.items.lock().unwrap();
+      let Some(bound) = items.get(id) else {
+        return Ok(());
+      };
+      bound
+        .iter()
+        .filter_map(|b| {
+          Some((
+            CheckMenuItem(b.item.upgrade()?),
+            b.checked,
+            b.radio.clone(),
+            b.source.clone(),
+          ))
+        })
+        .collect::<Vec<_>>()
+    };
+
+    // the menu event only has the id, so the clicked item is the one the OS toggled
+    let mut clicked = None;
+    for (index, (item, synced, _, _)) in bound.iter().enumerate() {
+      let checked = item.is_checked()?;
+      if checked != *synced {
+        clicked = Some((index, checked));
+        break;
+      }
+      clicked.get_or_insert((index, checked));
+    }
+    let Some((index, checked)) = clicked else {
+      return Ok(());
+    };
+    let (_, _, radio, source) = bound.swap_remove(index);
+
+    let (value, source) = match radio {
+      Some((group, value)) => {
+        self.select(&group, id)?;
+        let source = self.groups.lock().unwrap().get(&group).cloned();
+        (value, source)
+      }
+      None => (Value::Bool(checked), source),
+    };
+
+    match source {
+      Some(Source::Event(event)) => app_handle.emit(&event, value),
+      Some(Source::State(state)) => (state.set)(&app_handle.manager().state(), value),
+      None => Ok(()),
+    }
+  }
+
+  /// Listens to the changes of the source, once.
+  fn observe(&self, app_handle: &AppHandle<R>, source: &Source) -> crate::Result<()> {
+    let key = source.key();
+    if !self.observed.lock().unwrap().insert(key.clone()) {
+      return Ok(());
+    }
+
+    let app_handle_ = app_handle.clone();
+    let apply = move |value: Value| {
+      let bindings = &app_handle_.manager().menu.bindings;
+      if let Err(e) = bindings.apply(&key, &value) {
+        log::error!("failed to update the bound menu items: {e}");
+      }
+    };
+
+    match source {
+      Source::Event(event) => {
+        app_handle.listen_any(event.clone(), move |event| {
+          if let Ok(value) = serde_json::from_str(event.payload()) {
+            apply(value);
+          }
+        });
+      }
+      Source::State(state) => {
+        if !(state.observe)(&app_handle.manager().state(), Box::new(apply)) {
+          self.observed.lock().unwrap().remove(&source.key());
+          return Err(crate::Error::StateNotManaged(state.type_name));
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Checks the items bound to the source according to its new value.
+  fn apply(&self, key: &SourceKey, value: &Value) -> crate::Result<()> {
+    let updates = {
+      let mut items = self.items.lock().unwrap();
+      let groups = self.groups.lock().unwrap();
+      items
+        .values_mut()
+        .flatten()
+        .filter_map(|bound| {
+          let item = bound.item.upgrade()?;
+          let checked = match &bound.radio {
+            Some((group, item_value)) => {
+              (groups.get(group)?.key() == *key).then(|| item_value == value)?
+            }
+            None => (bound.source.as_ref()?.key() == *key).then(|| value.as_bool())??,
+          };
+          bound.checked = checked;
+          Some((CheckMenuItem(item), checked))
+        })
+        .collect::<Vec<_>>()
+    };
+
+    // the items are updated without holding the locks since it runs on the main thread
+    for (item, checked) in updates {
+      item.set_checked(checked)?;
+    }
+    Ok(())
+  }
+
+  /// Checks the radio items with the given id and unchecks the other items of their group.
+  fn select(&self, group: &str, id: &MenuId) -> crate::Result<()> {
+    let updates = self
+      .items
+      .lock()
+      .unwrap()
+      .iter_mut()
+      .flat_map(|(item_id, bound)| bound.iter_mut().map(move |b| (item_id, b)))
+      .filter(|(_, bound)| matches!(&bound.radio, Some((g, _)) if g == group))
+      .filter_map(|(item_id, bound)| {
+        let item = bound.item.upgrade()?;
+        bound.checked = item_id == id;
+        Some((CheckMenuItem(item), bound.checked))
+      })
+      .collect::<Vec<_>>();
+
+    for (item, checked) in updates {
+      item.set_checked(checked)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::any::TypeId;
+
+  use serde_json::Value;
+
+  use super::{MenuBinding, Observable, SourceKey};
+  use crate::{
+    menu::{CheckMenuItemBuilder, RadioMenuItemBuilder},
+    sealed::ManagerBase,
+    test::mock_app,
+    Manager,
+  };
+
+  #[test]
+  fn select_checks_every_item_sharing_the_id() {
+    let app = mock_app();
+    let handle = app.handle();
+    let light = RadioMenuItemBuilder::with_id("light", "Light", "theme", "light")
+      .build(handle)
+      .unwrap();
+    let light_copy = RadioMenuItemBuilder::with_id("light", "Light", "theme", "light")
+      .build(handle)
+      .unwrap();
+    let dark = RadioMenuItemBuilder::with_id("dark", "Dark", "theme", "dark")
+      .checked(true)
+      .build(handle)
+      .unwrap();
+    assert!(dark.is_checked().unwrap());
+
+    let bindings = &handle.manager().menu.bindings;
+    bindings.select("theme", light.id()).unwrap();
+
+    assert!(light.is_checked().unwrap());
+    assert!(light_copy.is_checked().unwrap());
+    assert!(!dark.is_checked().unwrap());
+  }
+
+  #[test]
+  fn apply_updates_every_bound_item() {
+    let app = mock_app();
+    app.manage(Observable::new(false));
+    let handle = app.handle();
+    let items = (0..2)
+      .map(|_| {
+        CheckMenuItemBuilder::with_id("pinned", "Pinned")
+          .bind(MenuBinding::state::<bool>())
+          .build(handle)
+          .unwrap()
+      })
+      .collect::<Vec<_>>();
+
+    app.state::<Observable<bool>>().set(true);
+    assert!(items.iter().all(|item| item.is_checked().unwrap()));
+
+    let bindings = &handle.manager().menu.bindings;
+    bindings
+      .apply(&SourceKey::State(TypeId::of::<bool>()), &Value::Bool(false))
+      .unwrap();
+    assert!(items.iter().all(|item| !item.is_checked().unwrap()));
+  }
+
+  #[test]
+  fn click_syncs_the_items_sharing_the_id() {
+    let app = mock_app();
+    app.manage(Observable::new(false));
+    let handle = app.handle();
+    let first = CheckMenuItemBuilder::with_id("pinned", "Pinned")
+      .bind(MenuBinding::state::<bool>())
+      .build(handle)
+      .unwrap();
+    let second = CheckMenuItemBuilder::with_id("pinned", "Pinned")
+      .bind(MenuBinding::state::<bool>())
+      .build(handle)
+      .unwrap();
+
+    // the OS toggles the clicked item before the menu event is emitted
+    second.set_checked(true).unwrap();
+    let bindings = &handle.manager().menu.bindings;
+    bindings.handle_click(handle, second.id()).unwrap();
+
+    assert!(app.state::<Observable<bool>>().get());
+    assert!(first.is_checked().unwrap());
+    assert!(second.is_checked().unwrap());
+  }
+}
@@ -2,15 +2,21 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use crate::{menu::CheckMenuItem, menu::MenuId, Manager, Runtime};
+use crate::{
+  menu::{CheckMenuItem, MenuBinding, MenuId},
+  sealed::ManagerBase,
+  Manager, Runtime,
+};
 
 /// A builder type for [`CheckMenuItem`]
 pub struct CheckMenuItemBuilder {
   id: Option<MenuId>,
   text: String,
+  text_key: Option<String>,
   enabled: bool,
   checked: bool,
   accelerator: Option<String>,
+  binding: Option<MenuBinding>,
 }
 
 impl CheckMenuItemBuilder {
@@ -22,9 +28,11 @@ impl CheckMenuItemBuilder {
     Self {
       id: None,
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       checked: true,
       accelerator: None,
+      binding: None,
     }
   }
 
@@ -36,9 +44,11 @@ impl CheckMenuItemBuilder {
     Self {
       id: Some(id.into()),
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       checked: true,
       accelerator: None,
+      binding: None,
     }
   }
 
@@ -66,25 +76,48 @@ impl CheckMenuItemBuilder {
     self
   }
 
+  /// Set a translation key for the text of this menu item.
+  ///
+  /// The text is resolved with the [`LocaleProvider`](crate::menu::LocaleProvider) of the app
+  /// and updated when the locale changes. The text given to the builder is used when the key can't be translated.
+  pub fn text_key<S: AsRef<str>>(mut self, key: S) -> Self {
+    self.text_key.replace(key.as_ref().to_string());
+    self
+  }
+
+  /// Bind the checked state of this menu item to a value.
+  ///
+  /// The item is checked or unchecked when the value changes, and clicking it updates the value,
+  /// so the menu stays in sync with the frontend and the commands.
+  pub fn bind(mut self, binding: MenuBinding) -> Self {
+    self.binding.replace(binding);
+    self
+  }
+
   /// Build the menu item
   pub fn build<R: Runtime, M: Manager<R>>(self, manager: &M) -> crate::Result<CheckMenuItem<R>> {
-    if let Some(id) = self.id {
-      CheckMenuItem::with_id(
-        manager,
-        id,
-        self.text,
-        self.enabled,
-        self.checked,
-        self.accelerator,
-      )
-    } else {
-      CheckMenuItem::new(
-        manager,
-        self.text,
-        self.enabled,
-        self.checked,
-        self.accelerator,
-      )
+    let menu = &manager.manager().menu;
+    let item = menu.localization.build(self.text_key, self.text, |text| {
+      if let Some(id) = self.id {
+        CheckMenuItem::with_id(
+          manager,
+          id,
+          text,
+          self.enabled,
+          self.checked,
+          self.accelerator,
+        )
+      } else {
+        CheckMenuItem::new(manager, text, self.enabled, self.checked, self.accelerator)
+      }
+    })?;
+
+    if let Some(binding) = self.binding {
+      menu
+        .bindings
+        .register(manager.app_handle(), &item, None, Some(binding))?;
     }
+
+    Ok(item)
   }
 }
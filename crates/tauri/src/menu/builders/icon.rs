@@ -5,6 +5,7 @@
 use crate::{
   image::Image,
   menu::{IconMenuItem, MenuId, NativeIcon},
+  sealed::ManagerBase,
   Manager, Runtime,
 };
 
@@ -12,6 +13,7 @@ use crate::{
 pub struct IconMenuItemBuilder<'a> {
   id: Option<MenuId>,
   text: String,
+  text_key: Option<String>,
   enabled: bool,
   icon: Option<Image<'a>>,
   native_icon: Option<NativeIcon>,
@@ -27,6 +29,7 @@ impl<'a> IconMenuItemBuilder<'a> {
     Self {
       id: None,
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       icon: None,
       native_icon: None,
@@ -42,6 +45,7 @@ impl<'a> IconMenuItemBuilder<'a> {
     Self {
       id: Some(id.into()),
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       icon: None,
       native_icon: None,
@@ -87,44 +91,43 @@ impl<'a> IconMenuItemBuilder<'a> {
     self
   }
 
+  /// Set a translation key for the text of this menu item.
+  ///
+  /// The text is resolved with the [`LocaleProvider`](crate::menu::LocaleProvider) of the app
+  /// and updated when the locale changes. The text given to the builder is used when the key can't be translated.
+  pub fn text_key<S: AsRef<str>>(mut self, key: S) -> Self {
+    self.text_key.replace(key.as_ref().to_string());
+    self
+  }
+
   /// Build the menu item
   pub fn build<R: Runtime, M: Manager<R>>(self, manager: &M) -> crate::Result<IconMenuItem<R>> {
-    if self.icon.is_some() {
-      if let Some(id) = self.id {
-        IconMenuItem::with_id(
+    let localization = &manager.manager().menu.localization;
+    localization.build(self.text_key, self.text, |text| {
+      if self.icon.is_some() {
+        if let Some(id) = self.id {
+          IconMenuItem::with_id(manager, id, text, self.enabled, self.icon, self.accelerator)
+        } else {
+          IconMenuItem::new(manager, text, self.enabled, self.icon, self.accelerator)
+        }
+      } else if let Some(id) = self.id {
+        IconMenuItem::with_id_and_native_icon(
           manager,
           id,
-          self.text,
+          text,
           self.enabled,
-          self.icon,
+          self.native_icon,
           self.accelerator,
         )
       } else {
-        IconMenuItem::new(
+        IconMenuItem::with_native_icon(
           manager,
-          self.text,
+          text,
           self.enabled,
-          self.icon,
+          self.native_icon,
           self.accelerator,
         )
       }
-    } else if let Some(id) = self.id {
-      IconMenuItem::with_id_and_native_icon(
-        manager,
-        id,
-        self.text,
-        self.enabled,
-        self.native_icon,
-        self.accelerator,
-      )
-    } else {
-      IconMenuItem::with_native_icon(
-        manager,
-        self.text,
-        self.enabled,
-        self.native_icon,
-        self.accelerator,
-      )
-    }
+    })
   }
 }
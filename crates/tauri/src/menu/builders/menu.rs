@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use crate::{image::Image, menu::*, Manager, Runtime};
+use crate::{image::Image, menu::*, sealed::ManagerBase, Manager, Runtime};
 
 /// A builder type for [`Menu`]
 ///
@@ -64,14 +64,17 @@ impl<'m, R: Runtime, M: Manager<R>> MenuBuilder<'m, R, M> {
 
   /// Builds this menu
   pub fn build(self) -> crate::Result<Menu<R>> {
+    let items = self.items.into_iter().collect::<crate::Result<Vec<_>>>()?;
+    let accelerators = &self.manager.manager().menu.accelerators;
+    accelerators.check(&items)?;
+
     let menu = if let Some(id) = self.id {
       Menu::with_id(self.manager, id)?
     } else {
       Menu::new(self.manager)?
     };
 
-    for item in self.items {
-      let item = item?;
+    for item in items {
       menu.append(&item)?;
     }
 
@@ -115,6 +118,7 @@ pub struct SubmenuBuilder<'m, R: Runtime, M: Manager<R>> {
   pub(crate) id: Option<MenuId>,
   pub(crate) manager: &'m M,
   pub(crate) text: String,
+  pub(crate) text_key: Option<String>,
   pub(crate) enabled: bool,
   pub(crate) items: Vec<crate::Result<MenuItemKind<R>>>,
 }
@@ -129,6 +133,7 @@ impl<'m, R: Runtime, M: Manager<R>> SubmenuBuilder<'m, R, M> {
       id: None,
       items: Vec::new(),
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       manager,
     }
@@ -142,6 +147,7 @@ impl<'m, R: Runtime, M: Manager<R>> SubmenuBuilder<'m, R, M> {
     Self {
       id: Some(id.into()),
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       items: Vec::new(),
       manager,
@@ -154,13 +160,25 @@ impl<'m, R: Runtime, M: Manager<R>> SubmenuBuilder<'m, R, M> {
     self
   }
 
+  /// Set a translation key for the text of the submenu.
+  ///
+  /// The text is resolved with the [`LocaleProvider`](crate::menu::LocaleProvider) of the app
+  /// and updated when the locale changes. The text given to the builder is used when the key can't be translated.
+  pub fn text_key<S: AsRef<str>>(mut self, key: S) -> Self {
+    self.text_key.replace(key.as_ref().to_string());
+    self
+  }
+
   /// Builds this submenu
   pub fn build(self) -> crate::Result<Submenu<R>> {
-    let submenu = if let Some(id) = self.id {
-      Submenu::with_id(self.manager, id, self.text, self.enabled)?
-    } else {
-      Submenu::new(self.manager, self.text, self.enabled)?
-    };
+    let localization = &self.manager.manager().menu.localization;
+    let submenu = localization.build(self.text_key, self.text, |text| {
+      if let Some(id) = self.id {
+        Submenu::with_id(self.manager, id, text, self.enabled)
+      } else {
+        Submenu::new(self.manager, text, self.enabled)
+      }
+    })?;
 
     for item in self.items {
       let item = item?;
@@ -211,6 +229,22 @@ macro_rules! shared_menu_builder {
         self
       }
 
+      /// Add a radio [CheckMenuItem] selecting `value` in `group` to the menu, see [RadioMenuItemBuilder].
+      pub fn radio<I: Into<MenuId>, S: AsRef<str>, G: Into<String>, V: serde::Serialize>(
+        mut self,
+        id: I,
+        text: S,
+        group: G,
+        value: V,
+      ) -> Self {
+        self.items.push(
+          RadioMenuItemBuilder::with_id(id, text, group, value)
+            .build(self.manager)
+            .map(|i| i.kind()),
+        );
+        self
+      }
+
       /// Add an [IconMenuItem] to the menu.
       pub fn icon<I: Into<MenuId>, S: AsRef<str>>(
         mut self,
@@ -13,5 +13,7 @@ mod normal;
 pub use normal::MenuItemBuilder;
 mod check;
 pub use check::CheckMenuItemBuilder;
+mod radio;
+pub use radio::RadioMenuItemBuilder;
 mod icon;
 pub use icon::IconMenuItemBuilder;
@@ -2,12 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use crate::{menu::MenuId, menu::MenuItem, Manager, Runtime};
+use crate::{menu::MenuId, menu::MenuItem, sealed::ManagerBase, Manager, Runtime};
 
 /// A builder type for [`MenuItem`]
 pub struct MenuItemBuilder {
   id: Option<MenuId>,
   text: String,
+  text_key: Option<String>,
   enabled: bool,
   accelerator: Option<String>,
 }
@@ -21,6 +22,7 @@ impl MenuItemBuilder {
     Self {
       id: None,
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       accelerator: None,
     }
@@ -34,6 +36,7 @@ impl MenuItemBuilder {
     Self {
       id: Some(id.into()),
       text: text.as_ref().to_string(),
+      text_key: None,
       enabled: true,
       accelerator: None,
     }
@@ -57,12 +60,24 @@ impl MenuItemBuilder {
     self
   }
 
+  /// Set a translation key for the text of this menu item.
+  ///
+  /// The text is resolved with the [`LocaleProvider`](crate::menu::LocaleProvider) of the app
+  /// and updated when the locale changes. The text given to the builder is used when the key can't be translated.
+  pub fn text_key<S: AsRef<str>>(mut self, key: S) -> Self {
+    self.text_key.replace(key.as_ref().to_string());
+    self
+  }
+
   /// Build the menu item
   pub fn build<R: Runtime, M: Manager<R>>(self, manager: &M) -> crate::Result<MenuItem<R>> {
-    if let Some(id) = self.id {
-      MenuItem::with_id(manager, id, self.text, self.enabled, self.accelerator)
-    } else {
-      MenuItem::new(manager, self.text, self.enabled, self.accelerator)
-    }
+    let localization = &manager.manager().menu.localization;
+    localization.build(self.text_key, self.text, |text| {
+      if let Some(id) = self.id {
+        MenuItem::with_id(manager, id, text, self.enabled, self.accelerator)
+      } else {
+        MenuItem::new(manager, text, self.enabled, self.accelerator)
+      }
+    })
   }
 }
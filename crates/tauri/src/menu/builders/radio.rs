@@ -0,0 +1,155 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+  menu::{CheckMenuItem, MenuBinding, MenuId},
+  sealed::ManagerBase,
+  Manager, Runtime,
+};
+
+/// A builder type for radio menu items, which are [`CheckMenuItem`]s of a group where a single item is checked.
+///
+/// Clicking an item of the group checks it and unchecks the other ones.
+///
+/// # Example
+///
+/// ```no_run
+/// use tauri::menu::{MenuBinding, MenuBuilder, RadioMenuItemBuilder};
+/// tauri::Builder::default()
+///   .setup(move |app| {
+///     let handle = app.handle();
+///     // the frontend and the commands select the theme by emitting the `theme` event
+///     let light = RadioMenuItemBuilder::new("Light", "theme", "light")
+///       .bind(MenuBinding::event("theme"))
+///       .build(handle)?;
+///     let dark = RadioMenuItemBuilder::new("Dark", "theme", "dark").build(handle)?;
+///     let menu = MenuBuilder::new(handle).items(&[&light, &dark]).build()?;
+///     app.set_menu(menu)?;
+///     Ok(())
+///   });
+/// ```
+pub struct RadioMenuItemBuilder {
+  id: Option<MenuId>,
+  text: String,
+  text_key: Option<String>,
+  enabled: bool,
+  checked: bool,
+  accelerator: Option<String>,
+  group: String,
+  value: serde_json::Result<Value>,
+  binding: Option<MenuBinding>,
+}
+
+impl RadioMenuItemBuilder {
+  /// Create a new radio menu item builder, for the item selecting `value` in `group`.
+  ///
+  /// - `text` could optionally contain an `&` before a character to assign this character as the mnemonic
+  ///   for this menu item. To display a `&` without assigning a mnemenonic, use `&&`.
+  pub fn new<S: AsRef<str>, G: Into<String>, V: Serialize>(text: S, group: G, value: V) -> Self {
+    Self {
+      id: None,
+      text: text.as_ref().to_string(),
+      text_key: None,
+      enabled: true,
+      checked: false,
+      accelerator: None,
+      group: group.into(),
+      value: serde_json::to_value(value),
+      binding: None,
+    }
+  }
+
+  /// Create a new radio menu item builder with the specified id, for the item selecting `value` in `group`.
+  ///
+  /// - `text` could optionally contain an `&` before a character to assign this character as the mnemonic
+  ///   for this menu item. To display a `&` without assigning a mnemenonic, use `&&`.
+  pub fn with_id<I: Into<MenuId>, S: AsRef<str>, G: Into<String>, V: Serialize>(
+    id: I,
+    text: S,
+    group: G,
+    value: V,
+  ) -> Self {
+    Self {
+      id: Some(id.into()),
+      ..Self::new(text, group, value)
+    }
+  }
+
+  /// Set the id for this menu item.
+  pub fn id<I: Into<MenuId>>(mut self, id: I) -> Self {
+    self.id.replace(id.into());
+    self
+  }
+
+  /// Set the enabled state for this menu item.
+  pub fn enabled(mut self, enabled: bool) -> Self {
+    self.enabled = enabled;
+    self
+  }
+
+  /// Set the checked state for this menu item, unchecking the other items of the group.
+  ///
+  /// This is ignored when the group is bound to a value.
+  pub fn checked(mut self, checked: bool) -> Self {
+    self.checked = checked;
+    self
+  }
+
+  /// Set the accelerator for this menu item.
+  pub fn accelerator<S: AsRef<str>>(mut self, accelerator: S) -> Self {
+    self.accelerator.replace(accelerator.as_ref().to_string());
+    self
+  }
+
+  /// Set a translation key for the text of this menu item.
+  ///
+  /// The text is resolved with the [`LocaleProvider`](crate::menu::LocaleProvider) of the app
+  /// and updated when the locale changes. The text given to the builder is used when the key can't be translated.
+  pub fn text_key<S: AsRef<str>>(mut self, key: S) -> Self {
+    self.text_key.replace(key.as_ref().to_string());
+    self
+  }
+
+  /// Bind the group of this menu item to a value, see [`MenuBinding`].
+  ///
+  /// The item of the group whose value is equal to the bound value is checked,
+  /// and clicking an item sets the bound value to the value of the item.
+  /// It only needs to be set on one of the items of the group.
+  pub fn bind(mut self, binding: MenuBinding) -> Self {
+    self.binding.replace(binding);
+    self
+  }
+
+  /// Build the menu item
+  pub fn build<R: Runtime, M: Manager<R>>(self, manager: &M) -> crate::Result<CheckMenuItem<R>> {
+    let value = self.value?;
+    let menu = &manager.manager().menu;
+    let item = menu.localization.build(self.text_key, self.text, |text| {
+      if let Some(id) = self.id {
+        CheckMenuItem::with_id(
+          manager,
+          id,
+          text,
+          self.enabled,
+          self.checked,
+          self.accelerator,
+        )
+      } else {
+        CheckMenuItem::new(manager, text, self.enabled, self.checked, self.accelerator)
+      }
+    })?;
+
+    menu.bindings.register(
+      manager.app_handle(),
+      &item,
+      Some((self.group, value)),
+      self.binding,
+    )?;
+
+    Ok(item)
+  }
+}
@@ -0,0 +1,147 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Creates the menu described by the `app > menu` configuration.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use tauri_utils::{
+  config::{MenuConfig, MenuItemConfig, PredefinedMenuItemConfig},
+  platform::Target,
+};
+
+use super::{
+  menu::about_metadata, CheckMenuItemBuilder, IconMenuItemBuilder, Menu, MenuItemBuilder,
+  MenuItemKind, PredefinedMenuItem, Submenu,
+};
+use crate::{image::Image, sealed::ManagerBase, AppHandle, Runtime};
+
+impl<R: Runtime> Menu<R> {
+  /// Creates the menu described by the configuration, using the items of the target platform.
+  ///
+  /// `icons` maps the icon paths of the configuration to the images embedded by the context.
+  pub(crate) fn from_config(
+    app_handle: &AppHandle<R>,
+    config: &MenuConfig,
+    icons: &HashMap<PathBuf, Image<'static>>,
+  ) -> crate::Result<Self> {
+    let items = config
+      .platform_items(Target::current())
+      .iter()
+      .map(|item| create_item(app_handle, item, icons))
+      .collect::<crate::Result<Vec<_>>>()?;
+    app_handle.manager().menu.accelerators.check(&items)?;
+
+    let menu = Menu::new(app_handle)?;
+    for item in items {
+      menu.append(&item)?;
+    }
+    Ok(menu)
+  }
+}
+
+fn create_item<R: Runtime>(
+  app_handle: &AppHandle<R>,
+  item: &MenuItemConfig,
+  icons: &HashMap<PathBuf, Image<'static>>,
+) -> crate::Result<MenuItemKind<R>> {
+  let item = match item {
+    MenuItemConfig::Item {
+      id,
+      text,
+      enabled,
+      accelerator,
+    } => {
+      let mut builder = MenuItemBuilder::new(text).enabled(*enabled);
+      if let Some(id) = id {
+        builder = builder.id(id);
+      }
+      if let Some(accelerator) = accelerator {
+        builder = builder.accelerator(accelerator);
+      }
+      MenuItemKind::MenuItem(builder.build(app_handle)?)
+    }
+    MenuItemConfig::Check {
+      id,
+      text,
+      enabled,
+      accelerator,
+      checked,
+    } => {
+      let mut builder = CheckMenuItemBuilder::new(text)
+        .enabled(*enabled)
+        .checked(*checked);
+      if let Some(id) = id {
+        builder = builder.id(id);
+      }
+      if let Some(accelerator) = accelerator {
+        builder = builder.accelerator(accelerator);
+      }
+      MenuItemKind::Check(builder.build(app_handle)?)
+    }
+    MenuItemConfig::Icon {
+      id,
+      text,
+      enabled,
+      accelerator,
+      icon,
+    } => {
+      let mut builder = IconMenuItemBuilder::new(text).enabled(*enabled);
+      if let Some(id) = id {
+        builder = builder.id(id);
+      }
+      if let Some(accelerator) = accelerator {
+        builder = builder.accelerator(accelerator);
+      }
+      // the icon is only missing when the context was not created by `generate_context!`
+      if let Some(icon) = icons.get(icon) {
+        builder = builder.icon(icon.clone());
+      }
+      MenuItemKind::Icon(builder.build(app_handle)?)
+    }
+    MenuItemConfig::Submenu {
+      id,
+      text,
+      enabled,
+      items,
+    } => {
+      let submenu = if let Some(id) = id {
+        Submenu::with_id(app_handle, id, text, *enabled)?
+      } else {
+        Submenu::new(app_handle, text, *enabled)?
+      };
+      for item in items {
+        submenu.append(&create_item(app_handle, item, icons)?)?;
+      }
+      MenuItemKind::Submenu(submenu)
+    }
+    MenuItemConfig::Predefined { item, text } => {
+      let text = text.as_deref();
+      let item = match item {
+        PredefinedMenuItemConfig::Separator => PredefinedMenuItem::separator(app_handle),
+        PredefinedMenuItemConfig::Copy => PredefinedMenuItem::copy(app_handle, text),
+        PredefinedMenuItemConfig::Cut => PredefinedMenuItem::cut(app_handle, text),
+        PredefinedMenuItemConfig::Paste => PredefinedMenuItem::paste(app_handle, text),
+        PredefinedMenuItemConfig::SelectAll => PredefinedMenuItem::select_all(app_handle, text),
+        PredefinedMenuItemConfig::Undo => PredefinedMenuItem::undo(app_handle, text),
+        PredefinedMenuItemConfig::Redo => PredefinedMenuItem::redo(app_handle, text),
+        PredefinedMenuItemConfig::Minimize => PredefinedMenuItem::minimize(app_handle, text),
+        PredefinedMenuItemConfig::Maximize => PredefinedMenuItem::maximize(app_handle, text),
+        PredefinedMenuItemConfig::Fullscreen => PredefinedMenuItem::fullscreen(app_handle, text),
+        PredefinedMenuItemConfig::Hide => PredefinedMenuItem::hide(app_handle, text),
+        PredefinedMenuItemConfig::HideOthers => PredefinedMenuItem::hide_others(app_handle, text),
+        PredefinedMenuItemConfig::ShowAll => PredefinedMenuItem::show_all(app_handle, text),
+        PredefinedMenuItemConfig::CloseWindow => PredefinedMenuItem::close_window(app_handle, text),
+        PredefinedMenuItemConfig::Quit => PredefinedMenuItem::quit(app_handle, text),
+        PredefinedMenuItemConfig::About => {
+          PredefinedMenuItem::about(app_handle, text, Some(about_metadata(app_handle)))
+        }
+        PredefinedMenuItemConfig::Services => PredefinedMenuItem::services(app_handle, text),
+      };
+      MenuItemKind::Predefined(item?)
+    }
+  };
+
+  Ok(item)
+}
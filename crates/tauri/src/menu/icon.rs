@@ -9,6 +9,7 @@ use super::{IconMenuItem, NativeIcon};
 use crate::menu::IconMenuItemInner;
 use crate::run_main_thread;
 use crate::{image::Image, menu::MenuId, AppHandle, Manager, Runtime};
+use crate::{menu::IsMenuItem, sealed::ManagerBase};
 
 impl<R: Runtime> IconMenuItem<R> {
   /// Create a new menu item.
@@ -31,7 +32,8 @@ impl<R: Runtime> IconMenuItem<R> {
     let app_handle = handle.clone();
 
     let text = text.as_ref().to_owned();
-    let accelerator = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accelerator = binding.as_deref().and_then(|s| s.parse().ok());
     let icon = match icon {
       Some(i) => Some(i.try_into()?),
       None => None,
@@ -46,7 +48,14 @@ impl<R: Runtime> IconMenuItem<R> {
       }
     })?;
 
-    Ok(Self(Arc::new(item)))
+    let item = Self(Arc::new(item));
+    handle
+      .manager()
+      .menu
+      .accelerators
+      .bind(item.kind(), binding);
+
+    Ok(item)
   }
 
   /// Create a new menu item with the specified id.
@@ -72,7 +81,8 @@ impl<R: Runtime> IconMenuItem<R> {
 
     let id = id.into();
     let text = text.as_ref().to_owned();
-    let accelerator = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accelerator = binding.as_deref().and_then(|s| s.parse().ok());
     let icon = match icon {
       Some(i) => Some(i.try_into()?),
       None => None,
@@ -87,7 +97,14 @@ impl<R: Runtime> IconMenuItem<R> {
       }
     })?;
 
-    Ok(Self(Arc::new(item)))
+    let item = Self(Arc::new(item));
+    handle
+      .manager()
+      .menu
+      .accelerators
+      .bind(item.kind(), binding);
+
+    Ok(item)
   }
 
   /// Create a new icon menu item but with a native icon.
@@ -114,7 +131,8 @@ impl<R: Runtime> IconMenuItem<R> {
 
     let text = text.as_ref().to_owned();
     let icon = native_icon.map(Into::into);
-    let accelerator = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accelerator = binding.as_deref().and_then(|s| s.parse().ok());
 
     let item = run_main_thread!(handle, || {
       let item = muda::IconMenuItem::with_native_icon(text, enabled, icon, accelerator);
@@ -125,7 +143,14 @@ impl<R: Runtime> IconMenuItem<R> {
       }
     })?;
 
-    Ok(Self(Arc::new(item)))
+    let item = Self(Arc::new(item));
+    handle
+      .manager()
+      .menu
+      .accelerators
+      .bind(item.kind(), binding);
+
+    Ok(item)
   }
 
   /// Create a new icon menu item with the specified id but with a native icon.
@@ -155,7 +180,8 @@ impl<R: Runtime> IconMenuItem<R> {
     let id = id.into();
     let text = text.as_ref().to_owned();
     let icon = native_icon.map(Into::into);
-    let accelerator = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accelerator = binding.as_deref().and_then(|s| s.parse().ok());
 
     let item = run_main_thread!(handle, || {
       let item =
@@ -167,7 +193,14 @@ impl<R: Runtime> IconMenuItem<R> {
       }
     })?;
 
-    Ok(Self(Arc::new(item)))
+    let item = Self(Arc::new(item));
+    handle
+      .manager()
+      .menu
+      .accelerators
+      .bind(item.kind(), binding);
+
+    Ok(item)
   }
 
   /// The application handle associated with this type.
@@ -205,11 +238,15 @@ impl<R: Runtime> IconMenuItem<R> {
 
   /// Set this menu item accelerator.
   pub fn set_accelerator<S: AsRef<str>>(&self, accelerator: Option<S>) -> crate::Result<()> {
-    let accel = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accel = binding.as_deref().and_then(|s| s.parse().ok());
     run_item_main_thread!(self, |self_: Self| (*self_.0)
       .as_ref()
-      .set_accelerator(accel))?
-    .map_err(Into::into)
+      .set_accelerator(accel))??;
+
+    let accelerators = &self.0.app_handle.manager().menu.accelerators;
+    accelerators.bind(self.kind(), binding);
+    Ok(())
   }
 
   /// Change this menu item icon or remove it.
@@ -0,0 +1,166 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  collections::HashMap,
+  sync::{Arc, Mutex},
+};
+
+use super::{IsMenuItem, MenuItemKind, WeakMenuItem};
+use crate::Runtime;
+
+/// Resolves the labels of the localized menu items.
+///
+/// Register it with [`AppHandle::set_locale_provider`](crate::AppHandle::set_locale_provider)
+/// and use a translation key in the menu item builders, for instance [`MenuItemBuilder::text_key`](super::MenuItemBuilder::text_key).
+///
+/// It is implemented for closures and for locale tables,
+/// a `HashMap<String, HashMap<String, String>>` mapping each locale to its translations.
+pub trait LocaleProvider: Send + Sync {
+  /// Translates the key for the given locale, or returns `None` to use the item default text.
+  fn translate(&self, locale: &str, key: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str, &str) -> Option<String> + Send + Sync> LocaleProvider for F {
+  fn translate(&self, locale: &str, key: &str) -> Option<String> {
+    self(locale, key)
+  }
+}
+
+impl LocaleProvider for HashMap<String, HashMap<String, String>> {
+  fn translate(&self, locale: &str, key: &str) -> Option<String> {
+    self.get(locale)?.get(key).cloned()
+  }
+}
+
+struct LocalizedItem<R: Runtime> {
+  key: String,
+  default_text: String,
+  item: WeakMenuItem<R>,
+}
+
+/// The locale state and the localized menu items, which are relabeled when the locale changes.
+pub(crate) struct MenuLocalization<R: Runtime> {
+  provider: Mutex<Option<Arc<dyn LocaleProvider>>>,
+  locale: Mutex<Option<String>>,
+  items: Mutex<Vec<LocalizedItem<R>>>,
+}
+
+impl<R: Runtime> Default for MenuLocalization<R> {
+  fn default() -> Self {
+    Self {
+      provider: Default::default(),
+      locale: Default::default(),
+      items: Default::default(),
+    }
+  }
+}
+
+impl<R: Runtime> MenuLocalization<R> {
+  pub fn locale(&self) -> Option<String> {
+    self.locale.lock().unwrap().clone()
+  }
+
+  /// The label of the key in the current locale, or `default_text` if it can't be translated.
+  pub fn text(&self, key: &str, default_text: &str) -> String {
+    // the provider is called without holding the locks since it may use the app locale
+    let locale = self.locale();
+    let provider = self.provider.lock().unwrap().clone();
+    match (locale, provider) {
+      (Some(locale), Some(provider)) => provider.translate(&locale, key),
+      _ => None,
+    }
+    .unwrap_or_else(|| default_text.to_string())
+  }
+
+  /// Builds an item labeled with the translation of `key`, which is relabeled when the locale changes.
+  ///
+  /// The item is built with `default_text` when there's no key.
+  pub fn build<I: IsMenuItem<R>>(
+    &self,
+    key: Option<String>,
+    default_text: String,
+    build: impl FnOnce(String) -> crate::Result<I>,
+  ) -> crate::Result<I> {
+    let Some(key) = key else {
+      return build(default_text);
+    };
+
+    let item = build(self.text(&key, &default_text))?;
+    // predefined items are translated by the OS
+    let item_kind = item.kind();
+    if !matches!(item_kind, MenuItemKind::Predefined(_)) {
+      self.items.lock().unwrap().push(LocalizedItem {
+        key,
+        default_text,
+        item: item_kind.downgrade(),
+      });
+    }
+    Ok(item)
+  }
+
+  pub fn set_provider(&self, provider: Box<dyn LocaleProvider>) -> crate::Result<()> {
+    self.provider.lock().unwrap().replace(provider.into());
+    self.relabel()
+  }
+
+  pub fn set_locale(&self, locale: String) -> crate::Result<()> {
+    self.locale.lock().unwrap().replace(locale);
+    self.relabel()
+  }
+
+  fn relabel(&self) -> crate::Result<()> {
+    let mut items = Vec::new();
+    // the items that were dropped are no longer tracked
+    self
+      .items
+      .lock()
+      .unwrap()
+      .retain(|i| match i.item.upgrade() {
+        Some(item) => {
+          items.push((item, i.key.clone(), i.default_text.clone()));
+          true
+        }
+        None => false,
+      });
+
+    // the labels are translated and set without holding the lock,
+    // since the provider may build menu items and setting a label runs on the main thread
+    for (item, key, default_text) in items {
+      let text = self.text(&key, &default_text);
+      match item {
+        MenuItemKind::MenuItem(i) => i.set_text(text)?,
+        MenuItemKind::Submenu(i) => i.set_text(text)?,
+        MenuItemKind::Check(i) => i.set_text(text)?,
+        MenuItemKind::Icon(i) => i.set_text(text)?,
+        MenuItemKind::Predefined(_) => (),
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use super::MenuLocalization;
+  use crate::test::MockRuntime;
+
+  #[test]
+  fn provider_can_read_the_locale() {
+    let localization = Arc::new(MenuLocalization::<MockRuntime>::default());
+    let weak = Arc::downgrade(&localization);
+    localization
+      .set_provider(Box::new(move |locale: &str, key: &str| {
+        let current = weak.upgrade()?.locale()?;
+        Some(format!("{key} ({current}, {locale})"))
+      }))
+      .unwrap();
+    localization.set_locale("fr".into()).unwrap();
+
+    assert_eq!(localization.text("open", "Open"), "open (fr, fr)");
+  }
+}
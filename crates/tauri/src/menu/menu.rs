@@ -10,6 +10,7 @@ use super::{
   AboutMetadata, IsMenuItem, Menu, MenuInner, MenuItemKind, PredefinedMenuItem, Submenu,
 };
 use crate::run_main_thread;
+use crate::sealed::ManagerBase;
 use crate::Window;
 use crate::{AppHandle, Manager, Position, Runtime};
 use muda::ContextMenu;
@@ -129,6 +130,7 @@ impl<R: Runtime> Menu<R> {
     manager: &M,
     items: &[&dyn IsMenuItem<R>],
   ) -> crate::Result<Self> {
+    check_accelerators(manager, items)?;
     let menu = Self::new(manager)?;
     menu.append_items(items)?;
     Ok(menu)
@@ -141,6 +143,7 @@ impl<R: Runtime> Menu<R> {
     id: I,
     items: &[&dyn IsMenuItem<R>],
   ) -> crate::Result<Self> {
+    check_accelerators(manager, items)?;
     let menu = Self::with_id(manager, id)?;
     menu.append_items(items)?;
     Ok(menu)
@@ -148,15 +151,7 @@ impl<R: Runtime> Menu<R> {
 
   /// Creates a menu filled with default menu items and submenus.
   pub fn default(app_handle: &AppHandle<R>) -> crate::Result<Self> {
-    let pkg_info = app_handle.package_info();
-    let config = app_handle.config();
-    let about_metadata = AboutMetadata {
-      name: Some(pkg_info.name.clone()),
-      version: Some(pkg_info.version.to_string()),
-      copyright: config.bundle.copyright.clone(),
-      authors: config.bundle.publisher.clone().map(|p| vec![p]),
-      ..Default::default()
-    };
+    let about_metadata = about_metadata(app_handle);
 
     let window_menu = Submenu::with_id_and_items(
       app_handle,
@@ -189,7 +184,7 @@ impl<R: Runtime> Menu<R> {
         #[cfg(target_os = "macos")]
         &Submenu::with_items(
           app_handle,
-          pkg_info.name.clone(),
+          app_handle.package_info().name.clone(),
           true,
           &[
             &PredefinedMenuItem::about(app_handle, None, Some(about_metadata))?,
@@ -402,3 +397,25 @@ impl<R: Runtime> Menu<R> {
     window.set_menu(self.clone())
   }
 }
+
+/// Checks the accelerators of the menu items for conflicts, see [`super::AcceleratorConflicts`].
+pub(crate) fn check_accelerators<R: Runtime, M: Manager<R>>(
+  manager: &M,
+  items: &[&dyn IsMenuItem<R>],
+) -> crate::Result<()> {
+  let items = items.iter().map(|i| i.kind()).collect::<Vec<_>>();
+  manager.manager().menu.accelerators.check(&items)
+}
+
+/// The about dialog metadata filled with the package information.
+pub(crate) fn about_metadata<R: Runtime>(app_handle: &AppHandle<R>) -> AboutMetadata<'static> {
+  let pkg_info = app_handle.package_info();
+  let config = app_handle.config();
+  AboutMetadata {
+    name: Some(pkg_info.name.clone()),
+    version: Some(pkg_info.version.to_string()),
+    copyright: config.bundle.copyright.clone(),
+    authors: config.bundle.publisher.clone().map(|p| vec![p]),
+    ..Default::default()
+  }
+}
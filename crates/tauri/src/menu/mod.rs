@@ -4,18 +4,25 @@
 
 //! Menu types and utilities.
 
+mod accelerator;
+pub(crate) mod binding;
 mod builders;
 mod check;
+mod config;
 mod icon;
+pub(crate) mod locale;
 #[allow(clippy::module_inception)]
 mod menu;
 mod normal;
 pub(crate) mod plugin;
 mod predefined;
 mod submenu;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
+pub use accelerator::{AcceleratorConflicts, AcceleratorRegistry};
+pub use binding::{MenuBinding, Observable};
 pub use builders::*;
+pub use locale::LocaleProvider;
 pub use menu::{HELP_SUBMENU_ID, WINDOW_SUBMENU_ID};
 use serde::{Deserialize, Serialize};
 
@@ -576,6 +583,16 @@ impl<R: Runtime> MenuItemKind<R> {
     }
   }
 
+  pub(crate) fn downgrade(&self) -> WeakMenuItem<R> {
+    match self {
+      MenuItemKind::MenuItem(i) => WeakMenuItem::MenuItem(Arc::downgrade(&i.0)),
+      MenuItemKind::Submenu(i) => WeakMenuItem::Submenu(Arc::downgrade(&i.0)),
+      MenuItemKind::Predefined(i) => WeakMenuItem::Predefined(Arc::downgrade(&i.0)),
+      MenuItemKind::Check(i) => WeakMenuItem::Check(Arc::downgrade(&i.0)),
+      MenuItemKind::Icon(i) => WeakMenuItem::Icon(Arc::downgrade(&i.0)),
+    }
+  }
+
   pub(crate) fn from_muda(app_handle: AppHandle<R>, i: muda::MenuItemKind) -> Self {
     match i {
       muda::MenuItemKind::MenuItem(i) => Self::MenuItem(MenuItem(Arc::new(MenuItemInner {
@@ -689,6 +706,42 @@ impl<R: Runtime> MenuItemKind<R> {
   }
 }
 
+/// A weak reference to a menu item, which does not keep it alive.
+pub(crate) enum WeakMenuItem<R: Runtime> {
+  MenuItem(Weak<MenuItemInner<R>>),
+  Submenu(Weak<SubmenuInner<R>>),
+  Predefined(Weak<PredefinedMenuItemInner<R>>),
+  Check(Weak<CheckMenuItemInner<R>>),
+  Icon(Weak<IconMenuItemInner<R>>),
+}
+
+impl<R: Runtime> WeakMenuItem<R> {
+  /// Returns the item if it is still alive.
+  pub(crate) fn upgrade(&self) -> Option<MenuItemKind<R>> {
+    match self {
+      Self::MenuItem(i) => i.upgrade().map(|i| MenuItemKind::MenuItem(MenuItem(i))),
+      Self::Submenu(i) => i.upgrade().map(|i| MenuItemKind::Submenu(Submenu(i))),
+      Self::Predefined(i) => i
+        .upgrade()
+        .map(|i| MenuItemKind::Predefined(PredefinedMenuItem(i))),
+      Self::Check(i) => i.upgrade().map(|i| MenuItemKind::Check(CheckMenuItem(i))),
+      Self::Icon(i) => i.upgrade().map(|i| MenuItemKind::Icon(IconMenuItem(i))),
+    }
+  }
+
+  /// Whether both references point to the same item.
+  pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Self::MenuItem(a), Self::MenuItem(b)) => a.ptr_eq(b),
+      (Self::Submenu(a), Self::Submenu(b)) => a.ptr_eq(b),
+      (Self::Predefined(a), Self::Predefined(b)) => a.ptr_eq(b),
+      (Self::Check(a), Self::Check(b)) => a.ptr_eq(b),
+      (Self::Icon(a), Self::Icon(b)) => a.ptr_eq(b),
+      _ => false,
+    }
+  }
+}
+
 impl<R: Runtime> Clone for MenuItemKind<R> {
   fn clone(&self) -> Self {
     match self {
@@ -7,6 +7,7 @@ use std::sync::Arc;
 use super::run_item_main_thread;
 use crate::menu::MenuItemInner;
 use crate::run_main_thread;
+use crate::{menu::IsMenuItem, sealed::ManagerBase};
 use crate::{menu::MenuId, AppHandle, Manager, Runtime};
 
 use super::MenuItem;
@@ -31,7 +32,8 @@ impl<R: Runtime> MenuItem<R> {
     let app_handle = handle.clone();
 
     let text = text.as_ref().to_owned();
-    let accelerator = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accelerator = binding.as_deref().and_then(|s| s.parse().ok());
 
     let item = run_main_thread!(handle, || {
       let item = muda::MenuItem::new(text, enabled, accelerator);
@@ -42,7 +44,14 @@ impl<R: Runtime> MenuItem<R> {
       }
     })?;
 
-    Ok(Self(Arc::new(item)))
+    let item = Self(Arc::new(item));
+    handle
+      .manager()
+      .menu
+      .accelerators
+      .bind(item.kind(), binding);
+
+    Ok(item)
   }
 
   /// Create a new menu item with the specified id.
@@ -66,7 +75,8 @@ impl<R: Runtime> MenuItem<R> {
     let app_handle = handle.clone();
 
     let id = id.into();
-    let accelerator = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accelerator = binding.as_deref().and_then(|s| s.parse().ok());
     let text = text.as_ref().to_owned();
 
     let item = run_main_thread!(handle, || {
@@ -78,7 +88,14 @@ impl<R: Runtime> MenuItem<R> {
       }
     })?;
 
-    Ok(Self(Arc::new(item)))
+    let item = Self(Arc::new(item));
+    handle
+      .manager()
+      .menu
+      .accelerators
+      .bind(item.kind(), binding);
+
+    Ok(item)
   }
 
   /// The application handle associated with this type.
@@ -116,10 +133,14 @@ impl<R: Runtime> MenuItem<R> {
 
   /// Set this menu item accelerator.
   pub fn set_accelerator<S: AsRef<str>>(&self, accelerator: Option<S>) -> crate::Result<()> {
-    let accel = accelerator.and_then(|s| s.as_ref().parse().ok());
+    let binding = accelerator.map(|s| s.as_ref().to_string());
+    let accel = binding.as_deref().and_then(|s| s.parse().ok());
     run_item_main_thread!(self, |self_: Self| (*self_.0)
       .as_ref()
-      .set_accelerator(accel))?
-    .map_err(Into::into)
+      .set_accelerator(accel))??;
+
+    let accelerators = &self.0.app_handle.manager().menu.accelerators;
+    accelerators.bind(self.kind(), binding);
+    Ok(())
   }
 }
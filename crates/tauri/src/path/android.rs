@@ -2,12 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use super::Result;
+use super::{CustomBaseDirectories, Result};
 use crate::{plugin::PluginHandle, Runtime};
 use std::path::PathBuf;
 
 /// A helper class to access the mobile path APIs.
-pub struct PathResolver<R: Runtime>(pub(crate) PluginHandle<R>);
+pub struct PathResolver<R: Runtime>(pub(crate) PluginHandle<R>, pub(crate) CustomBaseDirectories);
 
 #[derive(serde::Deserialize)]
 struct PathResponse {
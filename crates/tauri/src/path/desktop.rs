@@ -2,14 +2,40 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use super::{Error, Result};
-use crate::{AppHandle, Manager, Runtime};
-use std::path::PathBuf;
+use super::{CustomBaseDirectories, Error, Result};
+use crate::{AppHandle, Env, Manager, Runtime};
+use std::path::{Path, PathBuf};
+
+/// Name of the file that enables the portable mode when placed next to the executable.
+#[cfg(desktop)]
+const PORTABLE_MARKER_FILE: &str = "portable";
 
 /// The path resolver is a helper class for general and application-specific path APIs.
-pub struct PathResolver<R: Runtime>(pub(crate) AppHandle<R>);
+pub struct PathResolver<R: Runtime>(
+  pub(crate) AppHandle<R>,
+  pub(crate) CustomBaseDirectories,
+  Option<PathBuf>,
+);
 
 impl<R: Runtime> PathResolver<R> {
+  pub(crate) fn new(app: AppHandle<R>) -> Self {
+    let portable_dir = portable_dir(&app.config().app, &app.config().identifier, &app.env());
+    Self(app, Default::default(), portable_dir)
+  }
+
+  /// The directory where the app directories are stored when running in portable mode,
+  /// or [`None`] if the portable mode is disabled.
+  ///
+  /// See [`AppConfig::portable`](crate::utils::config::AppConfig::portable).
+  pub fn portable_dir(&self) -> Option<&Path> {
+    self.2.as_deref()
+  }
+
+  /// Resolves a directory inside [`Self::portable_dir`] in portable mode.
+  fn portable(&self, dir: &str) -> Option<PathBuf> {
+    self.2.as_ref().map(|portable_dir| portable_dir.join(dir))
+  }
+
   /// Returns the path to the user's audio directory.
   ///
   /// ## Platform-specific
@@ -18,7 +44,7 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Resolves to `$HOME/Music`.
   /// - **Windows:** Resolves to `{FOLDERID_Music}`.
   pub fn audio_dir(&self) -> Result<PathBuf> {
-    dirs::audio_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_MUSIC_DIR", dirs::audio_dir)
   }
 
   /// Returns the path to the user's cache directory.
@@ -73,7 +99,7 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Resolves to `$HOME/Desktop`.
   /// - **Windows:** Resolves to `{FOLDERID_Desktop}`.
   pub fn desktop_dir(&self) -> Result<PathBuf> {
-    dirs::desktop_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_DESKTOP_DIR", dirs::desktop_dir)
   }
 
   /// Returns the path to the user's document directory.
@@ -84,7 +110,7 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Resolves to `$HOME/Documents`.
   /// - **Windows:** Resolves to `{FOLDERID_Documents}`.
   pub fn document_dir(&self) -> Result<PathBuf> {
-    dirs::document_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_DOCUMENTS_DIR", dirs::document_dir)
   }
 
   /// Returns the path to the user's download directory.
@@ -95,7 +121,7 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Resolves to `$HOME/Downloads`.
   /// - **Windows:** Resolves to `{FOLDERID_Downloads}`.
   pub fn download_dir(&self) -> Result<PathBuf> {
-    dirs::download_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_DOWNLOAD_DIR", dirs::download_dir)
   }
 
   /// Returns the path to the user's executable directory.
@@ -124,10 +150,14 @@ impl<R: Runtime> PathResolver<R> {
   ///
   /// ## Platform-specific
   ///
-  /// - **Linux:** Resolves to `$HOME`.
+  /// - **Linux:** Resolves to `$HOME`, or `$SNAP_REAL_HOME` inside a Snap.
   /// - **macOS:** Resolves to `$HOME`.
   /// - **Windows:** Resolves to `{FOLDERID_Profile}`.
   pub fn home_dir(&self) -> Result<PathBuf> {
+    #[cfg(target_os = "linux")]
+    if let Some(sandbox) = self.0.env().sandbox {
+      return super::sandbox::home_dir(sandbox).ok_or(Error::UnknownPath);
+    }
     dirs::home_dir().ok_or(Error::UnknownPath)
   }
 
@@ -139,7 +169,7 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Resolves to `$HOME/Pictures`.
   /// - **Windows:** Resolves to `{FOLDERID_Pictures}`.
   pub fn picture_dir(&self) -> Result<PathBuf> {
-    dirs::picture_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_PICTURES_DIR", dirs::picture_dir)
   }
 
   /// Returns the path to the user's public directory.
@@ -150,17 +180,21 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Resolves to `$HOME/Public`.
   /// - **Windows:** Resolves to `{FOLDERID_Public}`.
   pub fn public_dir(&self) -> Result<PathBuf> {
-    dirs::public_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_PUBLICSHARE_DIR", dirs::public_dir)
   }
 
   /// Returns the path to the user's runtime directory.
   ///
   /// ## Platform-specific
   ///
-  /// - **Linux:** Resolves to `$XDG_RUNTIME_DIR`.
+  /// - **Linux:** Resolves to `$XDG_RUNTIME_DIR`, or `$XDG_RUNTIME_DIR/app/$FLATPAK_ID` inside a Flatpak.
   /// - **macOS:** Not supported.
   /// - **Windows:** Not supported.
   pub fn runtime_dir(&self) -> Result<PathBuf> {
+    #[cfg(target_os = "linux")]
+    if let Some(sandbox) = self.0.env().sandbox {
+      return super::sandbox::runtime_dir(sandbox).ok_or(Error::UnknownPath);
+    }
     dirs::runtime_dir().ok_or(Error::UnknownPath)
   }
 
@@ -172,7 +206,7 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Not supported.
   /// - **Windows:** Resolves to `{FOLDERID_Templates}`.
   pub fn template_dir(&self) -> Result<PathBuf> {
-    dirs::template_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_TEMPLATES_DIR", dirs::template_dir)
   }
 
   /// Returns the path to the user's video dir
@@ -183,7 +217,7 @@ impl<R: Runtime> PathResolver<R> {
   /// - **macOS:** Resolves to `$HOME/Movies`.
   /// - **Windows:** Resolves to `{FOLDERID_Videos}`.
   pub fn video_dir(&self) -> Result<PathBuf> {
-    dirs::video_dir().ok_or(Error::UnknownPath)
+    self.user_dir("XDG_VIDEOS_DIR", dirs::video_dir)
   }
 
   /// Returns the path to the resource directory of this app.
@@ -195,7 +229,12 @@ impl<R: Runtime> PathResolver<R> {
   /// Returns the path to the suggested directory for your app's config files.
   ///
   /// Resolves to [`config_dir`](Self::config_dir)`/${bundle_identifier}`.
+  /// In portable mode, resolves to [`portable_dir`](Self::portable_dir)`/config`.
   pub fn app_config_dir(&self) -> Result<PathBuf> {
+    if let Some(dir) = self.portable("config") {
+      return Ok(dir);
+    }
+
     dirs::config_dir()
       .ok_or(Error::UnknownPath)
       .map(|dir| dir.join(&self.0.config().identifier))
@@ -204,7 +243,12 @@ impl<R: Runtime> PathResolver<R> {
   /// Returns the path to the suggested directory for your app's data files.
   ///
   /// Resolves to [`data_dir`](Self::data_dir)`/${bundle_identifier}`.
+  /// In portable mode, resolves to [`portable_dir`](Self::portable_dir)`/data`.
   pub fn app_data_dir(&self) -> Result<PathBuf> {
+    if let Some(dir) = self.portable("data") {
+      return Ok(dir);
+    }
+
     dirs::data_dir()
       .ok_or(Error::UnknownPath)
       .map(|dir| dir.join(&self.0.config().identifier))
@@ -213,7 +257,12 @@ impl<R: Runtime> PathResolver<R> {
   /// Returns the path to the suggested directory for your app's local data files.
   ///
   /// Resolves to [`local_data_dir`](Self::local_data_dir)`/${bundle_identifier}`.
+  /// In portable mode, resolves to [`portable_dir`](Self::portable_dir)`/local-data`.
   pub fn app_local_data_dir(&self) -> Result<PathBuf> {
+    if let Some(dir) = self.portable("local-data") {
+      return Ok(dir);
+    }
+
     dirs::data_local_dir()
       .ok_or(Error::UnknownPath)
       .map(|dir| dir.join(&self.0.config().identifier))
@@ -222,7 +271,12 @@ impl<R: Runtime> PathResolver<R> {
   /// Returns the path to the suggested directory for your app's cache files.
   ///
   /// Resolves to [`cache_dir`](Self::cache_dir)`/${bundle_identifier}`.
+  /// In portable mode, resolves to [`portable_dir`](Self::portable_dir)`/cache`.
   pub fn app_cache_dir(&self) -> Result<PathBuf> {
+    if let Some(dir) = self.portable("cache") {
+      return Ok(dir);
+    }
+
     dirs::cache_dir()
       .ok_or(Error::UnknownPath)
       .map(|dir| dir.join(&self.0.config().identifier))
@@ -235,7 +289,13 @@ impl<R: Runtime> PathResolver<R> {
   /// - **Linux:** Resolves to [`local_data_dir`](Self::local_data_dir)`/${bundle_identifier}/logs`.
   /// - **macOS:** Resolves to [`home_dir`](Self::home_dir)`/Library/Logs/${bundle_identifier}`
   /// - **Windows:** Resolves to [`local_data_dir`](Self::local_data_dir)`/${bundle_identifier}/logs`.
+  ///
+  /// In portable mode, resolves to [`portable_dir`](Self::portable_dir)`/logs` on all platforms.
   pub fn app_log_dir(&self) -> Result<PathBuf> {
+    if let Some(dir) = self.portable("logs") {
+      return Ok(dir);
+    }
+
     #[cfg(target_os = "macos")]
     let path = dirs::home_dir()
       .ok_or(Error::UnknownPath)
@@ -253,4 +313,87 @@ impl<R: Runtime> PathResolver<R> {
   pub fn temp_dir(&self) -> Result<PathBuf> {
     Ok(std::env::temp_dir())
   }
+
+  /// Returns the location on the host of a file exposed to the sandbox by the documents portal,
+  /// for instance a file picked with the file dialog, or the given path if it is not a portal path.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux:** The documents portal mounts the files on `/run/user/$UID/doc` on Flatpak and Snap.
+  /// - **Windows / macOS:** Returns the given path.
+  pub fn host_path<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+    #[cfg(target_os = "linux")]
+    if self.0.env().sandbox.is_some() {
+      if let Some(host_path) = super::sandbox::document_portal_host_path(path.as_ref()) {
+        return host_path;
+      }
+    }
+    path.as_ref().to_path_buf()
+  }
+
+  /// Resolves a [`xdg-user-dirs`](https://www.freedesktop.org/wiki/Software/xdg-user-dirs/) directory,
+  /// reading the host configuration inside a sandbox.
+  #[allow(unused_variables)]
+  fn user_dir(&self, name: &str, resolve: fn() -> Option<PathBuf>) -> Result<PathBuf> {
+    #[cfg(target_os = "linux")]
+    if let Some(dir) = self
+      .0
+      .env()
+      .sandbox
+      .and_then(|sandbox| super::sandbox::user_dir(sandbox, name))
+    {
+      return Ok(dir);
+    }
+    resolve().ok_or(Error::UnknownPath)
+  }
+}
+
+/// Resolves the portable directory if the portable mode is enabled by the configuration or the marker file.
+#[cfg(desktop)]
+fn portable_dir(
+  config: &crate::utils::config::AppConfig,
+  identifier: &str,
+  env: &Env,
+) -> Option<PathBuf> {
+  let location = portable_location(env)?;
+  if config.portable || location.join(PORTABLE_MARKER_FILE).is_file() {
+    Some(location.join(identifier))
+  } else {
+    None
+  }
+}
+
+#[cfg(mobile)]
+fn portable_dir(
+  _config: &crate::utils::config::AppConfig,
+  _identifier: &str,
+  _env: &Env,
+) -> Option<PathBuf> {
+  None
+}
+
+/// The directory the app is distributed in: the one containing the AppImage on Linux,
+/// the `.app` bundle on macOS or the executable otherwise.
+#[cfg(desktop)]
+fn portable_location(env: &Env) -> Option<PathBuf> {
+  #[cfg(target_os = "linux")]
+  if let Some(appimage) = &env.appimage {
+    return Path::new(appimage).parent().map(Path::to_path_buf);
+  }
+  #[cfg(not(target_os = "linux"))]
+  let _ = env;
+
+  let exe = crate::utils::platform::current_exe().ok()?;
+  let exe_dir = exe.parent()?;
+
+  // the executable is located at `Name.app/Contents/MacOS/name`
+  #[cfg(target_os = "macos")]
+  if let Some(bundle) = exe_dir
+    .ancestors()
+    .find(|dir| dir.extension().map_or(false, |ext| ext == "app"))
+  {
+    return bundle.parent().map(Path::to_path_buf);
+  }
+
+  Some(exe_dir.to_path_buf())
 }
@@ -3,8 +3,10 @@
 // SPDX-License-Identifier: MIT
 
 use std::{
+  collections::HashMap,
   path::{Component, Display, Path, PathBuf},
   str::FromStr,
+  sync::Mutex,
 };
 
 use crate::Runtime;
@@ -20,6 +22,8 @@ use crate::error::*;
 mod android;
 #[cfg(not(target_os = "android"))]
 mod desktop;
+#[cfg(target_os = "linux")]
+mod sandbox;
 
 #[cfg(target_os = "android")]
 pub use android::PathResolver;
@@ -218,7 +222,112 @@ impl BaseDirectory {
   }
 }
 
+/// A base directory given to the path API, either a [`BaseDirectory`] or the name of a directory
+/// registered with [`PathResolver::register_base_directory`].
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Directory {
+  Base(BaseDirectory),
+  Custom(String),
+}
+
+/// Base directories registered at runtime, identified by their variable name without the `$` prefix.
+#[derive(Default)]
+pub(crate) struct CustomBaseDirectories(Mutex<HashMap<String, PathBuf>>);
+
+impl CustomBaseDirectories {
+  fn get(&self, name: &str) -> Option<PathBuf> {
+    self.0.lock().unwrap().get(variable_name(name)).cloned()
+  }
+
+  fn insert(&self, name: String, path: PathBuf) {
+    self.0.lock().unwrap().insert(name, path);
+  }
+
+  fn remove(&self, name: &str) -> Option<PathBuf> {
+    self.0.lock().unwrap().remove(variable_name(name))
+  }
+}
+
+/// The variable name without its optional `$` prefix.
+fn variable_name(variable: &str) -> &str {
+  variable.strip_prefix('$').unwrap_or(variable)
+}
+
 impl<R: Runtime> PathResolver<R> {
+  /// Registers a named base directory that can be used like a [`BaseDirectory`] variable,
+  /// for instance `$WORKSPACE/notes.md` on [`Self::parse`] and file system scope patterns, or with [`Self::resolve_custom`].
+  ///
+  /// The name is written in uppercase without the `$` prefix and cannot shadow a [`BaseDirectory`] variable.
+  /// Registering an existing name replaces its path.
+  /// Scopes are resolved when they are created, so the directory should be registered on the app or plugin setup.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::Manager;
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     app.path().register_base_directory("WORKSPACE", "/mnt/workspaces/tauri")?;
+  ///     let path = app.path().parse("$WORKSPACE/notes.md")?;
+  ///     assert_eq!(path.to_str().unwrap(), "/mnt/workspaces/tauri/notes.md");
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn register_base_directory<N: Into<String>, P: Into<PathBuf>>(
+    &self,
+    name: N,
+    path: P,
+  ) -> Result<()> {
+    let name = name.into();
+    let path = path.into();
+
+    let valid_name = name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+      && name
+        .chars()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+    if !valid_name {
+      return Err(Error::InvalidBaseDirectory(
+        name,
+        "the name must only contain uppercase letters, digits and underscores",
+      ));
+    }
+    if BaseDirectory::from_variable(&format!("${name}")).is_some() {
+      return Err(Error::InvalidBaseDirectory(
+        name,
+        "the name is already used by a built-in base directory",
+      ));
+    }
+    if !path.is_absolute() {
+      return Err(Error::InvalidBaseDirectory(
+        name,
+        "the path must be absolute",
+      ));
+    }
+
+    self.1.insert(name, path);
+    Ok(())
+  }
+
+  /// Removes a base directory registered with [`Self::register_base_directory`], returning its path.
+  pub fn unregister_base_directory(&self, name: &str) -> Option<PathBuf> {
+    self.1.remove(name)
+  }
+
+  /// The path of a base directory registered with [`Self::register_base_directory`].
+  pub fn custom_base_directory(&self, name: &str) -> Option<PathBuf> {
+    self.1.get(name)
+  }
+
+  /// Resolves the path with a base directory registered with [`Self::register_base_directory`].
+  pub fn resolve_custom<P: AsRef<Path>>(&self, path: P, name: &str) -> Result<PathBuf> {
+    let mut base_dir_path = self
+      .custom_base_directory(name)
+      .ok_or_else(|| Error::UnknownBaseDirectory(variable_name(name).to_string()))?;
+    base_dir_path.push(path);
+    Ok(base_dir_path)
+  }
+
   /// Resolves the path with the base directory.
   ///
   /// # Examples
@@ -238,6 +347,8 @@ impl<R: Runtime> PathResolver<R> {
 
   /// Parse the given path, resolving a [`BaseDirectory`] variable if the path starts with one.
   ///
+  /// Base directories registered with [`Self::register_base_directory`] are also resolved.
+  ///
   /// # Examples
   ///
   /// ```rust,no_run
@@ -254,8 +365,14 @@ impl<R: Runtime> PathResolver<R> {
     let mut components = path.as_ref().components();
     match components.next() {
       Some(Component::Normal(str)) => {
-        if let Some(base_directory) = BaseDirectory::from_variable(&str.to_string_lossy()) {
+        let variable = str.to_string_lossy();
+        if let Some(base_directory) = BaseDirectory::from_variable(&variable) {
           p.push(resolve_path::<R>(self, base_directory, None)?);
+        } else if let Some(custom) = variable
+          .strip_prefix('$')
+          .and_then(|name| self.custom_base_directory(name))
+        {
+          p.push(custom);
         } else {
           p.push(str);
         }
@@ -341,6 +458,48 @@ mod test {
 
   use std::path::PathBuf;
 
+  #[cfg(not(target_os = "android"))]
+  #[test]
+  fn custom_base_directory() {
+    use crate::Manager;
+
+    let app = crate::test::mock_app();
+    let resolver = app.path();
+    let workspace = std::env::temp_dir().join("workspace");
+
+    assert!(resolver
+      .register_base_directory("workspace", &workspace)
+      .is_err());
+    assert!(resolver
+      .register_base_directory("APPDATA", &workspace)
+      .is_err());
+    assert!(resolver
+      .register_base_directory("WORKSPACE", "relative")
+      .is_err());
+
+    resolver
+      .register_base_directory("WORKSPACE", &workspace)
+      .unwrap();
+    assert_eq!(
+      resolver.parse("$WORKSPACE/../notes.md").unwrap(),
+      workspace.join("notes.md")
+    );
+    assert_eq!(
+      resolver.resolve_custom("notes.md", "$WORKSPACE").unwrap(),
+      workspace.join("notes.md")
+    );
+
+    assert_eq!(
+      resolver.unregister_base_directory("WORKSPACE"),
+      Some(workspace)
+    );
+    assert!(resolver.resolve_custom("notes.md", "WORKSPACE").is_err());
+    assert_eq!(
+      resolver.parse("$WORKSPACE/notes.md").unwrap(),
+      PathBuf::from("$WORKSPACE/notes.md")
+    );
+  }
+
   impl Arbitrary for SafePathBuf {
     fn arbitrary(g: &mut Gen) -> Self {
       Self(PathBuf::arbitrary(g))
@@ -6,7 +6,7 @@ use std::path::{Component, Path, PathBuf, MAIN_SEPARATOR};
 
 use serialize_to_javascript::{default_template, DefaultTemplate, Template};
 
-use super::{BaseDirectory, Error, PathResolver, Result};
+use super::{Directory, Error, PathResolver, Result};
 use crate::{
   command,
   plugin::{Builder, TauriPlugin},
@@ -89,10 +89,14 @@ fn normalize_path_no_absolute(path: &Path) -> PathBuf {
 pub fn resolve_directory<R: Runtime>(
   _app: AppHandle<R>,
   resolver: State<'_, PathResolver<R>>,
-  directory: BaseDirectory,
+  directory: Directory,
   path: Option<PathBuf>,
 ) -> Result<PathBuf> {
-  super::resolve_path(&resolver, directory, path).map(|p| dunce::simplified(&p).to_path_buf())
+  let resolved = match directory {
+    Directory::Base(directory) => super::resolve_path(&resolver, directory, path),
+    Directory::Custom(name) => resolver.resolve_custom(path.unwrap_or_default(), &name),
+  };
+  resolved.map(|p| dunce::simplified(&p).to_path_buf())
 }
 
 #[command(root = "crate")]
@@ -235,12 +239,12 @@ pub(crate) fn init<R: Runtime>() -> TauriPlugin<R> {
       #[cfg(target_os = "android")]
       {
         let handle = _api.register_android_plugin("app.tauri", "PathPlugin")?;
-        app.manage(PathResolver(handle));
+        app.manage(PathResolver(handle, Default::default()));
       }
 
       #[cfg(not(target_os = "android"))]
       {
-        app.manage(PathResolver(app.clone()));
+        app.manage(PathResolver::new(app.clone()));
       }
 
       Ok(())
@@ -0,0 +1,117 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Path resolution inside the Flatpak and Snap sandboxes.
+
+use std::{
+  ffi::{CString, OsString},
+  os::unix::ffi::{OsStrExt, OsStringExt},
+  path::{Path, PathBuf},
+};
+
+use crate::utils::platform::Sandbox;
+
+/// The extended attribute the documents portal sets on its files with their location on the host.
+const HOST_PATH_XATTR: &std::ffi::CStr = c"user.document-portal.host-path";
+
+/// The user home directory on the host. Snap replaces `$HOME` with `$SNAP_USER_DATA`.
+pub fn home_dir(sandbox: Sandbox) -> Option<PathBuf> {
+  match sandbox {
+    Sandbox::Snap => std::env::var_os("SNAP_REAL_HOME").map(PathBuf::from),
+    _ => dirs::home_dir(),
+  }
+}
+
+/// Reads a directory such as `XDG_DOCUMENTS_DIR` from the host `user-dirs.dirs` file,
+/// since the sandbox points `$XDG_CONFIG_HOME` to a directory owned by the app.
+pub fn user_dir(sandbox: Sandbox, name: &str) -> Option<PathBuf> {
+  let home = home_dir(sandbox)?;
+  let contents = std::fs::read_to_string(home.join(".config/user-dirs.dirs")).ok()?;
+  parse_user_dir(&contents, name, &home)
+}
+
+fn parse_user_dir(contents: &str, name: &str, home: &Path) -> Option<PathBuf> {
+  contents.lines().find_map(|line| {
+    let value = line.trim().strip_prefix(name)?.strip_prefix('=')?;
+    let value = value.trim_matches('"');
+    let path = match value.strip_prefix("$HOME") {
+      Some(relative) => home.join(relative.trim_start_matches('/')),
+      None => PathBuf::from(value),
+    };
+    // a directory set to `$HOME/` is disabled
+    (path.is_absolute() && path != home).then_some(path)
+  })
+}
+
+/// The runtime directory of the app.
+pub fn runtime_dir(sandbox: Sandbox) -> Option<PathBuf> {
+  let runtime_dir = dirs::runtime_dir()?;
+  match sandbox {
+    // the app directory is the only part of `$XDG_RUNTIME_DIR` shared with the host and other instances
+    Sandbox::Flatpak => std::env::var_os("FLATPAK_ID").map(|id| runtime_dir.join("app").join(id)),
+    _ => Some(runtime_dir),
+  }
+}
+
+/// Resolves the host location of a file exposed by the documents portal, mounted on `/run/user/$UID/doc`.
+pub fn document_portal_host_path(path: &Path) -> Option<PathBuf> {
+  // the snap runtime directory is a subdirectory of the user one so the mount point is computed here
+  let portal_dir = PathBuf::from(format!("/run/user/{}/doc", unsafe { libc::getuid() }));
+  if !path.starts_with(portal_dir) {
+    return None;
+  }
+
+  let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+  let mut buf = vec![0_u8; libc::PATH_MAX as usize];
+  let len = unsafe {
+    libc::getxattr(
+      c_path.as_ptr(),
+      HOST_PATH_XATTR.as_ptr(),
+      buf.as_mut_ptr().cast(),
+      buf.len(),
+    )
+  };
+  if len <= 0 {
+    return None;
+  }
+  buf.truncate(len as usize);
+  if buf.last() == Some(&0) {
+    buf.pop();
+  }
+  Some(PathBuf::from(OsString::from_vec(buf)))
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::{Path, PathBuf};
+
+  #[test]
+  fn parse_user_dir() {
+    let home = Path::new("/home/tauri");
+    let contents = r#"
+# This file is written by xdg-user-dirs-update
+XDG_DESKTOP_DIR="$HOME/Desktop"
+XDG_DOCUMENTS_DIR="$HOME/Documents/Work"
+XDG_MUSIC_DIR="/mnt/music"
+XDG_PUBLICSHARE_DIR="$HOME/"
+"#;
+
+    assert_eq!(
+      super::parse_user_dir(contents, "XDG_DOCUMENTS_DIR", home),
+      Some(PathBuf::from("/home/tauri/Documents/Work"))
+    );
+    assert_eq!(
+      super::parse_user_dir(contents, "XDG_MUSIC_DIR", home),
+      Some(PathBuf::from("/mnt/music"))
+    );
+    assert_eq!(
+      super::parse_user_dir(contents, "XDG_PUBLICSHARE_DIR", home),
+      None
+    );
+    assert_eq!(
+      super::parse_user_dir(contents, "XDG_VIDEOS_DIR", home),
+      None
+    );
+  }
+}
@@ -26,6 +26,7 @@ use std::{
   collections::HashMap,
   fmt::{self, Debug},
   sync::Arc,
+  time::Duration,
 };
 
 /// Mobile APIs.
@@ -241,6 +242,7 @@ const RESERVED_PLUGIN_NAMES: &[&str] = &["core", "tauri"];
 pub struct Builder<R: Runtime, C: DeserializeOwned = ()> {
   name: &'static str,
   invoke_handler: Box<InvokeHandler<R>>,
+  invoke_timeout: Option<Duration>,
   setup: Option<Box<SetupHook<R, C>>>,
   js_init_script: Option<String>,
   on_navigation: Box<OnNavigation<R>>,
@@ -260,6 +262,7 @@ impl<R: Runtime, C: DeserializeOwned> Builder<R, C> {
       setup: None,
       js_init_script: None,
       invoke_handler: Box::new(|_| false),
+      invoke_timeout: None,
       on_navigation: Box::new(|_, _| true),
       on_page_load: Box::new(|_, _| ()),
       on_window_ready: Box::new(|_| ()),
@@ -302,6 +305,35 @@ impl<R: Runtime, C: DeserializeOwned> Builder<R, C> {
     self
   }
 
+  /// Sets the maximum duration of the plugin's async commands.
+  ///
+  /// When it elapses the command task is dropped and the promise is rejected with a timeout error.
+  /// A shorter timeout set by the command attribute or the `Tauri-Invoke-Timeout` request header takes precedence.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use std::time::Duration;
+  /// use tauri::{plugin::{Builder, TauriPlugin}, Runtime};
+  ///
+  /// #[tauri::command]
+  /// async fn fetch_data() -> Result<String, String> {
+  ///   Ok("data".into())
+  /// }
+  ///
+  /// fn init<R: Runtime>() -> TauriPlugin<R> {
+  ///   Builder::new("example")
+  ///     .invoke_handler(tauri::generate_handler![fetch_data])
+  ///     .invoke_timeout(Duration::from_secs(30))
+  ///     .build()
+  /// }
+  /// ```
+  #[must_use]
+  pub fn invoke_timeout(mut self, timeout: Duration) -> Self {
+    self.invoke_timeout.replace(timeout);
+    self
+  }
+
   /// Sets the provided JavaScript to be run after the global object has been created,
   /// but before the HTML document has been parsed and before any other script included by the HTML document is run.
   ///
@@ -645,6 +677,7 @@ impl<R: Runtime, C: DeserializeOwned> Builder<R, C> {
       name: self.name,
       app: None,
       invoke_handler: self.invoke_handler,
+      invoke_timeout: self.invoke_timeout,
       setup: self.setup,
       js_init_script: self.js_init_script,
       on_navigation: self.on_navigation,
@@ -672,6 +705,7 @@ pub struct TauriPlugin<R: Runtime, C: DeserializeOwned = ()> {
   name: &'static str,
   app: Option<AppHandle<R>>,
   invoke_handler: Box<InvokeHandler<R>>,
+  invoke_timeout: Option<Duration>,
   setup: Option<Box<SetupHook<R, C>>>,
   js_init_script: Option<String>,
   on_navigation: Box<OnNavigation<R>>,
@@ -752,7 +786,10 @@ impl<R: Runtime, C: DeserializeOwned> Plugin<R> for TauriPlugin<R, C> {
     (self.on_event)(app, event)
   }
 
-  fn extend_api(&mut self, invoke: Invoke<R>) -> bool {
+  fn extend_api(&mut self, mut invoke: Invoke<R>) -> bool {
+    if let Some(timeout) = self.invoke_timeout {
+      invoke.set_timeout(timeout);
+    }
     (self.invoke_handler)(invoke)
   }
 }
@@ -5,14 +5,22 @@
 use crate::{path::SafePathBuf, scope, webview::UriSchemeProtocolHandler};
 use http::{header::*, status::StatusCode, Request, Response};
 use http_range::HttpRange;
-use std::{borrow::Cow, io::SeekFrom};
+use std::{
+  borrow::Cow,
+  io::SeekFrom,
+  time::{SystemTime, UNIX_EPOCH},
+};
 use tauri_utils::mime_type::MimeType;
 use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
-pub fn get(scope: scope::fs::Scope, window_origin: String) -> UriSchemeProtocolHandler {
-  Box::new(
-    move |_, request, responder| match get_response(request, &scope, &window_origin) {
+pub fn get(
+  scope: scope::fs::Scope,
+  window_origin: String,
+  cache_control: Option<String>,
+) -> UriSchemeProtocolHandler {
+  Box::new(move |_, request, responder| {
+    match get_response(request, &scope, &window_origin, cache_control.as_deref()) {
       Ok(response) => responder.respond(response),
       Err(e) => responder.respond(
         http::Response::builder()
@@ -22,14 +30,15 @@ pub fn get(scope: scope::fs::Scope, window_origin: String) -> UriSchemeProtocolH
           .body(e.to_string().as_bytes().to_vec())
           .unwrap(),
       ),
-    },
-  )
+    }
+  })
 }
 
 fn get_response(
   request: Request<Vec<u8>>,
   scope: &scope::fs::Scope,
   window_origin: &str,
+  cache_control: Option<&str>,
 ) -> Result<Response<Cow<'static, [u8]>>, Box<dyn std::error::Error>> {
   // skip leading `/`
   let path = percent_encoding::percent_decode(request.uri().path()[1..].as_bytes())
@@ -43,14 +52,39 @@ fn get_response(
     return resp.status(403).body(Vec::new().into()).map_err(Into::into);
   }
 
-  if !scope.is_allowed(&path) {
-    log::error!("asset protocol not configured to allow the path: {}", path);
-    return resp.status(403).body(Vec::new().into()).map_err(Into::into);
+  // the scope resolves the path and opens it at once so it cannot be swapped for a symlink in between,
+  // and rejects paths outside of it before accessing the filesystem so their existence is not revealed
+  let file = match scope.open(&path) {
+    Ok(file) => file,
+    Err(crate::Error::PathNotAllowed(_)) => {
+      log::error!("asset protocol not configured to allow the path: {}", path);
+      return resp.status(403).body(Vec::new().into()).map_err(Into::into);
+    }
+    Err(e) => return Err(e.into()),
+  };
+
+  // validators derived from the file metadata so the webview can revalidate its cached copy
+  let metadata = file.metadata()?;
+  let modified = metadata.modified().ok();
+  let etag = entity_tag(metadata.len(), modified);
+  resp = resp.header(ETAG, &etag);
+  if let Some(modified) = modified {
+    resp = resp.header(LAST_MODIFIED, httpdate::fmt_http_date(modified));
+  }
+  if let Some(cache_control) = cache_control {
+    resp = resp.header(CACHE_CONTROL, cache_control);
   }
 
-  let (mut file, len, mime_type, read_bytes) = crate::async_runtime::safe_block_on(async move {
-    let mut file = File::open(&path).await?;
+  if is_not_modified(request.headers(), &etag, modified) {
+    return resp
+      .status(StatusCode::NOT_MODIFIED)
+      .body(Vec::new().into())
+      .map_err(Into::into);
+  }
 
+  let mut file = File::from_std(file);
+
+  let (mut file, len, mime_type, read_bytes) = crate::async_runtime::safe_block_on(async move {
     // get file length
     let len = {
       let old_pos = file.stream_position().await?;
@@ -223,6 +257,31 @@ fn get_response(
   response.map_err(Into::into)
 }
 
+fn entity_tag(len: u64, modified: Option<SystemTime>) -> String {
+  let modified = modified
+    .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+    .map(|d| d.as_nanos())
+    .unwrap_or_default();
+  format!("\"{len:x}-{modified:x}\"")
+}
+
+fn is_not_modified(headers: &HeaderMap, etag: &str, modified: Option<SystemTime>) -> bool {
+  // `If-Modified-Since` must be ignored when `If-None-Match` is present
+  if let Some(matches) = super::if_none_match(headers, etag) {
+    return matches;
+  }
+
+  let since = headers
+    .get(IF_MODIFIED_SINCE)
+    .and_then(|v| v.to_str().ok())
+    .and_then(|v| v.parse::<httpdate::HttpDate>().ok());
+  match (modified, since) {
+    // HTTP dates only have a precision of one second
+    (Some(modified), Some(since)) => httpdate::HttpDate::from(modified) <= since,
+    _ => false,
+  }
+}
+
 fn random_boundary() -> String {
   let mut x = [0_u8; 30];
   getrandom::getrandom(&mut x).expect("failed to get random bytes");
@@ -234,3 +293,35 @@ fn random_boundary() -> String {
       a
     })
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  #[test]
+  fn conditional_requests() {
+    let modified = UNIX_EPOCH + Duration::from_millis(1_700_000_000_500);
+    let etag = entity_tag(10, Some(modified));
+
+    let mut headers = HeaderMap::new();
+    assert!(!is_not_modified(&headers, &etag, Some(modified)));
+
+    headers.insert(
+      IF_MODIFIED_SINCE,
+      HeaderValue::from_str(&httpdate::fmt_http_date(modified)).unwrap(),
+    );
+    assert!(is_not_modified(&headers, &etag, Some(modified)));
+    assert!(!is_not_modified(
+      &headers,
+      &etag,
+      Some(modified + Duration::from_secs(1))
+    ));
+
+    // the entity tag takes precedence over the modification date
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"other\""));
+    assert!(!is_not_modified(&headers, &etag, Some(modified)));
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_str(&etag).unwrap());
+    assert!(is_not_modified(&headers, &etag, Some(modified)));
+  }
+}
@@ -7,3 +7,36 @@ pub mod asset;
 #[cfg(feature = "isolation")]
 pub mod isolation;
 pub mod tauri;
+
+/// Whether the `If-None-Match` header of the request matches the given entity tag.
+///
+/// Returns `None` when the request does not have the header.
+pub(crate) fn if_none_match(headers: &http::HeaderMap, etag: &str) -> Option<bool> {
+  let value = headers.get(http::header::IF_NONE_MATCH)?.to_str().ok()?;
+  // `If-None-Match` uses the weak comparison
+  let etag = etag.trim_start_matches("W/");
+  Some(
+    value
+      .split(',')
+      .map(str::trim)
+      .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use http::{header::IF_NONE_MATCH, HeaderMap, HeaderValue};
+
+  #[test]
+  fn if_none_match() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(super::if_none_match(&headers, "\"a\""), None);
+
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_static("\"b\", W/\"a\""));
+    assert_eq!(super::if_none_match(&headers, "\"a\""), Some(true));
+    assert_eq!(super::if_none_match(&headers, "\"c\""), Some(false));
+
+    headers.insert(IF_NONE_MATCH, HeaderValue::from_static("*"));
+    assert_eq!(super::if_none_match(&headers, "\"c\""), Some(true));
+  }
+}
@@ -157,7 +157,22 @@ fn get_response<R: Runtime>(
     if let Some(csp) = &asset.csp_header {
       builder = builder.header("Content-Security-Policy", csp);
     }
-    builder.body(asset.bytes.into())?
+    if let Some(etag) = &asset.etag {
+      builder = builder.header(http::header::ETAG, etag);
+    }
+    // the embedded asset hash only changes when the app is rebuilt
+    let not_modified = asset
+      .etag
+      .as_deref()
+      .and_then(|etag| super::if_none_match(request.headers(), etag))
+      .unwrap_or(false);
+    if not_modified {
+      builder
+        .status(StatusCode::NOT_MODIFIED)
+        .body(Vec::new().into())?
+    } else {
+      builder.body(asset.bytes.into())?
+    }
   };
   if let Some(handler) = &web_resource_request_handler {
     handler(request, &mut response);
@@ -4,8 +4,10 @@
 
 use std::{
   collections::{HashMap, HashSet},
+  ffi::OsStr,
   fmt,
-  path::{Path, PathBuf, MAIN_SEPARATOR},
+  fs::File,
+  path::{Component, Path, PathBuf, MAIN_SEPARATOR},
   sync::{
     atomic::{AtomicU32, Ordering},
     Arc, Mutex,
@@ -18,6 +20,20 @@ use crate::ScopeEventId;
 
 pub use glob::Pattern;
 
+#[cfg(all(desktop, feature = "protocol-asset"))]
+mod persist;
+
+/// A path allowed or forbidden at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Grant {
+  pub path: PathBuf,
+  #[serde(default)]
+  pub directory: bool,
+  #[serde(default)]
+  pub recursive: bool,
+}
+
 /// Scope change event.
 #[derive(Debug, Clone)]
 pub enum Event {
@@ -34,9 +50,15 @@ type EventListener = Box<dyn Fn(&Event) + Send>;
 pub struct Scope {
   allowed_patterns: Arc<Mutex<HashSet<Pattern>>>,
   forbidden_patterns: Arc<Mutex<HashSet<Pattern>>>,
+  /// The allowed patterns of the configuration, which are never revoked.
+  config_patterns: Arc<HashSet<Pattern>>,
+  /// The paths allowed at runtime, which can be revoked.
+  runtime_grants: Arc<Mutex<Vec<Grant>>>,
   event_listeners: Arc<Mutex<HashMap<ScopeEventId, EventListener>>>,
   match_options: glob::MatchOptions,
   next_event_id: Arc<AtomicU32>,
+  #[cfg(all(desktop, feature = "protocol-asset"))]
+  store: Option<Arc<persist::Store>>,
 }
 
 impl Scope {
@@ -92,8 +114,6 @@ fn push_pattern<P: AsRef<Path>, F: Fn(&str) -> Result<Pattern, glob::PatternErro
   //   - `C:\\SomeDir`
   #[cfg(windows)]
   {
-    use std::path::Component;
-
     if matches!(path.components().next(), Some(Component::Prefix(_))) {
       let simplified = dunce::simplified(&path);
       let simplified_str = simplified.to_string_lossy();
@@ -156,6 +176,18 @@ fn canonicalize_parent(mut path: PathBuf) -> Option<PathBuf> {
     }
   }
 }
+/// The allowed patterns added by a path allowed at runtime.
+fn grant_patterns(grant: &Grant) -> crate::Result<HashSet<Pattern>> {
+  let mut patterns = HashSet::new();
+  push_pattern(&mut patterns, &grant.path, escaped_pattern)?;
+  if grant.directory {
+    push_pattern(&mut patterns, &grant.path, |p| {
+      escaped_pattern_with(p, if grant.recursive { "**" } else { "*" })
+    })?;
+  }
+  Ok(patterns)
+}
+
 impl Scope {
   /// Creates a new scope from a [`FsScope`] configuration.
   pub fn new<R: crate::Runtime, M: crate::Manager<R>>(
@@ -191,10 +223,14 @@ impl Scope {
     };
 
     Ok(Self {
+      config_patterns: Arc::new(allowed_patterns.clone()),
+      runtime_grants: Default::default(),
       allowed_patterns: Arc::new(Mutex::new(allowed_patterns)),
       forbidden_patterns: Arc::new(Mutex::new(forbidden_patterns)),
       event_listeners: Default::default(),
       next_event_id: Default::default(),
+      #[cfg(all(desktop, feature = "protocol-asset"))]
+      store: None,
       match_options: glob::MatchOptions {
         // this is needed so `/dir/*` doesn't match files within subdirectories such as `/dir/subdir/file.txt`
         // see: <https://github.com/tauri-apps/tauri/security/advisories/GHSA-6mv3-wm7j-h4w5>
@@ -205,6 +241,52 @@ impl Scope {
     })
   }
 
+  /// Saves the paths allowed or forbidden at runtime to the given file, restoring the ones it already contains.
+  ///
+  /// The file is signed with a key saved in the credential store of the OS
+  /// and ignored if it is corrupted or was edited without access to the key.
+  /// Forbidden paths always take precedence over the restored grants.
+  ///
+  /// This is enabled for the asset protocol scope with [`AssetProtocolConfig::persist_scope`](tauri_utils::config::AssetProtocolConfig::persist_scope).
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux**: Requires a Secret Service provider such as GNOME Keyring or KWallet.
+  #[cfg(all(desktop, feature = "protocol-asset"))]
+  #[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "protocol-asset"))))]
+  pub fn persisted<P: Into<PathBuf>>(self, path: P) -> crate::Result<Self> {
+    self.with_store(persist::Store::open(path.into())?)
+  }
+
+  #[cfg(all(desktop, feature = "protocol-asset"))]
+  fn with_store(mut self, store: persist::Store) -> crate::Result<Self> {
+    let grants = store.grants();
+    for grant in grants.allowed {
+      if grant.directory {
+        self.allow_directory(grant.path, grant.recursive)?;
+      } else {
+        self.allow_file(grant.path)?;
+      }
+    }
+    for grant in grants.forbidden {
+      if grant.directory {
+        self.forbid_directory(grant.path, grant.recursive)?;
+      } else {
+        self.forbid_file(grant.path)?;
+      }
+    }
+    self.store.replace(Arc::new(store));
+    Ok(self)
+  }
+
+  #[cfg(all(desktop, feature = "protocol-asset"))]
+  fn persist<F: FnOnce(&persist::Store) -> crate::Result<()>>(&self, f: F) -> crate::Result<()> {
+    match &self.store {
+      Some(store) => f(store),
+      None => Ok(()),
+    }
+  }
+
   /// The list of allowed patterns.
   pub fn allowed_patterns(&self) -> HashSet<Pattern> {
     self.allowed_patterns.lock().unwrap().clone()
@@ -271,7 +353,11 @@ impl Scope {
       })?;
     }
     self.emit(Event::PathAllowed(path.to_path_buf()));
-    Ok(())
+    self.grant(Grant {
+      path: path.to_path_buf(),
+      directory: true,
+      recursive,
+    })
   }
 
   /// Extend the allowed patterns with the given file path.
@@ -285,6 +371,23 @@ impl Scope {
       escaped_pattern,
     )?;
     self.emit(Event::PathAllowed(path.to_path_buf()));
+    self.grant(Grant {
+      path: path.to_path_buf(),
+      directory: false,
+      recursive: false,
+    })
+  }
+
+  /// Records a path allowed at runtime so it can be revoked.
+  fn grant(&self, grant: Grant) -> crate::Result<()> {
+    {
+      let mut runtime_grants = self.runtime_grants.lock().unwrap();
+      if !runtime_grants.contains(&grant) {
+        runtime_grants.push(grant.clone());
+      }
+    }
+    #[cfg(all(desktop, feature = "protocol-asset"))]
+    self.persist(|store| store.allow(grant))?;
     Ok(())
   }
 
@@ -304,6 +407,14 @@ impl Scope {
       })?;
     }
     self.emit(Event::PathForbidden(path.to_path_buf()));
+    #[cfg(all(desktop, feature = "protocol-asset"))]
+    self.persist(|store| {
+      store.forbid(Grant {
+        path: path.to_path_buf(),
+        directory: true,
+        recursive,
+      })
+    })?;
     Ok(())
   }
 
@@ -318,6 +429,63 @@ impl Scope {
       escaped_pattern,
     )?;
     self.emit(Event::PathForbidden(path.to_path_buf()));
+    #[cfg(all(desktop, feature = "protocol-asset"))]
+    self.persist(|store| {
+      store.forbid(Grant {
+        path: path.to_path_buf(),
+        directory: false,
+        recursive: false,
+      })
+    })?;
+    Ok(())
+  }
+
+  /// Removes a directory allowed with [`Self::allow_directory`], including from the persisted grants.
+  ///
+  /// `recursive` must match the value used to allow the directory.
+  /// The paths allowed by the configuration and the forbidden paths are not affected.
+  pub fn revoke_directory<P: AsRef<Path>>(&self, path: P, recursive: bool) -> crate::Result<()> {
+    self.revoke(Grant {
+      path: path.as_ref().to_path_buf(),
+      directory: true,
+      recursive,
+    })
+  }
+
+  /// Removes a file allowed with [`Self::allow_file`], including from the persisted grants.
+  ///
+  /// The paths allowed by the configuration and the forbidden paths are not affected.
+  pub fn revoke_file<P: AsRef<Path>>(&self, path: P) -> crate::Result<()> {
+    self.revoke(Grant {
+      path: path.as_ref().to_path_buf(),
+      directory: false,
+      recursive: false,
+    })
+  }
+
+  fn revoke(&self, grant: Grant) -> crate::Result<()> {
+    {
+      let mut runtime_grants = self.runtime_grants.lock().unwrap();
+      let len = runtime_grants.len();
+      runtime_grants.retain(|g| g != &grant);
+      if runtime_grants.len() != len {
+        // keep the patterns that the configuration or another runtime grant also needs
+        let mut patterns = grant_patterns(&grant)?;
+        patterns.retain(|p| !self.config_patterns.contains(p));
+        for other in runtime_grants.iter() {
+          for pattern in grant_patterns(other)? {
+            patterns.remove(&pattern);
+          }
+        }
+        self
+          .allowed_patterns
+          .lock()
+          .unwrap()
+          .retain(|p| !patterns.contains(p));
+      }
+    }
+    #[cfg(all(desktop, feature = "protocol-asset"))]
+    self.persist(|store| store.revoke(&grant))?;
     Ok(())
   }
 
@@ -339,28 +507,527 @@ impl Scope {
     };
 
     if let Ok(path) = path {
-      let path: PathBuf = path.components().collect();
-      let forbidden = self
-        .forbidden_patterns
+      self.matches(&path)
+    } else {
+      false
+    }
+  }
+
+  /// Checks a path that has no symlinks left against the scope patterns.
+  fn matches(&self, path: &Path) -> bool {
+    let path: PathBuf = path.components().collect();
+    let forbidden = self
+      .forbidden_patterns
+      .lock()
+      .unwrap()
+      .iter()
+      .any(|p| p.matches_path_with(&path, self.match_options));
+
+    if forbidden {
+      false
+    } else {
+      let allowed = self
+        .allowed_patterns
         .lock()
         .unwrap()
         .iter()
         .any(|p| p.matches_path_with(&path, self.match_options));
+      allowed
+    }
+  }
 
-      if forbidden {
-        false
-      } else {
-        let allowed = self
-          .allowed_patterns
-          .lock()
-          .unwrap()
-          .iter()
-          .any(|p| p.matches_path_with(&path, self.match_options));
-        allowed
+  fn ensure_allowed(&self, path: &Path) -> crate::Result<()> {
+    if self.matches(path) {
+      Ok(())
+    } else {
+      Err(crate::Error::PathNotAllowed(path.to_path_buf()))
+    }
+  }
+
+  /// Opens the file at the given path in read-only mode if it is allowed on this scope.
+  ///
+  /// Unlike checking the path with [`Self::is_allowed`] and opening it afterwards,
+  /// the path is resolved and checked in the same operation that opens it,
+  /// so a symlink swapped in while the file is being opened cannot be used to escape the scope.
+  /// Symlinks are followed, but both the requested path and the path they resolve to must be allowed.
+  /// The requested path is checked before the filesystem is accessed,
+  /// so the errors returned for a path outside of the scope do not tell whether it exists.
+  ///
+  /// On Unix each path component is opened relative to a handle to its parent directory without following symlinks,
+  /// and the path a symlink leads to is checked before it is walked.
+  /// On Windows the canonical path is checked and verified again once the file is opened.
+  pub fn open<P: AsRef<Path>>(&self, path: P) -> crate::Result<File> {
+    let path = absolute(path.as_ref())?;
+    self.ensure_allowed(&normalize(&path))?;
+
+    #[cfg(unix)]
+    {
+      let resolved = unix::resolve(&path, true, &|p| self.matches(p))?;
+      let real_path = resolved.real_path();
+      self.ensure_allowed(&real_path)?;
+      match &resolved.name {
+        Some(name) => unix::open_file(&resolved.dir, name).map_err(Into::into),
+        None => unix::reopen_dir(&resolved.dir).map_err(Into::into),
+      }
+    }
+
+    #[cfg(not(unix))]
+    {
+      let real_path = std::fs::canonicalize(&path)?;
+      self.ensure_allowed(&real_path)?;
+      let file = File::open(&real_path)?;
+      verify_unchanged(&path, &real_path)?;
+      Ok(file)
+    }
+  }
+
+  /// Creates the file at the given path, or truncates it if it exists, if it is allowed on this scope.
+  ///
+  /// The parent directory is resolved the same way as [`Self::open`],
+  /// and the file itself is never a symlink: creating a path that points to a symlink fails.
+  pub fn create<P: AsRef<Path>>(&self, path: P) -> crate::Result<File> {
+    let path = absolute(path.as_ref())?;
+    self.ensure_allowed(&normalize(&path))?;
+
+    #[cfg(unix)]
+    {
+      let resolved = unix::resolve(&path, false, &|p| self.matches(p))?;
+      let name = resolved.name.as_ref().ok_or(crate::Error::NoBasename)?;
+      self.ensure_allowed(&resolved.real_path())?;
+      unix::create_file(&resolved.dir, name).map_err(Into::into)
+    }
+
+    #[cfg(not(unix))]
+    {
+      let name = path.file_name().ok_or(crate::Error::NoBasename)?;
+      let parent = path.parent().ok_or(crate::Error::NoParent)?;
+      let real_parent = std::fs::canonicalize(parent)?;
+      let real_path = real_parent.join(name);
+      self.ensure_allowed(&real_path)?;
+      if std::fs::symlink_metadata(&real_path).is_ok_and(|m| m.file_type().is_symlink()) {
+        return Err(crate::Error::PathNotAllowed(real_path));
+      }
+      let file = File::create(&real_path)?;
+      verify_unchanged(parent, &real_parent)?;
+      Ok(file)
+    }
+  }
+
+  /// Reads the entries of the directory at the given path if it is allowed on this scope.
+  ///
+  /// The directory is resolved the same way as [`Self::open`] and its entries are read from the opened handle.
+  /// Entries are not checked against the scope, use [`Self::open`] to access them.
+  pub fn read_dir<P: AsRef<Path>>(&self, path: P) -> crate::Result<Vec<DirEntry>> {
+    let path = absolute(path.as_ref())?;
+    self.ensure_allowed(&normalize(&path))?;
+
+    #[cfg(unix)]
+    {
+      let resolved = unix::resolve(&path, true, &|p| self.matches(p))?;
+      if resolved.name.is_some() {
+        return Err(std::io::Error::from_raw_os_error(libc::ENOTDIR).into());
       }
+      self.ensure_allowed(&resolved.dir_path)?;
+      let dir = unix::reopen_dir(&resolved.dir)?;
+      unix::read_dir(&dir, &resolved.dir_path).map_err(Into::into)
+    }
+
+    #[cfg(not(unix))]
+    {
+      let real_path = std::fs::canonicalize(&path)?;
+      self.ensure_allowed(&real_path)?;
+      let entries = std::fs::read_dir(&real_path)?;
+      verify_unchanged(&path, &real_path)?;
+      entries
+        .map(|entry| {
+          let entry = entry?;
+          let file_type = entry.file_type()?;
+          Ok(DirEntry {
+            path: entry.path(),
+            kind: if file_type.is_symlink() {
+              EntryKind::Symlink
+            } else if file_type.is_dir() {
+              EntryKind::Dir
+            } else if file_type.is_file() {
+              EntryKind::File
+            } else {
+              EntryKind::Other
+            },
+          })
+        })
+        .collect()
+    }
+  }
+}
+
+/// An entry of a directory read with [`Scope::read_dir`].
+#[derive(Debug, Clone)]
+pub struct DirEntry {
+  path: PathBuf,
+  kind: EntryKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+  File,
+  Dir,
+  Symlink,
+  Other,
+}
+
+impl DirEntry {
+  /// The path of the entry, joined to the resolved path of the directory.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// The file name of the entry.
+  pub fn file_name(&self) -> &OsStr {
+    // entries always have a name
+    self.path.file_name().unwrap_or_default()
+  }
+
+  /// Whether the entry is a regular file.
+  pub fn is_file(&self) -> bool {
+    self.kind == EntryKind::File
+  }
+
+  /// Whether the entry is a directory.
+  pub fn is_dir(&self) -> bool {
+    self.kind == EntryKind::Dir
+  }
+
+  /// Whether the entry is a symlink. Symlinks are not followed when reading a directory.
+  pub fn is_symlink(&self) -> bool {
+    self.kind == EntryKind::Symlink
+  }
+}
+
+fn absolute(path: &Path) -> crate::Result<PathBuf> {
+  if path.is_absolute() {
+    Ok(path.to_path_buf())
+  } else {
+    std::env::current_dir()
+      .map(|cwd| cwd.join(path))
+      .map_err(crate::Error::CurrentDir)
+  }
+}
+
+/// Resolves the `.` and `..` components of an absolute path without accessing the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+  let mut normalized = PathBuf::new();
+  for component in path.components() {
+    match component {
+      Component::ParentDir => {
+        normalized.pop();
+      }
+      Component::CurDir => {}
+      component => normalized.push(component),
+    }
+  }
+  normalized
+}
+
+/// Ensures the path still resolves to the checked path after it was opened.
+#[cfg(not(unix))]
+fn verify_unchanged(path: &Path, real_path: &Path) -> crate::Result<()> {
+  if std::fs::canonicalize(path)? == real_path {
+    Ok(())
+  } else {
+    Err(crate::Error::PathNotAllowed(path.to_path_buf()))
+  }
+}
+
+#[cfg(unix)]
+mod unix {
+  use std::{
+    collections::VecDeque,
+    ffi::{CStr, CString, OsStr, OsString},
+    fs::File,
+    io,
+    os::unix::{
+      ffi::{OsStrExt, OsStringExt},
+      io::{AsRawFd, FromRawFd},
+    },
+    path::{Component, Path, PathBuf},
+  };
+
+  use super::{normalize, DirEntry, EntryKind};
+
+  /// Same limit as Linux uses when resolving paths.
+  const MAX_SYMLINKS: usize = 40;
+
+  /// A path resolved from the filesystem root without following symlinks implicitly.
+  pub(super) struct Resolved {
+    /// Handle to the last directory of the path.
+    pub dir: File,
+    /// Path of [`Self::dir`], which contains no symlink.
+    pub dir_path: PathBuf,
+    /// The last component of the path if it is not a directory, or if it should not be followed.
+    pub name: Option<OsString>,
+  }
+
+  impl Resolved {
+    pub fn real_path(&self) -> PathBuf {
+      match &self.name {
+        Some(name) => self.dir_path.join(name),
+        None => self.dir_path.clone(),
+      }
+    }
+  }
+
+  fn c_path(name: &OsStr) -> io::Result<CString> {
+    CString::new(name.as_bytes()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+  }
+
+  fn openat(dir: &File, name: &OsStr, flags: libc::c_int) -> io::Result<File> {
+    let name = c_path(name)?;
+    let fd = unsafe {
+      libc::openat(
+        dir.as_raw_fd(),
+        name.as_ptr(),
+        flags | libc::O_CLOEXEC | libc::O_NOFOLLOW,
+        0o666 as libc::c_uint,
+      )
+    };
+    if fd < 0 {
+      Err(io::Error::last_os_error())
     } else {
-      false
+      Ok(unsafe { File::from_raw_fd(fd) })
+    }
+  }
+
+  /// Opens a directory to walk through it.
+  ///
+  /// On Linux the handle is opened with `O_PATH`, so directories that can only be searched are walked too.
+  #[cfg(any(target_os = "linux", target_os = "android"))]
+  fn open_dir(dir: &File, name: &OsStr) -> io::Result<File> {
+    openat(dir, name, libc::O_PATH | libc::O_DIRECTORY)
+  }
+
+  /// Opens a directory to walk through it.
+  #[cfg(not(any(target_os = "linux", target_os = "android")))]
+  fn open_dir(dir: &File, name: &OsStr) -> io::Result<File> {
+    openat(dir, name, libc::O_RDONLY | libc::O_DIRECTORY)
+  }
+
+  /// Opens a directory handle returned by [`resolve`] for reading.
+  pub(super) fn reopen_dir(dir: &File) -> io::Result<File> {
+    openat(dir, OsStr::new("."), libc::O_RDONLY | libc::O_DIRECTORY)
+  }
+
+  fn open_root() -> io::Result<File> {
+    File::open("/")
+  }
+
+  fn readlinkat(dir: &File, name: &OsStr) -> io::Result<PathBuf> {
+    let name = c_path(name)?;
+    let mut buf = Vec::<u8>::with_capacity(libc::PATH_MAX as usize);
+    loop {
+      let len = unsafe {
+        libc::readlinkat(
+          dir.as_raw_fd(),
+          name.as_ptr(),
+          buf.as_mut_ptr().cast(),
+          buf.capacity(),
+        )
+      };
+      if len < 0 {
+        return Err(io::Error::last_os_error());
+      }
+      let len = len as usize;
+      if len < buf.capacity() {
+        unsafe { buf.set_len(len) };
+        return Ok(PathBuf::from(OsString::from_vec(buf)));
+      }
+      // the target may have been truncated
+      buf.reserve(buf.capacity() * 2);
+    }
+  }
+
+  /// Walks the absolute `path` one component at a time, holding a handle to the current directory.
+  ///
+  /// Symlinks are read and their target is spliced into the remaining components,
+  /// so the returned directory path is the real location of the handle.
+  /// When `follow_last` is false, the last component is returned as is even if it is a directory or a symlink.
+  ///
+  /// The path a symlink leads to must be `allowed` before it is walked.
+  pub(super) fn resolve(
+    path: &Path,
+    follow_last: bool,
+    allowed: &dyn Fn(&Path) -> bool,
+  ) -> crate::Result<Resolved> {
+    let mut pending = path
+      .components()
+      .filter_map(|c| match c {
+        Component::Normal(name) => Some(name.to_os_string()),
+        Component::ParentDir => Some(OsString::from("..")),
+        Component::RootDir | Component::CurDir | Component::Prefix(_) => None,
+      })
+      .collect::<VecDeque<_>>();
+
+    let mut dir = open_root()?;
+    let mut dir_path = PathBuf::from("/");
+    let mut links = 0;
+
+    while let Some(name) = pending.pop_front() {
+      if name == ".." {
+        dir = open_dir(&dir, &name)?;
+        dir_path.pop();
+        continue;
+      }
+
+      let is_last = pending.is_empty();
+      if is_last && !follow_last {
+        return Ok(Resolved {
+          dir,
+          dir_path,
+          name: Some(name),
+        });
+      }
+
+      match open_dir(&dir, &name) {
+        Ok(next) => {
+          dir = next;
+          dir_path.push(&name);
+        }
+        Err(e) if e.raw_os_error() == Some(libc::ENOENT) && is_last => {
+          return Ok(Resolved {
+            dir,
+            dir_path,
+            name: Some(name),
+          });
+        }
+        // not a directory: either a symlink or a file
+        Err(e) => match readlinkat(&dir, &name) {
+          Ok(target) => {
+            links += 1;
+            if links > MAX_SYMLINKS {
+              return Err(io::Error::from_raw_os_error(libc::ELOOP).into());
+            }
+            let target_path = normalize(
+              &dir_path
+                .join(&target)
+                .join(pending.iter().collect::<PathBuf>()),
+            );
+            if !allowed(&target_path) {
+              return Err(crate::Error::PathNotAllowed(target_path));
+            }
+            if target.is_absolute() {
+              dir = open_root()?;
+              dir_path = PathBuf::from("/");
+            }
+            for component in target.components().rev() {
+              match component {
+                Component::Normal(name) => pending.push_front(name.to_os_string()),
+                Component::ParentDir => pending.push_front(OsString::from("..")),
+                Component::RootDir | Component::CurDir | Component::Prefix(_) => {}
+              }
+            }
+          }
+          Err(link_error) if link_error.raw_os_error() == Some(libc::EINVAL) => {
+            if is_last && e.raw_os_error() == Some(libc::ENOTDIR) {
+              return Ok(Resolved {
+                dir,
+                dir_path,
+                name: Some(name),
+              });
+            }
+            return Err(e.into());
+          }
+          Err(_) => return Err(e.into()),
+        },
+      }
     }
+
+    Ok(Resolved {
+      dir,
+      dir_path,
+      name: None,
+    })
+  }
+
+  pub(super) fn open_file(dir: &File, name: &OsStr) -> io::Result<File> {
+    openat(dir, name, libc::O_RDONLY)
+  }
+
+  pub(super) fn create_file(dir: &File, name: &OsStr) -> io::Result<File> {
+    if name == ".." {
+      return Err(io::Error::from_raw_os_error(libc::EISDIR));
+    }
+    openat(dir, name, libc::O_WRONLY | libc::O_CREAT | libc::O_TRUNC)
+  }
+
+  pub(super) fn read_dir(dir: &File, dir_path: &Path) -> io::Result<Vec<DirEntry>> {
+    // fdopendir takes ownership of the descriptor
+    let fd = unsafe { libc::dup(dir.as_raw_fd()) };
+    if fd < 0 {
+      return Err(io::Error::last_os_error());
+    }
+    let stream = unsafe { libc::fdopendir(fd) };
+    if stream.is_null() {
+      let error = io::Error::last_os_error();
+      unsafe { libc::close(fd) };
+      return Err(error);
+    }
+
+    let mut entries = Vec::new();
+    let result = loop {
+      let entry = unsafe { libc::readdir(stream) };
+      if entry.is_null() {
+        break Ok(());
+      }
+      let (name, d_type) = unsafe {
+        (
+          CStr::from_ptr((*entry).d_name.as_ptr()).to_bytes().to_vec(),
+          (*entry).d_type,
+        )
+      };
+      if name == b"." || name == b".." {
+        continue;
+      }
+      let name = OsString::from_vec(name);
+      let kind = match d_type {
+        libc::DT_REG => EntryKind::File,
+        libc::DT_DIR => EntryKind::Dir,
+        libc::DT_LNK => EntryKind::Symlink,
+        libc::DT_UNKNOWN => match stat_kind(dir, &name) {
+          Ok(kind) => kind,
+          Err(e) => break Err(e),
+        },
+        _ => EntryKind::Other,
+      };
+      entries.push(DirEntry {
+        path: dir_path.join(&name),
+        kind,
+      });
+    };
+    unsafe { libc::closedir(stream) };
+
+    result.map(|_| entries)
+  }
+
+  fn stat_kind(dir: &File, name: &OsStr) -> io::Result<EntryKind> {
+    let name = c_path(name)?;
+    let mut stat = std::mem::MaybeUninit::<libc::stat>::uninit();
+    if unsafe {
+      libc::fstatat(
+        dir.as_raw_fd(),
+        name.as_ptr(),
+        stat.as_mut_ptr(),
+        libc::AT_SYMLINK_NOFOLLOW,
+      )
+    } < 0
+    {
+      return Err(io::Error::last_os_error());
+    }
+    let mode = unsafe { stat.assume_init() }.st_mode & libc::S_IFMT;
+    Ok(match mode {
+      libc::S_IFREG => EntryKind::File,
+      libc::S_IFDIR => EntryKind::Dir,
+      libc::S_IFLNK => EntryKind::Symlink,
+      _ => EntryKind::Other,
+    })
   }
 }
 
@@ -388,8 +1055,12 @@ mod tests {
     Scope {
       allowed_patterns: Default::default(),
       forbidden_patterns: Default::default(),
+      config_patterns: Default::default(),
+      runtime_grants: Default::default(),
       event_listeners: Default::default(),
       next_event_id: Default::default(),
+      #[cfg(all(desktop, feature = "protocol-asset"))]
+      store: None,
       match_options: glob::MatchOptions {
         // this is needed so `/dir/*` doesn't match files within subdirectories such as `/dir/subdir/file.txt`
         // see: <https://github.com/tauri-apps/tauri/security/advisories/GHSA-6mv3-wm7j-h4w5>
@@ -501,6 +1172,68 @@ mod tests {
     }
   }
 
+  #[cfg(all(unix, desktop, feature = "protocol-asset"))]
+  #[test]
+  fn persisted_grants() {
+    let dir = std::env::temp_dir().join(format!("tauri-scope-persist-{}", std::process::id()));
+    let store = dir.join("scope.json");
+    let open_store =
+      |path: &std::path::Path| super::persist::Store::with_key(path.into(), [7; 32]).unwrap();
+
+    let scope = new_scope().with_store(open_store(&store)).unwrap();
+    scope.allow_directory("/home/tauri", true).unwrap();
+    scope.allow_file("/home/other/file").unwrap();
+    scope.forbid_file("/home/tauri/secret").unwrap();
+
+    let scope = new_scope().with_store(open_store(&store)).unwrap();
+    assert!(scope.is_allowed("/home/tauri/inner/file"));
+    assert!(scope.is_allowed("/home/other/file"));
+    assert!(!scope.is_allowed("/home/tauri/secret"));
+
+    scope.revoke_directory("/home/tauri", true).unwrap();
+    assert!(!scope.is_allowed("/home/tauri/inner/file"));
+
+    let scope = new_scope().with_store(open_store(&store)).unwrap();
+    assert!(!scope.is_allowed("/home/tauri/inner/file"));
+    assert!(scope.is_allowed("/home/other/file"));
+
+    std::fs::remove_dir_all(dir).unwrap();
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn revoke_keeps_other_grants() {
+    let mut scope = new_scope();
+    let mut config_patterns = std::collections::HashSet::new();
+    super::push_pattern(
+      &mut config_patterns,
+      "/home/tauri/config",
+      super::escaped_pattern,
+    )
+    .unwrap();
+    scope.config_patterns = std::sync::Arc::new(config_patterns.clone());
+    *scope.allowed_patterns.lock().unwrap() = config_patterns;
+
+    // a runtime grant of a path the configuration already allows
+    scope.allow_file("/home/tauri/config").unwrap();
+    scope.revoke_file("/home/tauri/config").unwrap();
+    assert!(scope.is_allowed("/home/tauri/config"));
+
+    // grants sharing a pattern
+    scope.allow_directory("/home/tauri/docs", false).unwrap();
+    scope.allow_file("/home/tauri/docs").unwrap();
+    scope.revoke_file("/home/tauri/docs").unwrap();
+    assert!(scope.is_allowed("/home/tauri/docs"));
+    assert!(scope.is_allowed("/home/tauri/docs/file"));
+    scope.revoke_directory("/home/tauri/docs", false).unwrap();
+    assert!(!scope.is_allowed("/home/tauri/docs"));
+    assert!(!scope.is_allowed("/home/tauri/docs/file"));
+
+    // paths that were not allowed at runtime are left alone
+    scope.revoke_directory("/home/tauri/config", true).unwrap();
+    assert!(scope.is_allowed("/home/tauri/config"));
+  }
+
   #[cfg(windows)]
   #[test]
   fn windows_root_paths() {
@@ -600,4 +1333,126 @@ mod tests {
       assert!(!scope.is_allowed("Q:Cargo.toml"));
     }
   }
+
+  #[cfg(unix)]
+  #[test]
+  fn symlinks_cannot_escape_scope() {
+    use std::{io::Read, os::unix::fs::symlink};
+
+    let root = std::env::temp_dir().join(format!("tauri-fs-scope-{}", std::process::id()));
+    let allowed = root.join("allowed");
+    let outside = root.join("outside");
+    std::fs::create_dir_all(allowed.join("sub")).unwrap();
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(allowed.join("file.txt"), "allowed").unwrap();
+    std::fs::write(outside.join("secret.txt"), "secret").unwrap();
+    symlink(outside.join("secret.txt"), allowed.join("secret-link")).unwrap();
+    symlink(&outside, allowed.join("outside-link")).unwrap();
+    symlink("../file.txt", allowed.join("sub").join("file-link")).unwrap();
+
+    let scope = new_scope();
+    scope.allow_directory(&allowed, true).unwrap();
+
+    let mut contents = String::new();
+    scope
+      .open(allowed.join("sub").join("file-link"))
+      .unwrap()
+      .read_to_string(&mut contents)
+      .unwrap();
+    assert_eq!(contents, "allowed");
+
+    assert!(matches!(
+      scope.open(allowed.join("secret-link")),
+      Err(crate::Error::PathNotAllowed(_))
+    ));
+    assert!(matches!(
+      scope.open(allowed.join("outside-link").join("secret.txt")),
+      Err(crate::Error::PathNotAllowed(_))
+    ));
+    assert!(matches!(
+      scope.open(
+        allowed
+          .join("sub")
+          .join("..")
+          .join("..")
+          .join("outside")
+          .join("secret.txt")
+      ),
+      Err(crate::Error::PathNotAllowed(_))
+    ));
+
+    // creating a file through a symlink fails instead of writing to its target
+    assert!(scope.create(allowed.join("secret-link")).is_err());
+    assert_eq!(
+      std::fs::read_to_string(outside.join("secret.txt")).unwrap(),
+      "secret"
+    );
+    assert!(matches!(
+      scope.create(allowed.join("outside-link").join("new.txt")),
+      Err(crate::Error::PathNotAllowed(_))
+    ));
+    scope.create(allowed.join("sub").join("new.txt")).unwrap();
+
+    let mut entries = scope
+      .read_dir(&allowed)
+      .unwrap()
+      .into_iter()
+      .map(|e| {
+        (
+          e.file_name().to_string_lossy().into_owned(),
+          e.is_dir(),
+          e.is_symlink(),
+        )
+      })
+      .collect::<Vec<_>>();
+    entries.sort();
+    assert_eq!(
+      entries,
+      vec![
+        ("file.txt".into(), false, false),
+        ("outside-link".into(), false, true),
+        ("secret-link".into(), false, true),
+        ("sub".into(), true, false),
+      ]
+    );
+    assert!(matches!(
+      scope.read_dir(allowed.join("outside-link")),
+      Err(crate::Error::PathNotAllowed(_))
+    ));
+
+    // paths outside of the scope fail the same way whether they exist or not
+    for path in [
+      outside.join("secret.txt"),
+      outside.join("missing.txt"),
+      allowed.join("outside-link").join("missing.txt"),
+    ] {
+      assert!(matches!(
+        scope.open(&path),
+        Err(crate::Error::PathNotAllowed(_))
+      ));
+    }
+
+    // directories that can only be searched are walked through
+    #[cfg(target_os = "linux")]
+    {
+      use std::os::unix::fs::PermissionsExt;
+
+      let search_only = allowed.join("search-only");
+      std::fs::create_dir(&search_only).unwrap();
+      std::fs::write(search_only.join("file.txt"), "searched").unwrap();
+      std::fs::set_permissions(&search_only, std::fs::Permissions::from_mode(0o311)).unwrap();
+
+      let mut contents = String::new();
+      scope
+        .open(search_only.join("file.txt"))
+        .unwrap()
+        .read_to_string(&mut contents)
+        .unwrap();
+      assert_eq!(contents, "searched");
+
+      std::fs::set_permissions(&search_only, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    std::fs::remove_dir_all(&root).unwrap();
+  }
 }
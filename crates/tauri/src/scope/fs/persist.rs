@@ -0,0 +1,242 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Storage of the paths allowed or forbidden on a [`super::Scope`] at runtime.
+//!
+//! The grants are saved as a JSON string signed with HMAC-SHA256.
+//! The key is generated on first use and saved in the credential store of the OS
+//! (Keychain on macOS, Credential Manager on Windows, Secret Service on Linux),
+//! so editing the store without access to the key invalidates it.
+
+use std::{fs, path::PathBuf, sync::Mutex};
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::Grant;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const KEY_LEN: usize = 32;
+const KEY_SERVICE: &str = "tauri-fs-scope";
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct Grants {
+  #[serde(default)]
+  pub allowed: Vec<Grant>,
+  #[serde(default)]
+  pub forbidden: Vec<Grant>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoreFile {
+  grants: String,
+  signature: String,
+}
+
+pub(crate) struct Store {
+  path: PathBuf,
+  key: [u8; KEY_LEN],
+  grants: Mutex<Grants>,
+}
+
+impl Store {
+  /// Opens the store at the given path, creating its key in the credential store of the OS if needed.
+  pub fn open(path: PathBuf) -> crate::Result<Self> {
+    let key = read_or_create_key(&path)?;
+    Self::with_key(path, key)
+  }
+
+  /// Opens the store at the given path, signed with the given key.
+  ///
+  /// A store that cannot be read or whose signature does not match is discarded.
+  pub fn with_key(path: PathBuf, key: [u8; KEY_LEN]) -> crate::Result<Self> {
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    let grants = match fs::read(&path) {
+      Ok(contents) => match load(&contents, &key) {
+        Some(grants) => grants,
+        None => {
+          log::warn!(
+            "ignoring the persisted scope at {} because it is invalid",
+            path.display()
+          );
+          Grants::default()
+        }
+      },
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => Grants::default(),
+      Err(e) => return Err(e.into()),
+    };
+
+    Ok(Self {
+      path,
+      key,
+      grants: Mutex::new(grants),
+    })
+  }
+
+  pub fn grants(&self) -> Grants {
+    self.grants.lock().unwrap().clone()
+  }
+
+  pub fn allow(&self, grant: Grant) -> crate::Result<()> {
+    self.update(|grants| {
+      if grants.allowed.contains(&grant) {
+        false
+      } else {
+        grants.allowed.push(grant);
+        true
+      }
+    })
+  }
+
+  pub fn forbid(&self, grant: Grant) -> crate::Result<()> {
+    self.update(|grants| {
+      if grants.forbidden.contains(&grant) {
+        false
+      } else {
+        grants.forbidden.push(grant);
+        true
+      }
+    })
+  }
+
+  pub fn revoke(&self, grant: &Grant) -> crate::Result<()> {
+    self.update(|grants| {
+      let len = grants.allowed.len();
+      grants.allowed.retain(|g| g != grant);
+      grants.allowed.len() != len
+    })
+  }
+
+  fn update<F: FnOnce(&mut Grants) -> bool>(&self, f: F) -> crate::Result<()> {
+    let mut grants = self.grants.lock().unwrap();
+    if f(&mut grants) {
+      self.save(&grants)?;
+    }
+    Ok(())
+  }
+
+  fn save(&self, grants: &Grants) -> crate::Result<()> {
+    let grants = serde_json::to_string(grants)?;
+    let file = StoreFile {
+      signature: sign(&self.key, grants.as_bytes()),
+      grants,
+    };
+
+    // write to a temporary file first so a crash cannot leave a truncated store
+    let tmp = self.path.with_extension("tmp");
+    fs::write(&tmp, serde_json::to_vec(&file)?)?;
+    fs::rename(tmp, &self.path)?;
+    Ok(())
+  }
+}
+
+fn load(contents: &[u8], key: &[u8]) -> Option<Grants> {
+  let file: StoreFile = serde_json::from_slice(contents).ok()?;
+  let signature = decode_hex(&file.signature)?;
+  let mut mac = HmacSha256::new_from_slice(key).ok()?;
+  mac.update(file.grants.as_bytes());
+  mac.verify_slice(&signature).ok()?;
+  serde_json::from_str(&file.grants).ok()
+}
+
+fn sign(key: &[u8], data: &[u8]) -> String {
+  // HMAC accepts keys of any size
+  let mut mac = HmacSha256::new_from_slice(key).unwrap();
+  mac.update(data);
+  mac
+    .finalize()
+    .into_bytes()
+    .iter()
+    .map(|b| format!("{b:02x}"))
+    .collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+  if hex.len() % 2 != 0 {
+    return None;
+  }
+  (0..hex.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+    .collect()
+}
+
+/// Reads the key of the store at the given path from the credential store of the OS, creating it if needed.
+fn read_or_create_key(path: &std::path::Path) -> crate::Result<[u8; KEY_LEN]> {
+  // the store is in the app data directory, so its path identifies the app
+  let entry = keyring::Entry::new(KEY_SERVICE, &path.to_string_lossy())?;
+  match entry.get_secret() {
+    Ok(key) => {
+      if let Ok(key) = key.try_into() {
+        return Ok(key);
+      }
+    }
+    Err(keyring::Error::NoEntry) => (),
+    Err(e) => return Err(e.into()),
+  }
+
+  let mut key = [0; KEY_LEN];
+  getrandom::getrandom(&mut key)?;
+  entry.set_secret(&key)?;
+  Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn signed_store() {
+    let dir = std::env::temp_dir().join(format!("tauri-scope-store-{}", std::process::id()));
+    let path = dir.join("scope.json");
+
+    let grant = Grant {
+      path: "/home/user/Documents".into(),
+      directory: true,
+      recursive: true,
+    };
+
+    let key = [7; KEY_LEN];
+    let store = Store::with_key(path.clone(), key).unwrap();
+    store.allow(grant.clone()).unwrap();
+    store.allow(grant.clone()).unwrap();
+    assert_eq!(
+      Store::with_key(path.clone(), key).unwrap().grants().allowed,
+      vec![grant.clone()]
+    );
+
+    store.revoke(&grant).unwrap();
+    assert!(Store::with_key(path.clone(), key)
+      .unwrap()
+      .grants()
+      .allowed
+      .is_empty());
+
+    // the store is only valid with its key
+    store.allow(grant.clone()).unwrap();
+    assert!(Store::with_key(path.clone(), [8; KEY_LEN])
+      .unwrap()
+      .grants()
+      .allowed
+      .is_empty());
+
+    // editing the grants without the key invalidates the store
+    let contents = fs::read_to_string(&path)
+      .unwrap()
+      .replace("Documents", "Secrets");
+    fs::write(&path, contents).unwrap();
+    assert!(Store::with_key(path.clone(), key)
+      .unwrap()
+      .grants()
+      .allowed
+      .is_empty());
+
+    fs::remove_dir_all(dir).unwrap();
+  }
+}
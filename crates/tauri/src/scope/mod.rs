@@ -38,4 +38,18 @@ impl Scopes {
     self.asset_protocol.forbid_file(path)?;
     Ok(())
   }
+
+  /// Revokes a directory allowed on the scopes.
+  pub fn revoke_directory<P: AsRef<Path>>(&self, path: P, recursive: bool) -> crate::Result<()> {
+    #[cfg(feature = "protocol-asset")]
+    self.asset_protocol.revoke_directory(path, recursive)?;
+    Ok(())
+  }
+
+  /// Revokes a file allowed on the scopes.
+  pub fn revoke_file<P: AsRef<Path>>(&self, path: P) -> crate::Result<()> {
+    #[cfg(feature = "protocol-asset")]
+    self.asset_protocol.revoke_file(path)?;
+    Ok(())
+  }
 }
@@ -8,7 +8,7 @@
 use tauri_runtime::{
   dpi::{PhysicalPosition, PhysicalSize, Position, Size},
   monitor::Monitor,
-  webview::{DetachedWebview, PendingWebview},
+  webview::{DetachedWebview, PendingWebview, PermissionRequest, PermissionResponder},
   window::{CursorIcon, DetachedWindow, PendingWindow, RawWindow, WindowEvent, WindowId},
   window::{WindowBuilder, WindowBuilderBase},
   DeviceEventFilter, Error, EventLoopProxy, ExitRequestedEventAction, Icon, ProgressBarState,
@@ -18,7 +18,10 @@ use tauri_runtime::{
 
 #[cfg(target_os = "macos")]
 use tauri_utils::TitleBarStyle;
-use tauri_utils::{config::WindowConfig, Theme};
+use tauri_utils::{
+  config::{WebviewPermission, WindowConfig},
+  Theme,
+};
 use url::Url;
 
 #[cfg(windows)]
@@ -158,14 +161,23 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
       },
     );
 
-    let webview = webview_id.map(|id| DetachedWebview {
-      label: pending.label.clone(),
-      dispatcher: MockWebviewDispatcher {
-        id,
-        context: self.context.clone(),
-        url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
-        last_evaluated_script: Default::default(),
-      },
+    let webview = webview_id.map(|id| {
+      let webview = pending.webview.unwrap();
+      DetachedWebview {
+        label: pending.label.clone(),
+        dispatcher: MockWebviewDispatcher {
+          id,
+          context: self.context.clone(),
+          url: Arc::new(Mutex::new(webview.url)),
+          last_evaluated_script: Default::default(),
+          permission_request_handler: PermissionRequestHandlerStore(
+            webview.permission_request_handler,
+          ),
+          new_window_handler: NewWindowHandlerStore(
+            webview.new_window_handler.map(|h| Arc::new(Mutex::new(h))),
+          ),
+        },
+      }
     });
 
     Ok(DetachedWindow {
@@ -197,6 +209,12 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        permission_request_handler: PermissionRequestHandlerStore(
+          pending.permission_request_handler,
+        ),
+        new_window_handler: NewWindowHandlerStore(
+          pending.new_window_handler.map(|h| Arc::new(Mutex::new(h))),
+        ),
       },
     })
   }
@@ -282,18 +300,78 @@ impl<T: UserEvent> RuntimeHandle<T> for MockRuntimeHandle {
   }
 }
 
+type PermissionRequestHandler = dyn Fn(PermissionRequest) + Send + Sync;
+
+#[derive(Clone)]
+struct PermissionRequestHandlerStore(Option<Arc<PermissionRequestHandler>>);
+
+impl fmt::Debug for PermissionRequestHandlerStore {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("PermissionRequestHandlerStore").finish()
+  }
+}
+
+type NewWindowHandler = dyn Fn(Url) -> bool + Send;
+
+#[derive(Clone)]
+struct NewWindowHandlerStore(Option<Arc<Mutex<Box<NewWindowHandler>>>>);
+
+impl fmt::Debug for NewWindowHandlerStore {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("NewWindowHandlerStore").finish()
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct MockWebviewDispatcher {
   id: u32,
   context: RuntimeContext,
   url: Arc<Mutex<String>>,
   last_evaluated_script: Arc<Mutex<Option<String>>>,
+  permission_request_handler: PermissionRequestHandlerStore,
+  new_window_handler: NewWindowHandlerStore,
 }
 
 impl MockWebviewDispatcher {
   pub fn last_evaluated_script(&self) -> Option<String> {
     self.last_evaluated_script.lock().unwrap().clone()
   }
+
+  /// Whether the permission requests of the webview content are forwarded to the app.
+  pub fn handles_permission_requests(&self) -> bool {
+    self.permission_request_handler.0.is_some()
+  }
+
+  /// Simulates a permission request of the webview content, returning the receiver of the answer.
+  ///
+  /// The answer is `None` if the request keeps the default behavior of the webview,
+  /// for instance when the webview does not handle permission requests.
+  pub fn request_permission(
+    &self,
+    permissions: Vec<WebviewPermission>,
+    origin: Url,
+  ) -> Receiver<Option<bool>> {
+    let (tx, rx) = sync_channel(1);
+    let request = PermissionRequest {
+      permissions,
+      origin,
+      responder: PermissionResponder::new(move |allow| {
+        let _ = tx.send(allow);
+      }),
+    };
+    if let Some(handler) = &self.permission_request_handler.0 {
+      handler(request);
+    }
+    rx
+  }
+
+  /// Simulates a new window request of the webview content, returning whether the webview opens the window.
+  pub fn request_new_window(&self, url: Url) -> bool {
+    match &self.new_window_handler.0 {
+      Some(handler) => handler.lock().unwrap()(url),
+      None => true,
+    }
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -773,14 +851,23 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
       },
     );
 
-    let webview = webview_id.map(|id| DetachedWebview {
-      label: pending.label.clone(),
-      dispatcher: MockWebviewDispatcher {
-        id,
-        context: self.context.clone(),
-        url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
-        last_evaluated_script: Default::default(),
-      },
+    let webview = webview_id.map(|id| {
+      let webview = pending.webview.unwrap();
+      DetachedWebview {
+        label: pending.label.clone(),
+        dispatcher: MockWebviewDispatcher {
+          id,
+          context: self.context.clone(),
+          url: Arc::new(Mutex::new(webview.url)),
+          last_evaluated_script: Default::default(),
+          permission_request_handler: PermissionRequestHandlerStore(
+            webview.permission_request_handler,
+          ),
+          new_window_handler: NewWindowHandlerStore(
+            webview.new_window_handler.map(|h| Arc::new(Mutex::new(h))),
+          ),
+        },
+      }
     });
 
     Ok(DetachedWindow {
@@ -811,6 +898,12 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        permission_request_handler: PermissionRequestHandlerStore(
+          pending.permission_request_handler,
+        ),
+        new_window_handler: NewWindowHandlerStore(
+          pending.new_window_handler.map(|h| Arc::new(Mutex::new(h))),
+        ),
       },
     })
   }
@@ -953,6 +1046,10 @@ impl<T: UserEvent> WindowDispatch<T> for MockWindowDispatcher {
     Ok(())
   }
 
+  fn set_hit_test(&self, hit_test: tauri_runtime::window::HitTest) -> Result<()> {
+    Ok(())
+  }
+
   fn set_progress_bar(&self, progress_state: ProgressBarState) -> Result<()> {
     Ok(())
   }
@@ -1065,14 +1162,23 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
       },
     );
 
-    let webview = webview_id.map(|id| DetachedWebview {
-      label: pending.label.clone(),
-      dispatcher: MockWebviewDispatcher {
-        id,
-        context: self.context.clone(),
-        url: Arc::new(Mutex::new(pending.webview.unwrap().url)),
-        last_evaluated_script: Default::default(),
-      },
+    let webview = webview_id.map(|id| {
+      let webview = pending.webview.unwrap();
+      DetachedWebview {
+        label: pending.label.clone(),
+        dispatcher: MockWebviewDispatcher {
+          id,
+          context: self.context.clone(),
+          url: Arc::new(Mutex::new(webview.url)),
+          last_evaluated_script: Default::default(),
+          permission_request_handler: PermissionRequestHandlerStore(
+            webview.permission_request_handler,
+          ),
+          new_window_handler: NewWindowHandlerStore(
+            webview.new_window_handler.map(|h| Arc::new(Mutex::new(h))),
+          ),
+        },
+      }
     });
 
     Ok(DetachedWindow {
@@ -1104,6 +1210,12 @@ impl<T: UserEvent> Runtime<T> for MockRuntime {
         context: self.context.clone(),
         last_evaluated_script: Default::default(),
         url: Arc::new(Mutex::new(pending.url)),
+        permission_request_handler: PermissionRequestHandlerStore(
+          pending.permission_request_handler,
+        ),
+        new_window_handler: NewWindowHandlerStore(
+          pending.new_window_handler.map(|h| Arc::new(Mutex::new(h))),
+        ),
       },
     })
   }
@@ -58,14 +58,21 @@ use serialize_to_javascript::DefaultTemplate;
 use std::{borrow::Cow, collections::HashMap, fmt::Debug};
 
 use crate::{
-  ipc::{InvokeError, InvokeResponse, InvokeResponseBody, RuntimeAuthority},
-  webview::InvokeRequest,
-  App, Assets, Builder, Context, Pattern, Runtime, Webview,
+  ipc::{
+    CommandScope, GlobalScope, InvokeError, InvokeResponse, InvokeResponseBody, Origin,
+    RuntimeAuthority, ScopeObject, ScopeObjectMatch,
+  },
+  sealed::ManagerBase,
+  webview::{InvokeRequest, ResolvedScope},
+  App, AppHandle, Assets, Builder, Context, Manager, Pattern, Runtime, Url, Webview,
 };
 use tauri_utils::{
-  acl::resolved::Resolved,
+  acl::{
+    resolved::{Resolved, ResolvedCommand},
+    APP_ACL_KEY,
+  },
   assets::{AssetKey, AssetsIter, CspHash},
-  config::{AppConfig, Config},
+  config::{AppConfig, Config, WebviewPermission},
 };
 
 /// The invoke key used for tests.
@@ -118,8 +125,10 @@ pub fn mock_context<R: Runtime, A: Assets<R>>(assets: A) -> crate::Context<R> {
         windows: Vec::new(),
         security: Default::default(),
         tray_icon: None,
+        menu: None,
         macos_private_api: false,
         enable_gtk_app_id: false,
+        portable: false,
       },
       bundle: Default::default(),
       build: Default::default(),
@@ -130,6 +139,10 @@ pub fn mock_context<R: Runtime, A: Assets<R>>(assets: A) -> crate::Context<R> {
     app_icon: None,
     #[cfg(all(desktop, feature = "tray-icon"))]
     tray_icon: None,
+    #[cfg(all(desktop, feature = "tray-icon"))]
+    tray_icon_animation: Vec::new(),
+    #[cfg(desktop)]
+    menu_icons: Default::default(),
     package_info: crate::PackageInfo {
       name: "test".into(),
       version: "0.1.0".parse().unwrap(),
@@ -302,6 +315,331 @@ pub fn get_ipc_response<W: AsRef<Webview<MockRuntime>>>(
   }
 }
 
+/// Simulates a permission request of the webview content and returns whether it was granted,
+/// or `None` if it keeps the default behavior of the webview.
+///
+/// The request goes through the [`WebviewWindowBuilder::on_permission_request`](crate::WebviewWindowBuilder::on_permission_request) hook
+/// and the [`SecurityConfig::permission_requests`](crate::utils::config::SecurityConfig::permission_requests) policies.
+///
+/// # Examples
+///
+/// ```rust
+/// use tauri::{
+///   test::{mock_builder, mock_context, noop_assets, request_permission},
+///   webview::WebviewPermission,
+/// };
+///
+/// let app = mock_builder().build(mock_context(noop_assets())).unwrap();
+/// let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+///   .on_permission_request(|_webview, request| {
+///     match request.permissions() {
+///       [WebviewPermission::Geolocation] => request.allow(),
+///       [WebviewPermission::Camera] => request.deny(),
+///       _ => (),
+///     }
+///   })
+///   .build()
+///   .unwrap();
+///
+/// let origin = webview.url().unwrap();
+/// let request = |permission| request_permission(&webview, vec![permission], origin.clone());
+/// assert_eq!(request(WebviewPermission::Geolocation), Some(true));
+/// assert_eq!(request(WebviewPermission::Camera), Some(false));
+/// assert_eq!(request(WebviewPermission::Microphone), None);
+/// ```
+pub fn request_permission<W: AsRef<Webview<MockRuntime>>>(
+  webview: &W,
+  permissions: Vec<WebviewPermission>,
+  origin: Url,
+) -> Option<bool> {
+  webview
+    .as_ref()
+    .webview
+    .dispatcher
+    .request_permission(permissions, origin)
+    .recv()
+    .ok()
+    .flatten()
+}
+
+/// Simulates a new window request of the webview content and returns whether the webview opens the window.
+///
+/// The request goes through the [`WebviewWindowBuilder::on_new_window`](crate::WebviewWindowBuilder::on_new_window) hook.
+///
+/// # Examples
+///
+/// ```rust
+/// use tauri::{
+///   test::{mock_builder, mock_context, noop_assets, request_new_window},
+///   webview::NewWindowResponse,
+/// };
+///
+/// let app = mock_builder().build(mock_context(noop_assets())).unwrap();
+/// let webview = tauri::WebviewWindowBuilder::new(&app, "main", Default::default())
+///   .on_new_window(|_webview, url| {
+///     if url.host_str() == Some("tauri.app") {
+///       NewWindowResponse::Allow
+///     } else {
+///       NewWindowResponse::Deny
+///     }
+///   })
+///   .build()
+///   .unwrap();
+///
+/// assert!(request_new_window(&webview, "https://tauri.app".parse().unwrap()));
+/// assert!(!request_new_window(&webview, "https://example.com".parse().unwrap()));
+/// ```
+pub fn request_new_window<W: AsRef<Webview<MockRuntime>>>(webview: &W, url: Url) -> bool {
+  webview.as_ref().webview.dispatcher.request_new_window(url)
+}
+
+/// Access to an IPC command granted by the app ACL, returned by [`assert_ipc_allowed`].
+///
+/// The capabilities and permissions that allow the command are only recorded in debug builds,
+/// so [`Self::capabilities`], [`Self::permissions`] and [`Self::assert_capability`] are not available with `--release`.
+pub struct IpcAccess<R: Runtime> {
+  app: AppHandle<R>,
+  command: String,
+  resolved: Vec<ResolvedCommand>,
+}
+
+impl<R: Runtime> IpcAccess<R> {
+  /// The identifiers of the capabilities that allow the command.
+  #[cfg(debug_assertions)]
+  pub fn capabilities(&self) -> Vec<&str> {
+    let mut capabilities = Vec::new();
+    for cmd in &self.resolved {
+      if !capabilities.contains(&cmd.referenced_by.capability.as_str()) {
+        capabilities.push(cmd.referenced_by.capability.as_str());
+      }
+    }
+    capabilities
+  }
+
+  /// The identifiers of the permissions that allow the command.
+  #[cfg(debug_assertions)]
+  pub fn permissions(&self) -> Vec<&str> {
+    let mut permissions = Vec::new();
+    for cmd in &self.resolved {
+      if !permissions.contains(&cmd.referenced_by.permission.as_str()) {
+        permissions.push(cmd.referenced_by.permission.as_str());
+      }
+    }
+    permissions
+  }
+
+  /// Asserts that the command was allowed by the given capability.
+  #[cfg(debug_assertions)]
+  pub fn assert_capability(&self, capability: &str) -> &Self {
+    let capabilities = self.capabilities();
+    assert!(
+      capabilities.contains(&capability),
+      "command `{}` is not allowed by capability `{capability}`, allowed by: {capabilities:?}",
+      self.command
+    );
+    self
+  }
+
+  /// Resolves the command and global scopes of the command.
+  pub fn scope<T: ScopeObject>(&self) -> crate::Result<ResolvedScope<T>> {
+    let plugin = self
+      .command
+      .strip_prefix("plugin:")
+      .and_then(|c| c.split('|').next())
+      .unwrap_or(APP_ACL_KEY);
+    let scope_ids = self
+      .resolved
+      .iter()
+      .filter_map(|cmd| cmd.scope_id)
+      .collect();
+
+    Ok(ResolvedScope {
+      command_scope: CommandScope::resolve(&self.app, scope_ids)?,
+      global_scope: GlobalScope::resolve(&self.app, plugin)?,
+    })
+  }
+
+  /// Checks the input against both the command and the global scopes.
+  ///
+  /// The input is rejected if any deny scope matches it.
+  /// Otherwise it is accepted if there are no allow scopes or if any of them matches it.
+  pub fn scope_matches<T: ScopeObjectMatch>(&self, input: &T::Input) -> bool {
+    let scope = self.scope::<T>().expect("failed to resolve scope");
+    let (command_scope, global_scope) = (scope.command_scope(), scope.global_scope());
+
+    if command_scope
+      .denies()
+      .iter()
+      .chain(global_scope.denies())
+      .any(|s| s.matches(input))
+    {
+      return false;
+    }
+
+    let mut allows = command_scope
+      .allows()
+      .iter()
+      .chain(global_scope.allows())
+      .peekable();
+    allows.peek().is_none() || allows.any(|s| s.matches(input))
+  }
+
+  /// Asserts that the command scope allows the given input. See [`Self::scope_matches`].
+  pub fn assert_scope_allowed<T: ScopeObjectMatch>(&self, input: &T::Input) -> &Self
+  where
+    T::Input: Debug,
+  {
+    assert!(
+      self.scope_matches::<T>(input),
+      "scope of command `{}` does not allow {input:?}",
+      self.command
+    );
+    self
+  }
+
+  /// Asserts that the command scope denies the given input. See [`Self::scope_matches`].
+  pub fn assert_scope_denied<T: ScopeObjectMatch>(&self, input: &T::Input) -> &Self
+  where
+    T::Input: Debug,
+  {
+    assert!(
+      !self.scope_matches::<T>(input),
+      "scope of command `{}` allows {input:?}",
+      self.command
+    );
+    self
+  }
+}
+
+fn acl_origin(origin: Option<Url>) -> Origin {
+  match origin {
+    Some(url) => Origin::Remote { url },
+    None => Origin::Local,
+  }
+}
+
+/// Checks the given command against the app ACL.
+///
+/// The `origin` is the URL loaded by the webview if it is a remote one, or `None` for the local app origin.
+///
+/// Returns the capabilities and scopes that apply to the command if it is allowed.
+/// The capabilities that matched are only reported in debug builds, see [`IpcAccess`].
+/// Build the app with your context (`tauri::generate_context!()`) so the capabilities compiled by `tauri-build` are used.
+pub fn resolve_ipc_access<R: Runtime, M: Manager<R>>(
+  manager: &M,
+  command: &str,
+  window: &str,
+  webview: &str,
+  origin: Option<Url>,
+) -> Option<IpcAccess<R>> {
+  let origin = acl_origin(origin);
+  let resolved = manager
+    .manager()
+    .runtime_authority
+    .lock()
+    .unwrap()
+    .resolve_access(command, window, webview, &origin)?;
+
+  Some(IpcAccess {
+    app: manager.app_handle().clone(),
+    command: command.into(),
+    resolved,
+  })
+}
+
+/// Asserts that the given command is allowed by the app ACL. See [`resolve_ipc_access`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tauri::test::{assert_ipc_allowed, assert_ipc_denied, mock_builder};
+///
+/// #[derive(Debug, serde::Deserialize)]
+/// struct PathScope {
+///   path: String,
+/// }
+///
+/// impl tauri::ipc::ScopeObjectMatch for PathScope {
+///   type Input = str;
+///
+///   fn matches(&self, input: &str) -> bool {
+///     input.starts_with(&self.path)
+///   }
+/// }
+///
+/// fn main() {
+///   let app = mock_builder()
+///     // remove the string argument to use your app's config file
+///     .build(tauri::generate_context!("test/fixture/src-tauri/tauri.conf.json"))
+///     .unwrap();
+///
+///   let access = assert_ipc_allowed(&app, "plugin:fs|read_file", "main", "main", None);
+///   access
+///     .assert_capability("default")
+///     .assert_scope_allowed::<PathScope>("$APPDATA/db.json")
+///     .assert_scope_denied::<PathScope>("/etc/passwd");
+///
+///   assert_ipc_denied(
+///     &app,
+///     "plugin:fs|read_file",
+///     "main",
+///     "main",
+///     Some("https://tauri.app".parse().unwrap()),
+///   );
+/// }
+/// ```
+pub fn assert_ipc_allowed<R: Runtime, M: Manager<R>>(
+  manager: &M,
+  command: &str,
+  window: &str,
+  webview: &str,
+  origin: Option<Url>,
+) -> IpcAccess<R> {
+  match resolve_ipc_access(manager, command, window, webview, origin.clone()) {
+    Some(access) => access,
+    None => {
+      #[cfg(debug_assertions)]
+      {
+        let (key, command_name) = command
+          .strip_prefix("plugin:")
+          .and_then(|c| c.split_once('|'))
+          .unwrap_or((APP_ACL_KEY, command));
+        panic!(
+          "{}",
+          manager
+            .manager()
+            .runtime_authority
+            .lock()
+            .unwrap()
+            .resolve_access_message(key, command_name, window, webview, &acl_origin(origin))
+        );
+      }
+      #[cfg(not(debug_assertions))]
+      panic!("command `{command}` is not allowed by the ACL");
+    }
+  }
+}
+
+/// Asserts that the given command is denied by the app ACL. See [`resolve_ipc_access`].
+pub fn assert_ipc_denied<R: Runtime, M: Manager<R>>(
+  manager: &M,
+  command: &str,
+  window: &str,
+  webview: &str,
+  origin: Option<Url>,
+) {
+  // the capabilities are only reported in debug builds
+  if let Some(_access) = resolve_ipc_access(manager, command, window, webview, origin) {
+    #[cfg(debug_assertions)]
+    panic!(
+      "command `{command}` is allowed by capabilities {:?}",
+      _access.capabilities()
+    );
+    #[cfg(not(debug_assertions))]
+    panic!("command `{command}` is allowed by the ACL");
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use std::time::Duration;
@@ -325,4 +663,207 @@ mod tests {
       println!("{event:?}");
     });
   }
+
+  #[test]
+  fn permission_request() {
+    use super::{mock_builder, mock_context, noop_assets, request_permission};
+    use crate::webview::WebviewPermission;
+
+    let mut context = mock_context(noop_assets());
+    context.config_mut().app.security.permission_requests =
+      serde_json::from_value(serde_json::json!([
+        { "permissions": ["camera"] },
+        { "permissions": ["microphone"], "local": false, "remote": ["https://*.tauri.app"] },
+        { "permissions": ["clipboardRead"], "allow": false }
+      ]))
+      .unwrap();
+    let app = mock_builder().build(context).unwrap();
+
+    let w = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .on_permission_request(|_webview, request| {
+        // let the config policies answer the other requests
+        if request.permissions() == [WebviewPermission::Notifications] {
+          request.allow();
+        }
+      })
+      .build()
+      .unwrap();
+
+    let local = w.url().unwrap();
+    let remote = "https://meet.tauri.app".parse().unwrap();
+    let request = |permissions: &[WebviewPermission], origin: &crate::Url| {
+      request_permission(&w, permissions.to_vec(), origin.clone())
+    };
+    assert_eq!(
+      request(&[WebviewPermission::Notifications], &local),
+      Some(true)
+    );
+    assert_eq!(request(&[WebviewPermission::Camera], &local), Some(true));
+    assert_eq!(
+      request(&[WebviewPermission::ClipboardRead], &local),
+      Some(false)
+    );
+    assert_eq!(
+      request(
+        &[WebviewPermission::Camera, WebviewPermission::ClipboardRead],
+        &local
+      ),
+      Some(false)
+    );
+    // the permissions without policy keep the default behavior of the webview
+    assert_eq!(
+      request(
+        &[WebviewPermission::Camera, WebviewPermission::Microphone],
+        &local
+      ),
+      None
+    );
+    assert_eq!(request(&[WebviewPermission::Camera], &remote), None);
+    assert_eq!(
+      request(&[WebviewPermission::Microphone], &remote),
+      Some(true)
+    );
+    assert_eq!(request(&[WebviewPermission::Geolocation], &local), None);
+  }
+
+  #[test]
+  fn permission_request_without_hook_or_policies() {
+    use super::{mock_app, request_permission};
+    use crate::webview::WebviewPermission;
+
+    let app = mock_app();
+    let w = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .build()
+      .unwrap();
+
+    // the webview doesn't handle the requests
+    assert!(!w.webview.webview.dispatcher.handles_permission_requests());
+    let origin = w.url().unwrap();
+    assert_eq!(
+      request_permission(&w, vec![WebviewPermission::Camera], origin),
+      None
+    );
+  }
+
+  #[test]
+  fn new_window_request() {
+    use std::sync::{Arc, Mutex};
+
+    use super::{mock_app, request_new_window};
+    use crate::webview::NewWindowResponse;
+
+    let app = mock_app();
+    let requested = Arc::new(Mutex::new(Vec::new()));
+    let requested_ = requested.clone();
+    let w = crate::WebviewWindowBuilder::new(&app, "main", Default::default())
+      .on_new_window(move |webview, url| {
+        assert_eq!(webview.label(), "main");
+        requested_.lock().unwrap().push(url.to_string());
+        match url.path() {
+          "/allow" => NewWindowResponse::Allow,
+          "/browser" => NewWindowResponse::OpenInBrowser,
+          _ => NewWindowResponse::Deny,
+        }
+      })
+      .build()
+      .unwrap();
+
+    let request = |w: &crate::WebviewWindow<_>, path: &str| {
+      request_new_window(w, format!("tauri://localhost/{path}").parse().unwrap())
+    };
+
+    assert!(request(&w, "allow"));
+    assert!(!request(&w, "deny"));
+    // only http and https URLs are opened in the browser, and the webview never opens the window
+    assert!(!request(&w, "browser"));
+    assert_eq!(
+      *requested.lock().unwrap(),
+      [
+        "tauri://localhost/allow",
+        "tauri://localhost/deny",
+        "tauri://localhost/browser"
+      ]
+    );
+
+    // without a hook the webview keeps its default behavior
+    let w = crate::WebviewWindowBuilder::new(&app, "other", Default::default())
+      .build()
+      .unwrap();
+    assert!(request(&w, "allow"));
+  }
+
+  #[derive(Debug, serde::Deserialize)]
+  struct PathScope {
+    path: String,
+  }
+
+  impl crate::ipc::ScopeObjectMatch for PathScope {
+    type Input = str;
+
+    fn matches(&self, input: &str) -> bool {
+      input.starts_with(&self.path)
+    }
+  }
+
+  #[test]
+  fn ipc_access() {
+    use tauri_utils::acl::resolved::{Resolved, ResolvedCommand, ResolvedScope};
+
+    use super::{assert_ipc_allowed, assert_ipc_denied, mock_builder, mock_context, noop_assets};
+    use crate::ipc::RuntimeAuthority;
+
+    let command = "plugin:fs|read";
+    let resolved = Resolved {
+      allowed_commands: [(
+        command.to_string(),
+        vec![ResolvedCommand {
+          #[cfg(debug_assertions)]
+          referenced_by: tauri_utils::acl::resolved::ResolvedCommandReference {
+            capability: "main-capability".into(),
+            permission: "fs:allow-read".into(),
+          },
+          windows: vec![glob::Pattern::new("main").unwrap()],
+          scope_id: Some(1),
+          ..Default::default()
+        }],
+      )]
+      .into_iter()
+      .collect(),
+      command_scope: [(
+        1,
+        ResolvedScope {
+          allow: vec![serde_json::json!({ "path": "/data" }).into()],
+          deny: vec![serde_json::json!({ "path": "/data/secret" }).into()],
+        },
+      )]
+      .into_iter()
+      .collect(),
+      ..Default::default()
+    };
+
+    let mut context = mock_context(noop_assets());
+    *context.runtime_authority_mut() = RuntimeAuthority::new(Default::default(), resolved);
+    let app = mock_builder().build(context).unwrap();
+
+    let access = assert_ipc_allowed(&app, command, "main", "main", None);
+    #[cfg(debug_assertions)]
+    {
+      access.assert_capability("main-capability");
+      assert_eq!(access.permissions(), vec!["fs:allow-read"]);
+    }
+    access
+      .assert_scope_allowed::<PathScope>("/data/db.json")
+      .assert_scope_denied::<PathScope>("/data/secret/key")
+      .assert_scope_denied::<PathScope>("/etc/passwd");
+
+    assert_ipc_denied(&app, command, "other", "other", None);
+    assert_ipc_denied(
+      &app,
+      command,
+      "main",
+      "main",
+      Some("https://tauri.app".parse().unwrap()),
+    );
+    assert_ipc_denied(&app, "plugin:fs|write", "main", "main", None);
+  }
 }
@@ -0,0 +1,70 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::time::Duration;
+
+use crate::image::Image;
+
+/// A sequence of frames shown one after the other by a tray icon, see [`TrayIcon::set_animation`](super::TrayIcon::set_animation).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use tauri::{image::Image, tray::TrayIconAnimation};
+///
+/// tauri::Builder::default()
+///   .setup(|app| {
+///     let frames = (1..=8)
+///       .map(|i| Image::from_path(format!("icons/sync-{i}.png")))
+///       .collect::<tauri::Result<Vec<_>>>()?;
+///     let animation = TrayIconAnimation::from_frames(frames, Duration::from_millis(100));
+///     if let Some(tray) = app.tray_by_id("main") {
+///       tray.set_animation(Some(animation))?;
+///     }
+///     Ok(())
+///   });
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrayIconAnimation {
+  pub(crate) frames: Vec<(Image<'static>, Duration)>,
+  pub(crate) repeat: bool,
+}
+
+impl Default for TrayIconAnimation {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl TrayIconAnimation {
+  /// Creates an animation without frames, which repeats once its frames are shown.
+  pub fn new() -> Self {
+    Self {
+      frames: Vec::new(),
+      repeat: true,
+    }
+  }
+
+  /// Creates an animation showing each frame for the same delay.
+  pub fn from_frames<'a, I: IntoIterator<Item = Image<'a>>>(frames: I, delay: Duration) -> Self {
+    frames.into_iter().fold(Self::new(), |animation, frame| {
+      animation.frame(frame, delay)
+    })
+  }
+
+  /// Adds a frame shown for `delay` before the next one.
+  pub fn frame(mut self, image: Image<'_>, delay: Duration) -> Self {
+    self.frames.push((image.to_owned(), delay));
+    self
+  }
+
+  /// Whether the animation restarts after the last frame, defaults to `true`.
+  ///
+  /// When `false`, the last frame stays shown.
+  pub fn repeat(mut self, repeat: bool) -> Self {
+    self.repeat = repeat;
+    self
+  }
+}
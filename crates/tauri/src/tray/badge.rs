@@ -0,0 +1,209 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use tauri_utils::config::TrayIconBadgeConfig;
+
+use crate::image::Image;
+
+const BADGE_COLOR: [u8; 4] = [0xE5, 0x39, 0x35, 0xFF];
+const TEXT_COLOR: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
+
+/// 3x5 bitmaps of the digits and of `+`, one row per byte with the leftmost pixel as the third bit.
+const GLYPHS: [(char, [u8; 5]); 11] = [
+  ('0', [0b111, 0b101, 0b101, 0b101, 0b111]),
+  ('1', [0b010, 0b110, 0b010, 0b010, 0b111]),
+  ('2', [0b111, 0b001, 0b111, 0b100, 0b111]),
+  ('3', [0b111, 0b001, 0b111, 0b001, 0b111]),
+  ('4', [0b101, 0b101, 0b111, 0b001, 0b001]),
+  ('5', [0b111, 0b100, 0b111, 0b001, 0b111]),
+  ('6', [0b111, 0b100, 0b111, 0b101, 0b111]),
+  ('7', [0b111, 0b001, 0b010, 0b010, 0b010]),
+  ('8', [0b111, 0b101, 0b111, 0b101, 0b111]),
+  ('9', [0b111, 0b101, 0b111, 0b001, 0b111]),
+  ('+', [0b000, 0b010, 0b111, 0b010, 0b000]),
+];
+
+/// A badge drawn over the top right corner of a tray icon, see [`TrayIcon::set_badge`](super::TrayIcon::set_badge).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TrayIconBadge {
+  /// A dot, for instance to notify of unread content.
+  Dot,
+  /// A number, for instance the count of unread notifications.
+  ///
+  /// Numbers above 99 are shown as `99+` and no badge is drawn for `0`.
+  Count(u32),
+}
+
+impl From<TrayIconBadgeConfig> for TrayIconBadge {
+  fn from(config: TrayIconBadgeConfig) -> Self {
+    match config {
+      TrayIconBadgeConfig::Dot => Self::Dot,
+      TrayIconBadgeConfig::Count(count) => Self::Count(count),
+    }
+  }
+}
+
+impl TrayIconBadge {
+  /// Draws the badge over a copy of the image.
+  pub fn apply(&self, image: &Image<'_>) -> Image<'static> {
+    let (width, height) = (image.width(), image.height());
+    let mut canvas = Canvas {
+      rgba: image.rgba().to_vec(),
+      width,
+      height,
+    };
+    let size = width.min(height);
+
+    match *self {
+      Self::Count(0) => (),
+      Self::Dot => {
+        let diameter = (size * 3 / 8).max(4).min(size);
+        canvas.fill_pill(width - diameter, diameter, diameter);
+      }
+      Self::Count(count) => {
+        let text = if count > 99 {
+          "99+".to_string()
+        } else {
+          count.to_string()
+        };
+
+        let badge_height = (size / 2).max(7).min(size);
+        let scale = (badge_height.saturating_sub(2) / 5).max(1);
+        let glyphs = text.chars().count() as u32;
+        let text_width = glyphs * 3 * scale + (glyphs - 1) * scale;
+        // the text is clipped on icons smaller than a glyph
+        let text_y = badge_height.saturating_sub(5 * scale) / 2;
+        let padding = text_y + scale;
+        let badge_width = (text_width + 2 * padding).max(badge_height).min(width);
+
+        let x = width - badge_width;
+        canvas.fill_pill(x, badge_width, badge_height);
+        canvas.draw_text(
+          &text,
+          x + badge_width.saturating_sub(text_width) / 2,
+          text_y,
+          scale,
+        );
+      }
+    }
+
+    Image::new_owned(canvas.rgba, width, height)
+  }
+}
+
+struct Canvas {
+  rgba: Vec<u8>,
+  width: u32,
+  height: u32,
+}
+
+impl Canvas {
+  fn set(&mut self, x: u32, y: u32, color: [u8; 4]) {
+    if x < self.width && y < self.height {
+      let i = ((y * self.width + x) * 4) as usize;
+      self.rgba[i..i + 4].copy_from_slice(&color);
+    }
+  }
+
+  /// Fills a rectangle with fully rounded ends at the top of the image.
+  fn fill_pill(&mut self, x: u32, width: u32, height: u32) {
+    let radius = height as f32 / 2.;
+    let (left, right) = (x as f32 + radius, (x + width) as f32 - radius);
+    for py in 0..height {
+      for px in x..x + width {
+        let (cx, cy) = (px as f32 + 0.5, py as f32 + 0.5);
+        let dx = cx - cx.clamp(left, right.max(left));
+        let dy = cy - radius;
+        if dx * dx + dy * dy <= radius * radius {
+          self.set(px, py, BADGE_COLOR);
+        }
+      }
+    }
+  }
+
+  fn draw_text(&mut self, text: &str, x: u32, y: u32, scale: u32) {
+    let mut glyph_x = x;
+    for c in text.chars() {
+      if let Some((_, rows)) = GLYPHS.iter().find(|(g, _)| *g == c) {
+        for (row, bits) in rows.iter().enumerate() {
+          for column in 0..3 {
+            if bits & (0b100 >> column) == 0 {
+              continue;
+            }
+            for sy in 0..scale {
+              for sx in 0..scale {
+                self.set(
+                  glyph_x + column * scale + sx,
+                  y + row as u32 * scale + sy,
+                  TEXT_COLOR,
+                );
+              }
+            }
+          }
+        }
+      }
+      glyph_x += 4 * scale;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pixel(image: &Image<'_>, x: u32, y: u32) -> [u8; 4] {
+    let i = ((y * image.width() + x) * 4) as usize;
+    image.rgba()[i..i + 4].try_into().unwrap()
+  }
+
+  #[test]
+  fn dot_badge() {
+    let icon = Image::new_owned(vec![0; 32 * 32 * 4], 32, 32);
+    let badged = TrayIconBadge::Dot.apply(&icon);
+
+    // the dot is centered in the top right corner
+    assert_eq!(pixel(&badged, 26, 6), BADGE_COLOR);
+    // its corners are rounded
+    assert_eq!(pixel(&badged, 31, 0), [0; 4]);
+    assert_eq!(pixel(&badged, 4, 28), [0; 4]);
+  }
+
+  #[test]
+  fn count_badge() {
+    let icon = Image::new_owned(vec![0; 32 * 32 * 4], 32, 32);
+
+    assert_eq!(TrayIconBadge::Count(0).apply(&icon).rgba(), icon.rgba());
+
+    let badged = TrayIconBadge::Count(7).apply(&icon);
+    let badge_pixels = |image: &Image<'_>| {
+      image
+        .rgba()
+        .chunks(4)
+        .filter(|p| *p == BADGE_COLOR || *p == TEXT_COLOR)
+        .count()
+    };
+    assert!(badged.rgba().chunks(4).any(|p| p == TEXT_COLOR));
+    // the badge stays in the top half of the icon
+    assert!(badged.rgba()[32 * 16 * 4..].iter().all(|b| *b == 0));
+
+    // larger numbers get a wider badge
+    let wide = TrayIconBadge::Count(100).apply(&icon);
+    assert!(badge_pixels(&wide) > badge_pixels(&badged));
+  }
+
+  #[test]
+  fn tiny_icons() {
+    for size in 1..8 {
+      let icon = Image::new_owned(vec![0; (size * size * 4) as usize], size, size);
+      for badge in [
+        TrayIconBadge::Dot,
+        TrayIconBadge::Count(7),
+        TrayIconBadge::Count(100),
+      ] {
+        assert_eq!(badge.apply(&icon).rgba().len(), icon.rgba().len());
+      }
+    }
+  }
+}
@@ -4,6 +4,8 @@
 
 //! Tray icon types and utilities.
 
+mod animation;
+mod badge;
 pub(crate) mod plugin;
 
 use crate::app::{GlobalMenuEventListener, GlobalTrayIconEventListener};
@@ -17,6 +19,9 @@ use serde::Serialize;
 use std::path::Path;
 pub use tray_icon::TrayIconId;
 
+pub use animation::TrayIconAnimation;
+pub use badge::TrayIconBadge;
+
 /// Describes the mouse button state.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
 pub enum MouseButtonState {
@@ -207,11 +212,35 @@ impl From<tray_icon::TrayIconEvent> for TrayIconEvent {
   }
 }
 
+/// The icon state of a tray icon, which is redrawn when its badge changes.
+#[derive(Default)]
+pub(crate) struct TrayIconState {
+  /// The icon shown under the badge, the base icon or the current animation frame.
+  pub(crate) icon: Option<Image<'static>>,
+  pub(crate) badge: Option<TrayIconBadge>,
+  /// Incremented to stop the running animation.
+  pub(crate) animation: u64,
+}
+
+impl TrayIconState {
+  /// The icon with the badge drawn over it.
+  pub(crate) fn render(&self) -> Option<Image<'static>> {
+    let icon = self.icon.as_ref()?;
+    Some(match self.badge {
+      Some(badge) => badge.apply(icon),
+      None => icon.clone(),
+    })
+  }
+}
+
 /// [`TrayIcon`] builder struct and associated methods.
 #[derive(Default)]
 pub struct TrayIconBuilder<R: Runtime> {
   on_menu_event: Option<GlobalMenuEventListener<AppHandle<R>>>,
   on_tray_icon_event: Option<GlobalTrayIconEventListener<TrayIcon<R>>>,
+  icon: Option<Image<'static>>,
+  badge: Option<TrayIconBadge>,
+  animation: Option<TrayIconAnimation>,
   inner: tray_icon::TrayIconBuilder,
 }
 
@@ -227,6 +256,9 @@ impl<R: Runtime> TrayIconBuilder<R> {
       inner: tray_icon::TrayIconBuilder::new(),
       on_menu_event: None,
       on_tray_icon_event: None,
+      icon: None,
+      badge: None,
+      animation: None,
     }
   }
 
@@ -259,10 +291,19 @@ impl<R: Runtime> TrayIconBuilder<R> {
   /// - **Linux:** Sometimes the icon won't be visible unless a menu is set.
   ///   Setting an empty [`Menu`](crate::menu::Menu) is enough.
   pub fn icon(mut self, icon: Image<'_>) -> Self {
-    let icon = icon.try_into().ok();
-    if let Some(icon) = icon {
-      self.inner = self.inner.with_icon(icon);
-    }
+    self.icon.replace(icon.to_owned());
+    self
+  }
+
+  /// Set a badge drawn over the icon, see [`TrayIcon::set_badge`].
+  pub fn badge(mut self, badge: TrayIconBadge) -> Self {
+    self.badge.replace(badge);
+    self
+  }
+
+  /// Set an animation played once the tray icon is built, see [`TrayIcon::set_animation`].
+  pub fn animation(mut self, animation: TrayIconAnimation) -> Self {
+    self.animation.replace(animation);
     self
   }
 
@@ -340,8 +381,20 @@ impl<R: Runtime> TrayIconBuilder<R> {
   }
 
   /// Builds and adds a new [`TrayIcon`] to the system tray.
-  pub fn build<M: Manager<R>>(self, manager: &M) -> crate::Result<TrayIcon<R>> {
+  pub fn build<M: Manager<R>>(mut self, manager: &M) -> crate::Result<TrayIcon<R>> {
     let id = self.id().clone();
+    let state = TrayIconState {
+      icon: self.icon,
+      badge: self.badge,
+      animation: 0,
+    };
+    if let Some(icon) = state
+      .render()
+      .and_then(|i| tray_icon::Icon::try_from(i).ok())
+    {
+      self.inner = self.inner.with_icon(icon);
+    }
+
     let inner = self.inner.build()?;
     let icon = TrayIcon {
       id,
@@ -353,8 +406,13 @@ impl<R: Runtime> TrayIconBuilder<R> {
       &icon.app_handle,
       self.on_menu_event,
       self.on_tray_icon_event,
+      state,
     );
 
+    if self.animation.is_some() {
+      icon.set_animation(self.animation)?;
+    }
+
     Ok(icon)
   }
 }
@@ -393,6 +451,7 @@ impl<R: Runtime> TrayIcon<R> {
     app_handle: &AppHandle<R>,
     on_menu_event: Option<GlobalMenuEventListener<AppHandle<R>>>,
     on_tray_icon_event: Option<GlobalTrayIconEventListener<TrayIcon<R>>>,
+    state: TrayIconState,
   ) {
     if let Some(handler) = on_menu_event {
       app_handle
@@ -414,6 +473,14 @@ impl<R: Runtime> TrayIcon<R> {
         .insert(self.id.clone(), handler);
     }
 
+    app_handle
+      .manager
+      .tray
+      .states
+      .lock()
+      .unwrap()
+      .insert(self.id.clone(), state);
+
     app_handle
       .manager
       .tray
@@ -464,7 +531,108 @@ impl<R: Runtime> TrayIcon<R> {
   }
 
   /// Sets a new tray icon. If `None` is provided, it will remove the icon.
+  ///
+  /// This stops the running animation and keeps the badge.
   pub fn set_icon(&self, icon: Option<Image<'_>>) -> crate::Result<()> {
+    self.show(icon.map(Image::to_owned), Some(self.stop_animation()))
+  }
+
+  /// Sets a badge drawn over the icon and over the frames of the animations. If `None` is provided, it will remove the badge.
+  ///
+  /// The badge is drawn in the top right corner of the icon.
+  pub fn set_badge(&self, badge: Option<TrayIconBadge>) -> crate::Result<()> {
+    let icon = {
+      let mut states = self.app_handle.manager.tray.states.lock().unwrap();
+      let state = states.entry(self.id.clone()).or_default();
+      state.badge = badge;
+      state.icon.clone()
+    };
+    self.show(icon, None)
+  }
+
+  /// Returns the badge drawn over the icon.
+  pub fn badge(&self) -> Option<TrayIconBadge> {
+    let states = self.app_handle.manager.tray.states.lock().unwrap();
+    states.get(&self.id).and_then(|s| s.badge)
+  }
+
+  /// Plays an animation, replacing the running one. If `None` is provided, it will stop the animation on the current frame.
+  ///
+  /// The frames are scheduled on the [async runtime](crate::async_runtime) and set on the main thread,
+  /// so there's no need for a timer thread calling [`Self::set_icon`].
+  /// The animation stops when the icon is set with [`Self::set_icon`] or when the tray icon is removed.
+  pub fn set_animation(&self, animation: Option<TrayIconAnimation>) -> crate::Result<()> {
+    let generation = self.stop_animation();
+    let Some(TrayIconAnimation { frames, repeat }) = animation.filter(|a| !a.frames.is_empty())
+    else {
+      return Ok(());
+    };
+
+    self.show(Some(frames[0].0.clone()), Some(generation))?;
+
+    let app_handle = self.app_handle.clone();
+    let id = self.id.clone();
+    crate::async_runtime::spawn(async move {
+      let mut index = 0;
+      loop {
+        tokio::time::sleep(frames[index].1).await;
+
+        index += 1;
+        if index == frames.len() {
+          if !repeat {
+            break;
+          }
+          index = 0;
+        }
+
+        if !app_handle.manager.tray.is_animating(&id, generation) {
+          break;
+        }
+
+        // the tray icons are only cloned on the main thread
+        let frame = frames[index].0.clone();
+        let app_handle_ = app_handle.clone();
+        let id_ = id.clone();
+        let shown = app_handle.run_on_main_thread(move || {
+          let icons = app_handle_.manager.tray.icons.lock().unwrap();
+          if let Some(tray) = icons.iter().find(|t| t.id == id_) {
+            if let Err(e) = tray.show(Some(frame), Some(generation)) {
+              log::error!("failed to show the tray icon animation frame: {e}");
+            }
+          }
+        });
+        if shown.is_err() {
+          break;
+        }
+      }
+    });
+
+    Ok(())
+  }
+
+  /// Stops the running animation, returning the generation of the next one.
+  fn stop_animation(&self) -> u64 {
+    let mut states = self.app_handle.manager.tray.states.lock().unwrap();
+    let state = states.entry(self.id.clone()).or_default();
+    state.animation += 1;
+    state.animation
+  }
+
+  /// Shows the icon with its badge, unless it is a frame of an animation that was stopped.
+  fn show(&self, icon: Option<Image<'static>>, generation: Option<u64>) -> crate::Result<()> {
+    let icon = {
+      let mut states = self.app_handle.manager.tray.states.lock().unwrap();
+      let state = match generation {
+        Some(generation) => match states.get_mut(&self.id) {
+          Some(state) if state.animation == generation => state,
+          _ => return Ok(()),
+        },
+        None => states.entry(self.id.clone()).or_default(),
+      };
+      state.icon = icon;
+      state.render()
+    };
+
     let icon = match icon {
       Some(i) => Some(i.try_into()?),
       None => None,
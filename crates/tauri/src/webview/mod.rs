@@ -19,9 +19,10 @@ use tauri_runtime::{
   WindowDispatch,
 };
 use tauri_runtime::{
-  webview::{DetachedWebview, PendingWebview, WebviewAttributes},
+  webview::{DetachedWebview, PendingWebview, PermissionResponder, WebviewAttributes},
   WebviewDispatch,
 };
+pub use tauri_utils::config::WebviewPermission;
 use tauri_utils::config::{WebviewUrl, WindowConfig};
 pub use url::Url;
 
@@ -48,12 +49,20 @@ use std::{
 pub(crate) type WebResourceRequestHandler =
   dyn Fn(http::Request<Vec<u8>>, &mut http::Response<Cow<'static, [u8]>>) + Send + Sync;
 pub(crate) type NavigationHandler = dyn Fn(&Url) -> bool + Send;
+pub(crate) type NewWindowHandler<R> =
+  dyn Fn(Webview<R>, &Url) -> NewWindowResponse + Send + Sync + 'static;
 pub(crate) type UriSchemeProtocolHandler =
   Box<dyn Fn(&str, http::Request<Vec<u8>>, UriSchemeResponder) + Send + Sync>;
 pub(crate) type OnPageLoad<R> = dyn Fn(Webview<R>, PageLoadPayload<'_>) + Send + Sync + 'static;
 
 pub(crate) type DownloadHandler<R> = dyn Fn(Webview<R>, DownloadEvent<'_>) -> bool + Send + Sync;
 
+pub(crate) type PermissionRequestHandler<R> = dyn Fn(Webview<R>, PermissionRequest) + Send + Sync;
+
+#[cfg(desktop)]
+pub(crate) type ContextMenuHandler<R> =
+  dyn Fn(Webview<R>, &ContextMenuRequest) -> ContextMenuResponse<R> + Send + Sync;
+
 #[derive(Clone, Serialize)]
 pub(crate) struct CreatedEvent {
   pub(crate) label: String,
@@ -91,6 +100,139 @@ pub enum DownloadEvent<'a> {
   },
 }
 
+/// The response of the [`WebviewBuilder#method.on_new_window`] hook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NewWindowResponse {
+  /// Lets the webview handle the request with its default behavior.
+  Allow,
+  /// Prevents the window from opening.
+  Deny,
+  /// Opens the URL in the default browser instead. Only `http` and `https` URLs are opened.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS**: Unsupported, the URL is not opened.
+  OpenInBrowser,
+}
+
+/// Opens an `http` or `https` URL in the default browser.
+#[cfg(desktop)]
+fn open_in_browser(url: &Url) -> std::io::Result<()> {
+  if !matches!(url.scheme(), "http" | "https") {
+    return Err(std::io::Error::new(
+      std::io::ErrorKind::InvalidInput,
+      format!("unsupported URL scheme `{}`", url.scheme()),
+    ));
+  }
+
+  open::that_detached(url.as_str())
+}
+
+/// A permission request of the webview content for the [`WebviewBuilder#method.on_permission_request`] hook.
+///
+/// The request can be answered later, for instance after asking the user.
+/// Dropping it without answering applies the [`SecurityConfig::permission_requests`](crate::utils::config::SecurityConfig::permission_requests) policies,
+/// or keeps the default behavior of the webview when no policy applies.
+pub struct PermissionRequest {
+  permissions: Vec<WebviewPermission>,
+  origin: Url,
+  default: Option<bool>,
+  responder: Option<PermissionResponder>,
+}
+
+impl PermissionRequest {
+  /// The requested permissions.
+  pub fn permissions(&self) -> &[WebviewPermission] {
+    &self.permissions
+  }
+
+  /// The URL of the page requesting the permissions.
+  pub fn origin(&self) -> &Url {
+    &self.origin
+  }
+
+  /// Grants the requested permissions.
+  pub fn allow(mut self) {
+    if let Some(responder) = self.responder.take() {
+      responder.respond(true);
+    }
+  }
+
+  /// Denies the requested permissions.
+  pub fn deny(mut self) {
+    if let Some(responder) = self.responder.take() {
+      responder.respond(false);
+    }
+  }
+}
+
+impl Drop for PermissionRequest {
+  fn drop(&mut self) {
+    // dropping the responder keeps the default behavior of the webview
+    if let (Some(responder), Some(allow)) = (self.responder.take(), self.default) {
+      responder.respond(allow);
+    }
+  }
+}
+
+/// A context menu request of the webview content for the [`WebviewBuilder#method.on_context_menu`] hook.
+#[cfg(desktop)]
+#[derive(Debug, Clone)]
+pub struct ContextMenuRequest {
+  position: PhysicalPosition<f64>,
+  link_url: Option<Url>,
+  image_url: Option<Url>,
+  selection_text: Option<String>,
+  is_editable: bool,
+}
+
+#[cfg(desktop)]
+impl ContextMenuRequest {
+  /// The position of the cursor, relative to the webview.
+  pub fn position(&self) -> PhysicalPosition<f64> {
+    self.position
+  }
+
+  /// The URL of the link the menu was requested on.
+  pub fn link_url(&self) -> Option<&Url> {
+    self.link_url.as_ref()
+  }
+
+  /// The source URL of the image the menu was requested on.
+  pub fn image_url(&self) -> Option<&Url> {
+    self.image_url.as_ref()
+  }
+
+  /// The selected text.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux**: Always `None`, WebKitGTK doesn't give the selected text without blocking the main thread.
+  pub fn selection_text(&self) -> Option<&str> {
+    self.selection_text.as_deref()
+  }
+
+  /// Whether the menu was requested on an editable element, such as an input.
+  pub fn is_editable(&self) -> bool {
+    self.is_editable
+  }
+}
+
+/// The response of the [`WebviewBuilder#method.on_context_menu`] hook.
+#[cfg(desktop)]
+#[non_exhaustive]
+pub enum ContextMenuResponse<R: Runtime> {
+  /// Shows the native context menu of the webview.
+  Default,
+  /// Shows no context menu.
+  Prevent,
+  /// Shows the given menu at the cursor position instead of the native one.
+  ///
+  /// Use [`PredefinedMenuItem`](crate::menu::PredefinedMenuItem)s such as copy or paste to keep the native actions.
+  Menu(crate::menu::Menu<R>),
+}
+
 /// The payload for the [`WebviewBuilder::on_page_load`] hook.
 #[derive(Debug, Clone)]
 pub struct PageLoadPayload<'a> {
@@ -234,8 +376,12 @@ unstable_struct!(
     pub(crate) webview_attributes: WebviewAttributes,
     pub(crate) web_resource_request_handler: Option<Box<WebResourceRequestHandler>>,
     pub(crate) navigation_handler: Option<Box<NavigationHandler>>,
+    pub(crate) new_window_handler: Option<Box<NewWindowHandler<R>>>,
     pub(crate) on_page_load_handler: Option<Box<OnPageLoad<R>>>,
     pub(crate) download_handler: Option<Arc<DownloadHandler<R>>>,
+    pub(crate) permission_request_handler: Option<Arc<PermissionRequestHandler<R>>>,
+    #[cfg(desktop)]
+    pub(crate) context_menu_handler: Option<Box<ContextMenuHandler<R>>>,
   }
 );
 
@@ -310,8 +456,12 @@ async fn create_window(app: tauri::AppHandle) {
       webview_attributes: WebviewAttributes::new(url),
       web_resource_request_handler: None,
       navigation_handler: None,
+      new_window_handler: None,
       on_page_load_handler: None,
       download_handler: None,
+      permission_request_handler: None,
+      #[cfg(desktop)]
+      context_menu_handler: None,
     }
   }
 
@@ -350,8 +500,12 @@ async fn reopen_window(app: tauri::AppHandle) {
       webview_attributes: WebviewAttributes::from(config),
       web_resource_request_handler: None,
       navigation_handler: None,
+      new_window_handler: None,
       on_page_load_handler: None,
       download_handler: None,
+      permission_request_handler: None,
+      #[cfg(desktop)]
+      context_menu_handler: None,
     }
   }
 
@@ -448,6 +602,51 @@ tauri::Builder::default()
     self
   }
 
+  /// Defines a closure to be executed when the webview content requests a new window,
+  /// with `window.open` or a link targeting a new browsing context such as `target="_blank"`.
+  ///
+  /// The returned [`NewWindowResponse`] lets the webview open the window, denies the request
+  /// or opens the URL in the default browser instead.
+  ///
+  /// # Examples
+  ///
+  #[cfg_attr(
+    feature = "unstable",
+    doc = r####"
+```rust,no_run
+use tauri::{
+  utils::config::WebviewUrl,
+  window::WindowBuilder,
+  webview::{NewWindowResponse, WebviewBuilder},
+};
+
+tauri::Builder::default()
+  .setup(|app| {
+    let window = WindowBuilder::new(app, "label").build()?;
+    let webview_builder = WebviewBuilder::new("core", WebviewUrl::App("index.html".into()))
+      .on_new_window(|webview, url| {
+        if url.host_str() == Some("accounts.example.com") {
+          // let the webview open the OAuth popup
+          NewWindowResponse::Allow
+        } else {
+          NewWindowResponse::OpenInBrowser
+        }
+      });
+
+    let webview = window.add_child(webview_builder, tauri::LogicalPosition::new(0, 0), window.inner_size().unwrap())?;
+    Ok(())
+  });
+```
+  "####
+  )]
+  pub fn on_new_window<F: Fn(Webview<R>, &Url) -> NewWindowResponse + Send + Sync + 'static>(
+    mut self,
+    f: F,
+  ) -> Self {
+    self.new_window_handler.replace(Box::new(f));
+    self
+  }
+
   /// Set a download event handler to be notified when a download is requested or finished.
   ///
   /// Returning `false` prevents the download from happening on a [`DownloadEvent::Requested`] event.
@@ -497,6 +696,118 @@ tauri::Builder::default()
     self
   }
 
+  /// Defines a closure to be executed when the webview content requests a permission,
+  /// such as the camera, microphone or geolocation access.
+  ///
+  /// The request can be answered right away or later from another thread.
+  /// Dropping it without answering applies the [`SecurityConfig::permission_requests`](crate::utils::config::SecurityConfig::permission_requests) policies,
+  /// or keeps the default behavior of the webview when no policy applies.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux**: Clipboard read requests are not supported.
+  /// - **macOS / Android / iOS**: Unsupported.
+  ///
+  /// # Examples
+  ///
+  #[cfg_attr(
+    feature = "unstable",
+    doc = r####"
+```rust,no_run
+use tauri::{
+  utils::config::WebviewUrl,
+  window::WindowBuilder,
+  webview::{WebviewBuilder, WebviewPermission},
+};
+
+tauri::Builder::default()
+  .setup(|app| {
+    let window = WindowBuilder::new(app, "label").build()?;
+    let webview_builder = WebviewBuilder::new("core", WebviewUrl::App("index.html".into()))
+      .on_permission_request(|webview, request| {
+        if request.permissions().contains(&WebviewPermission::Camera) {
+          request.allow();
+        } else {
+          request.deny();
+        }
+      });
+
+    let webview = window.add_child(webview_builder, tauri::LogicalPosition::new(0, 0), window.inner_size().unwrap())?;
+    Ok(())
+  });
+```
+  "####
+  )]
+  pub fn on_permission_request<F: Fn(Webview<R>, PermissionRequest) + Send + Sync + 'static>(
+    mut self,
+    f: F,
+  ) -> Self {
+    self.permission_request_handler.replace(Arc::new(f));
+    self
+  }
+
+  /// Defines a closure to be executed when the context menu of the webview is requested,
+  /// for instance with a right click.
+  ///
+  /// The returned [`ContextMenuResponse`] shows the native menu, no menu, or a [`Menu`](crate::menu::Menu)
+  /// replacing the native one, built from the link, image, selection and editable element under the cursor.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux**: The selected text is not available, see [`ContextMenuRequest::selection_text`].
+  /// - **macOS**: Unsupported.
+  ///
+  /// # Examples
+  ///
+  #[cfg_attr(
+    feature = "unstable",
+    doc = r####"
+```rust,no_run
+use tauri::{
+  menu::MenuBuilder,
+  utils::config::WebviewUrl,
+  window::WindowBuilder,
+  webview::{ContextMenuResponse, WebviewBuilder},
+};
+
+tauri::Builder::default()
+  .setup(|app| {
+    let window = WindowBuilder::new(app, "label").build()?;
+    let webview_builder = WebviewBuilder::new("core", WebviewUrl::App("index.html".into()))
+      .on_context_menu(|webview, request| {
+        if request.is_editable() {
+          return ContextMenuResponse::Default;
+        }
+        let mut menu = MenuBuilder::new(&webview);
+        if request.selection_text().is_some() {
+          menu = menu.copy();
+        }
+        if request.link_url().is_some() {
+          menu = menu.text("open-link", "Open Link");
+        }
+        match menu.build() {
+          Ok(menu) => ContextMenuResponse::Menu(menu),
+          Err(_) => ContextMenuResponse::Prevent,
+        }
+      });
+
+    let webview = window.add_child(webview_builder, tauri::LogicalPosition::new(0, 0), window.inner_size().unwrap())?;
+    Ok(())
+  });
+```
+  "####
+  )]
+  #[cfg(desktop)]
+  pub fn on_context_menu<
+    F: Fn(Webview<R>, &ContextMenuRequest) -> ContextMenuResponse<R> + Send + Sync + 'static,
+  >(
+    mut self,
+    f: F,
+  ) -> Self {
+    self.context_menu_handler.replace(Box::new(f));
+    self
+  }
+
   /// Defines a closure to be executed when a page load event is triggered.
   /// The event can be either [`PageLoadEvent::Started`] if the page has started loading
   /// or [`PageLoadEvent::Finished`] when the page finishes loading.
@@ -551,6 +862,29 @@ tauri::Builder::default()
     pending.navigation_handler = self.navigation_handler.take();
     pending.web_resource_request_handler = self.web_resource_request_handler.take();
 
+    if let Some(new_window_handler) = self.new_window_handler.take() {
+      let label = pending.label.clone();
+      let manager = manager.manager_owned();
+      pending
+        .new_window_handler
+        .replace(Box::new(move |url: Url| {
+          let Some(webview) = manager.get_webview(&label) else {
+            return false;
+          };
+          match new_window_handler(webview, &url) {
+            NewWindowResponse::Allow => true,
+            NewWindowResponse::Deny => false,
+            NewWindowResponse::OpenInBrowser => {
+              #[cfg(desktop)]
+              if let Err(e) = open_in_browser(&url) {
+                log::error!("failed to open {url} in the browser: {e}");
+              }
+              false
+            }
+          }
+        }));
+    }
+
     if let Some(download_handler) = self.download_handler.take() {
       let label = pending.label.clone();
       let manager = manager.manager_owned();
@@ -573,6 +907,93 @@ tauri::Builder::default()
       }));
     }
 
+    // the requests keep the default behavior of the webview without a hook or policies
+    if self.permission_request_handler.is_some()
+      || !manager.config().app.security.permission_requests.is_empty()
+    {
+      let label = pending.label.clone();
+      let manager = manager.manager_owned();
+      let permission_request_handler = self.permission_request_handler.take();
+      pending.permission_request_handler.replace(Arc::new(
+        move |request: tauri_runtime::webview::PermissionRequest| {
+          // dropping the responder keeps the default behavior of the webview
+          let Some(webview) = manager.get_webview(&label) else {
+            return;
+          };
+          let remote_origin = (!webview.is_local_url(&request.origin)).then_some(&request.origin);
+          let security = &manager.config().app.security;
+          let answers = request
+            .permissions
+            .iter()
+            .map(|permission| {
+              security.permission_request_answer(*permission, &label, remote_origin)
+            })
+            .collect::<Vec<_>>();
+          // a denied permission denies the request, and a permission without policy keeps the default behavior
+          let default = if answers.contains(&Some(false)) {
+            Some(false)
+          } else {
+            answers.iter().all(Option::is_some).then_some(true)
+          };
+          let request = PermissionRequest {
+            permissions: request.permissions,
+            origin: request.origin,
+            default,
+            responder: Some(request.responder),
+          };
+          if let Some(handler) = &permission_request_handler {
+            handler(webview, request);
+          }
+        },
+      ));
+    }
+
+    #[cfg(desktop)]
+    if let Some(context_menu_handler) = self.context_menu_handler.take() {
+      let label = pending.label.clone();
+      let manager = manager.manager_owned();
+      pending.context_menu_handler.replace(Box::new(
+        move |request: tauri_runtime::webview::ContextMenuRequest| {
+          let Some(webview) = manager.get_webview(&label) else {
+            return true;
+          };
+          let window = webview.window();
+          let scale_factor = window.scale_factor().unwrap_or(1.);
+          let request = ContextMenuRequest {
+            position: request.position.to_physical(scale_factor),
+            link_url: request.link_url,
+            image_url: request.image_url,
+            selection_text: request.selection_text,
+            is_editable: request.is_editable,
+          };
+
+          match context_menu_handler(webview.clone(), &request) {
+            ContextMenuResponse::Default => true,
+            ContextMenuResponse::Prevent => false,
+            ContextMenuResponse::Menu(menu) => {
+              // the menu is positioned relative to the window
+              let position = webview
+                .position()
+                .map(|offset| {
+                  PhysicalPosition::new(
+                    offset.x as f64 + request.position.x,
+                    offset.y as f64 + request.position.y,
+                  )
+                })
+                .unwrap_or(request.position);
+              // the menu is shown once the webview is done with the request
+              crate::async_runtime::spawn(async move {
+                if let Err(e) = window.popup_menu_at(&menu, position) {
+                  log::error!("failed to show the context menu: {e}");
+                }
+              });
+              false
+            }
+          }
+        },
+      ));
+    }
+
     let label_ = pending.label.clone();
     let manager_ = manager.manager_owned();
     pending
@@ -946,8 +1367,8 @@ impl<R: Runtime> Webview<R> {
         .filter_map(|cmd| cmd.scope_id)
         .collect::<Vec<_>>();
 
-      let command_scope = CommandScope::resolve(self, scope_ids)?;
-      let global_scope = GlobalScope::resolve(self, plugin)?;
+      let command_scope = CommandScope::resolve(self.app_handle(), scope_ids)?;
+      let global_scope = GlobalScope::resolve(self.app_handle(), plugin)?;
 
       Ok(Some(ResolvedScope {
         global_scope,
@@ -1236,6 +1657,11 @@ fn main() {
       return;
     }
 
+    if request.cmd == crate::ipc::batch::BATCH_COMMAND {
+      crate::ipc::batch::handle(self, request, responder);
+      return;
+    }
+
     let resolver = InvokeResolver::new(
       self.clone(),
       Arc::new(Mutex::new(Some(Box::new(
@@ -1252,6 +1678,13 @@ fn main() {
     #[cfg(mobile)]
     let app_handle = self.app_handle.clone();
 
+    let timeout = request
+      .headers
+      .get(crate::ipc::INVOKE_TIMEOUT_HEADER_NAME)
+      .and_then(|timeout| timeout.to_str().ok())
+      .and_then(|timeout| timeout.parse().ok())
+      .map(std::time::Duration::from_millis);
+
     let message = InvokeMessage::new(
       self,
       manager.state(),
@@ -1284,6 +1717,10 @@ fn main() {
       acl: resolved_acl,
     };
 
+    if let Some(timeout) = timeout {
+      invoke.set_timeout(timeout);
+    }
+
     let plugin_command = request.cmd.strip_prefix("plugin:").map(|raw_command| {
       let mut tokens = raw_command.split('|');
       // safe to unwrap: split always has a least one item
@@ -1781,8 +2218,8 @@ impl<'de, R: Runtime> CommandArg<'de, R> for Webview<R> {
 
 /// Resolved scope that can be obtained via [`Webview::resolve_command_scope`].
 pub struct ResolvedScope<T: ScopeObject> {
-  command_scope: CommandScope<T>,
-  global_scope: GlobalScope<T>,
+  pub(crate) command_scope: CommandScope<T>,
+  pub(crate) global_scope: GlobalScope<T>,
 }
 
 impl<T: ScopeObject> ResolvedScope<T> {
@@ -26,6 +26,7 @@ use crate::{
     window::CursorIcon,
     UserAttentionType,
   },
+  webview::{ContextMenuRequest, ContextMenuResponse},
 };
 use serde::Serialize;
 use tauri_utils::{
@@ -49,7 +50,7 @@ use tauri_macros::default_runtime;
 #[cfg(windows)]
 use windows::Win32::Foundation::HWND;
 
-use super::{DownloadEvent, ResolvedScope};
+use super::{DownloadEvent, NewWindowResponse, PermissionRequest, ResolvedScope};
 
 /// A builder for [`WebviewWindow`], a window that hosts a single webview.
 pub struct WebviewWindowBuilder<'a, R: Runtime, M: Manager<R>> {
@@ -117,6 +118,8 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   /// Keep in mind that you can't create 2 windows with the same `label` so make sure
   /// that the initial window was closed or change the label of the new [`WebviewWindowBuilder`].
   ///
+  /// The [`layout`](WindowConfig#structfield.layout) of the configuration is ignored since the window has its own webview.
+  ///
   /// # Known issues
   ///
   /// On Windows, this function deadlocks when used in a synchronous command, see [the Webview2 issue].
@@ -138,8 +141,14 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
   ///
   /// [the Webview2 issue]: https://github.com/tauri-apps/wry/issues/583
   pub fn from_config(manager: &'a M, config: &WindowConfig) -> crate::Result<Self> {
+    #[cfg_attr(not(any(test, all(desktop, feature = "unstable"))), allow(unused_mut))]
+    let mut window_builder = WindowBuilder::from_config(manager, config)?;
+    // the window has its own webview instead of the webviews of the layout
+    #[cfg(any(test, all(desktop, feature = "unstable")))]
+    window_builder.layout.take();
+
     Ok(Self {
-      window_builder: WindowBuilder::from_config(manager, config)?,
+      window_builder,
       webview_builder: WebviewBuilder::from_config(config),
     })
   }
@@ -258,6 +267,43 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     self
   }
 
+  /// Defines a closure to be executed when the webview content requests a new window,
+  /// with `window.open` or a link targeting a new browsing context such as `target="_blank"`.
+  ///
+  /// The returned [`NewWindowResponse`] lets the webview open the window, denies the request
+  /// or opens the URL in the default browser instead.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::{
+  ///   utils::config::WebviewUrl,
+  ///   webview::{NewWindowResponse, WebviewWindowBuilder},
+  /// };
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     let webview_window = WebviewWindowBuilder::new(app, "core", WebviewUrl::App("index.html".into()))
+  ///       .on_new_window(|webview, url| {
+  ///         if url.host_str() == Some("accounts.example.com") {
+  ///           // let the webview open the OAuth popup
+  ///           NewWindowResponse::Allow
+  ///         } else {
+  ///           NewWindowResponse::OpenInBrowser
+  ///         }
+  ///       })
+  ///       .build()?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn on_new_window<F: Fn(Webview<R>, &Url) -> NewWindowResponse + Send + Sync + 'static>(
+    mut self,
+    f: F,
+  ) -> Self {
+    self.webview_builder = self.webview_builder.on_new_window(f);
+    self
+  }
+
   /// Set a download event handler to be notified when a download is requested or finished.
   ///
   /// Returning `false` prevents the download from happening on a [`DownloadEvent::Requested`] event.
@@ -306,6 +352,95 @@ tauri::Builder::default()
     self
   }
 
+  /// Defines a closure to be executed when the webview content requests a permission,
+  /// such as the camera, microphone or geolocation access.
+  ///
+  /// The request can be answered right away or later from another thread.
+  /// Dropping it without answering applies the [`SecurityConfig::permission_requests`](crate::utils::config::SecurityConfig::permission_requests) policies,
+  /// or keeps the default behavior of the webview when no policy applies.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux**: Clipboard read requests are not supported.
+  /// - **macOS / Android / iOS**: Unsupported.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::{
+  ///   utils::config::WebviewUrl,
+  ///   webview::{WebviewPermission, WebviewWindowBuilder},
+  /// };
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     let webview_window = WebviewWindowBuilder::new(app, "core", WebviewUrl::App("index.html".into()))
+  ///       .on_permission_request(|webview, request| {
+  ///         if request.permissions().contains(&WebviewPermission::Camera) {
+  ///           request.allow();
+  ///         } else {
+  ///           request.deny();
+  ///         }
+  ///       })
+  ///       .build()?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn on_permission_request<F: Fn(Webview<R>, PermissionRequest) + Send + Sync + 'static>(
+    mut self,
+    f: F,
+  ) -> Self {
+    self.webview_builder = self.webview_builder.on_permission_request(f);
+    self
+  }
+
+  /// Defines a closure to be executed when the context menu of the webview is requested,
+  /// for instance with a right click.
+  ///
+  /// The returned [`ContextMenuResponse`] shows the native menu, no menu, or a [`Menu`]
+  /// replacing the native one, built from the link, image, selection and editable element under the cursor.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux**: The selected text is not available, see [`ContextMenuRequest::selection_text`].
+  /// - **macOS**: Unsupported.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::{
+  ///   menu::MenuBuilder,
+  ///   utils::config::WebviewUrl,
+  ///   webview::{ContextMenuResponse, WebviewWindowBuilder},
+  /// };
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     let webview_window = WebviewWindowBuilder::new(app, "core", WebviewUrl::App("index.html".into()))
+  ///       .on_context_menu(|webview, request| {
+  ///         if request.selection_text().is_none() {
+  ///           return ContextMenuResponse::Prevent;
+  ///         }
+  ///         match MenuBuilder::new(&webview).copy().build() {
+  ///           Ok(menu) => ContextMenuResponse::Menu(menu),
+  ///           Err(_) => ContextMenuResponse::Default,
+  ///         }
+  ///       })
+  ///       .build()?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  #[cfg(desktop)]
+  pub fn on_context_menu<
+    F: Fn(Webview<R>, &ContextMenuRequest) -> ContextMenuResponse<R> + Send + Sync + 'static,
+  >(
+    mut self,
+    f: F,
+  ) -> Self {
+    self.webview_builder = self.webview_builder.on_context_menu(f);
+    self
+  }
+
   /// Defines a closure to be executed when a page load event is triggered.
   /// The event can be either [`tauri_runtime::webview::PageLoadEvent::Started`] if the page has started loading
   /// or [`tauri_runtime::webview::PageLoadEvent::Finished`] when the page finishes loading.
@@ -382,6 +517,16 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     self
   }
 
+  /// Whether the position, size, maximized and fullscreen state of the window are saved
+  /// when it is moved, resized or closed, and restored the next time it is created.
+  ///
+  /// See [`WindowBuilder::remember_state`](crate::window::WindowBuilder::remember_state).
+  #[must_use]
+  pub fn remember_state(mut self, remember: bool) -> Self {
+    self.window_builder = self.window_builder.remember_state(remember);
+    self
+  }
+
   /// Window size.
   #[must_use]
   pub fn inner_size(mut self, width: f64, height: f64) -> Self {
@@ -600,6 +745,26 @@ impl<'a, R: Runtime, M: Manager<R>> WebviewWindowBuilder<'a, R, M> {
     Ok(self)
   }
 
+  /// Makes the window to be created a modal window of `parent`, see [`Self::parent`].
+  ///
+  /// The input to the parent window is disabled until the modal window is closed.
+  /// Create it hidden and use [`WebviewWindow::show_modal`] to show it and wait for its result.
+  pub fn modal(mut self, parent: &WebviewWindow<R>) -> crate::Result<Self> {
+    self.window_builder = self.window_builder.modal(&parent.window)?;
+    Ok(self)
+  }
+
+  /// Adds the window to be created to a [`WindowGroup`](crate::window::WindowGroup), whose windows are minimized and restored together.
+  ///
+  /// Only minimizing and restoring a window follows the group. Hiding or closing a window does not affect
+  /// the other ones, use [`WindowGroup::show`](crate::window::WindowGroup::show), [`WindowGroup::hide`](crate::window::WindowGroup::hide)
+  /// and [`WindowGroup::close`](crate::window::WindowGroup::close) for that.
+  #[must_use]
+  pub fn group<G: Into<String>>(mut self, group: G) -> Self {
+    self.window_builder = self.window_builder.group(group);
+    self
+  }
+
   /// Set an owner to the window to be created.
   ///
   /// From MSDN:
@@ -1455,6 +1620,25 @@ impl<R: Runtime> WebviewWindow<R> {
     self.window.destroy()
   }
 
+  /// Shows this window and waits until it is closed, resolving with the result given to [`Self::close_with_result`].
+  ///
+  /// See [`Window::show_modal`](crate::window::Window::show_modal).
+  pub async fn show_modal<T: serde::de::DeserializeOwned>(&self) -> crate::Result<Option<T>> {
+    self.window.show_modal().await
+  }
+
+  /// Closes this window like [`Self::close`], resolving the [`Self::show_modal`] calls with `result`.
+  ///
+  /// If the close request is prevented, the result is discarded when the window is closed again.
+  pub fn close_with_result<T: Serialize>(&self, result: T) -> crate::Result<()> {
+    self.window.close_with_result(result)
+  }
+
+  /// Returns the [`WindowGroup`](crate::window::WindowGroup) of this window, see [`WebviewWindowBuilder::group`].
+  pub fn group(&self) -> Option<crate::window::WindowGroup<R>> {
+    self.window.group()
+  }
+
   /// Determines if this window should be [decorated].
   ///
   /// [decorated]: https://en.wikipedia.org/wiki/Window_(computing)#Window_decoration
@@ -1633,6 +1817,13 @@ impl<R: Runtime> WebviewWindow<R> {
     self.window.start_dragging()
   }
 
+  /// Sets the drag, caption button and resize areas of the undecorated window, in logical pixels.
+  ///
+  /// See [`Window::set_hit_test`](crate::window::Window::set_hit_test).
+  pub fn set_hit_test(&self, hit_test: crate::window::HitTest) -> crate::Result<()> {
+    self.window.set_hit_test(hit_test)
+  }
+
   /// Sets the taskbar progress state.
   ///
   /// ## Platform-specific
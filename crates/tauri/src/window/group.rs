@@ -0,0 +1,241 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+  collections::{HashMap, HashSet},
+  fmt,
+  sync::{Arc, Mutex},
+};
+
+use tauri_macros::default_runtime;
+
+use crate::{manager::AppManager, Runtime, Window, WindowEvent};
+
+/// A group of windows sharing their lifecycle, see [`WindowBuilder::group`](super::WindowBuilder::group).
+///
+/// Minimizing or restoring a window of the group minimizes or restores the other ones,
+/// and the windows can be shown, hidden and closed together with [`Self::show`], [`Self::hide`] and [`Self::close`].
+/// Hiding or closing a single window does not affect the rest of the group.
+#[default_runtime(crate::Wry, wry)]
+pub struct WindowGroup<R: Runtime> {
+  name: String,
+  manager: Arc<AppManager<R>>,
+}
+
+impl<R: Runtime> fmt::Debug for WindowGroup<R> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("WindowGroup")
+      .field("name", &self.name)
+      .finish()
+  }
+}
+
+impl<R: Runtime> Clone for WindowGroup<R> {
+  fn clone(&self) -> Self {
+    Self {
+      name: self.name.clone(),
+      manager: self.manager.clone(),
+    }
+  }
+}
+
+impl<R: Runtime> WindowGroup<R> {
+  pub(crate) fn new(name: String, manager: Arc<AppManager<R>>) -> Self {
+    Self { name, manager }
+  }
+
+  /// The name of the group.
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// The windows of the group, in creation order.
+  pub fn windows(&self) -> Vec<Window<R>> {
+    self
+      .manager
+      .window
+      .groups
+      .members(&self.name)
+      .iter()
+      .filter_map(|label| self.manager.get_window(label))
+      .collect()
+  }
+
+  fn for_each(&self, f: impl Fn(&Window<R>) -> crate::Result<()>) -> crate::Result<()> {
+    self.windows().iter().try_for_each(f)
+  }
+
+  /// Shows the windows of the group.
+  pub fn show(&self) -> crate::Result<()> {
+    self.for_each(Window::show)
+  }
+
+  /// Hides the windows of the group.
+  pub fn hide(&self) -> crate::Result<()> {
+    self.for_each(Window::hide)
+  }
+
+  /// Minimizes the windows of the group.
+  pub fn minimize(&self) -> crate::Result<()> {
+    self.manager.window.groups.set_minimized(&self.name, true);
+    self.for_each(Window::minimize)
+  }
+
+  /// Restores the minimized windows of the group.
+  pub fn unminimize(&self) -> crate::Result<()> {
+    self.manager.window.groups.set_minimized(&self.name, false);
+    self.for_each(Window::unminimize)
+  }
+
+  /// Closes the windows of the group, emitting their close requested events.
+  pub fn close(&self) -> crate::Result<()> {
+    self.for_each(Window::close)
+  }
+}
+
+/// The window groups and their windows.
+#[derive(Default)]
+pub(crate) struct GroupStore {
+  members: Mutex<HashMap<String, Vec<String>>>,
+  minimized: Mutex<HashSet<String>>,
+}
+
+impl GroupStore {
+  pub(crate) fn add(&self, group: &str, label: &str) {
+    self
+      .members
+      .lock()
+      .unwrap()
+      .entry(group.into())
+      .or_default()
+      .push(label.into());
+  }
+
+  fn members(&self, group: &str) -> Vec<String> {
+    self
+      .members
+      .lock()
+      .unwrap()
+      .get(group)
+      .cloned()
+      .unwrap_or_default()
+  }
+
+  /// The group of the window.
+  pub(crate) fn group_of(&self, label: &str) -> Option<String> {
+    self
+      .members
+      .lock()
+      .unwrap()
+      .iter()
+      .find(|(_, labels)| labels.iter().any(|l| l == label))
+      .map(|(group, _)| group.clone())
+  }
+
+  /// Sets whether the group is minimized, returning `false` if it already was in this state.
+  fn set_minimized(&self, group: &str, minimized: bool) -> bool {
+    let mut groups = self.minimized.lock().unwrap();
+    if minimized {
+      groups.insert(group.into())
+    } else {
+      groups.remove(group)
+    }
+  }
+
+  pub(crate) fn on_window_event<R: Runtime>(&self, window: &Window<R>, event: &WindowEvent) {
+    match event {
+      // minimizing a window resizes it
+      WindowEvent::Resized(_) => {
+        let Some(group) = self.group_of(window.label()) else {
+          return;
+        };
+        let Ok(minimized) = window.is_minimized() else {
+          return;
+        };
+        if !self.set_minimized(&group, minimized) {
+          return;
+        }
+
+        for label in self.members(&group) {
+          if label == window.label() {
+            continue;
+          }
+          if let Some(window) = window.manager.get_window(&label) {
+            let _ = if minimized {
+              window.minimize()
+            } else {
+              window.unminimize()
+            };
+          }
+        }
+      }
+      WindowEvent::Destroyed => {
+        let mut members = self.members.lock().unwrap();
+        members.retain(|group, labels| {
+          labels.retain(|l| l != window.label());
+          if labels.is_empty() {
+            self.minimized.lock().unwrap().remove(group);
+          }
+          !labels.is_empty()
+        });
+      }
+      _ => (),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::GroupStore;
+  use crate::{test::mock_app, WindowEvent};
+
+  #[test]
+  fn members() {
+    let store = GroupStore::default();
+    store.add("editor", "main");
+    store.add("editor", "inspector");
+    store.add("settings", "preferences");
+
+    assert_eq!(store.members("editor"), ["main", "inspector"]);
+    assert!(store.members("unknown").is_empty());
+    assert_eq!(store.group_of("inspector").as_deref(), Some("editor"));
+    assert_eq!(store.group_of("unknown"), None);
+  }
+
+  #[test]
+  fn minimized_state_changes_once() {
+    let store = GroupStore::default();
+    store.add("editor", "main");
+
+    assert!(store.set_minimized("editor", true));
+    assert!(!store.set_minimized("editor", true));
+    assert!(store.set_minimized("editor", false));
+    assert!(!store.set_minimized("editor", false));
+  }
+
+  #[test]
+  fn destroyed_windows_leave_their_group() {
+    let app = mock_app();
+    let window = |label: &str| {
+      crate::WebviewWindowBuilder::new(&app, label, Default::default())
+        .build()
+        .unwrap()
+        .as_ref()
+        .window()
+    };
+    let (main, inspector) = (window("main"), window("inspector"));
+    let store = GroupStore::default();
+    store.add("editor", "main");
+    store.add("editor", "inspector");
+    store.set_minimized("editor", true);
+
+    store.on_window_event(&main, &WindowEvent::Destroyed);
+    assert_eq!(store.members("editor"), ["inspector"]);
+
+    // the state of the group is reset once its last window is destroyed
+    store.on_window_event(&inspector, &WindowEvent::Destroyed);
+    assert_eq!(store.group_of("inspector"), None);
+    assert!(store.set_minimized("editor", true));
+  }
+}
@@ -0,0 +1,319 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use tauri_runtime::dpi::{LogicalPosition, LogicalSize};
+use tauri_utils::config::WindowLayoutConfig;
+
+pub use tauri_utils::config::{LayoutSize, SplitDirection};
+
+/// A layout of the webviews of a window, made of splits and stacks, see [`Window::set_layout`](super::Window::set_layout).
+///
+/// The bounds of the webviews are updated when the window is resized or moved to a monitor with another scale factor.
+///
+/// # Examples
+///
+/// ```
+/// use tauri::window::{Layout, LayoutSize, SplitDirection};
+///
+/// // a sidebar next to tabs
+/// let layout = Layout::split(SplitDirection::Horizontal)
+///   .child(Layout::webview("sidebar").size(LayoutSize::Fixed(240.)))
+///   .child(
+///     Layout::stack()
+///       .child(Layout::webview("editor"))
+///       .child(Layout::webview("preview")),
+///   );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+  kind: LayoutKind,
+  size: LayoutSize,
+  visible: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum LayoutKind {
+  Webview(String),
+  Split {
+    direction: SplitDirection,
+    children: Vec<Layout>,
+  },
+  Stack {
+    children: Vec<Layout>,
+    selected: usize,
+  },
+}
+
+/// The bounds of a webview in logical pixels, relative to the window.
+pub(crate) type Bounds = (LogicalPosition<f64>, LogicalSize<f64>);
+
+impl From<WindowLayoutConfig> for Layout {
+  fn from(config: WindowLayoutConfig) -> Self {
+    match config {
+      WindowLayoutConfig::Webview(webview) => Self::webview(webview.label)
+        .size(webview.size)
+        .visible(webview.visible),
+      WindowLayoutConfig::Split(split) => split
+        .children
+        .into_iter()
+        .fold(Self::split(split.direction), |layout, child| {
+          layout.child(child.into())
+        })
+        .size(split.size)
+        .visible(split.visible),
+      WindowLayoutConfig::Stack(stack) => stack
+        .children
+        .into_iter()
+        .fold(Self::stack(), |layout, child| layout.child(child.into()))
+        .selected(stack.selected)
+        .size(stack.size)
+        .visible(stack.visible),
+    }
+  }
+}
+
+impl Layout {
+  fn new(kind: LayoutKind) -> Self {
+    Self {
+      kind,
+      size: LayoutSize::default(),
+      visible: true,
+    }
+  }
+
+  /// A webview of the window, filling its area.
+  pub fn webview<L: Into<String>>(label: L) -> Self {
+    Self::new(LayoutKind::Webview(label.into()))
+  }
+
+  /// Places its children side by side in the given direction, sized with [`Self::size`].
+  pub fn split(direction: SplitDirection) -> Self {
+    Self::new(LayoutKind::Split {
+      direction,
+      children: Vec::new(),
+    })
+  }
+
+  /// Places its children on top of each other and only shows the [selected](Self::selected) one, for instance for tabs.
+  pub fn stack() -> Self {
+    Self::new(LayoutKind::Stack {
+      children: Vec::new(),
+      selected: 0,
+    })
+  }
+
+  /// Adds a child to this split or stack. Webviews have no children.
+  pub fn child(mut self, child: Layout) -> Self {
+    if let LayoutKind::Split { children, .. } | LayoutKind::Stack { children, .. } = &mut self.kind
+    {
+      children.push(child);
+    }
+    self
+  }
+
+  /// The size of this item in its split, defaults to a ratio of `1`.
+  pub fn size(mut self, size: LayoutSize) -> Self {
+    self.size = size;
+    self
+  }
+
+  /// Whether this item is visible, defaults to `true`.
+  ///
+  /// Hidden items leave their space to the other items of their split.
+  pub fn visible(mut self, visible: bool) -> Self {
+    self.visible = visible;
+    self
+  }
+
+  /// The index of the visible child of this stack, defaults to `0`. Only stacks have a selected child.
+  pub fn selected(mut self, index: usize) -> Self {
+    if let LayoutKind::Stack { selected, .. } = &mut self.kind {
+      *selected = index;
+    }
+    self
+  }
+
+  /// The labels of the webviews of this layout.
+  pub fn webviews(&self) -> Vec<&str> {
+    match &self.kind {
+      LayoutKind::Webview(label) => vec![label.as_str()],
+      LayoutKind::Split { children, .. } | LayoutKind::Stack { children, .. } => {
+        children.iter().flat_map(Layout::webviews).collect()
+      }
+    }
+  }
+
+  fn contains(&self, label: &str) -> bool {
+    self.webviews().contains(&label)
+  }
+
+  /// Sets whether the item of the webview is visible. Returns `false` if the webview is not in the layout.
+  pub(crate) fn set_webview_visible(&mut self, label: &str, visible: bool) -> bool {
+    match &mut self.kind {
+      LayoutKind::Webview(l) if l == label => {
+        self.visible = visible;
+        true
+      }
+      LayoutKind::Webview(_) => false,
+      LayoutKind::Split { children, .. } | LayoutKind::Stack { children, .. } => children
+        .iter_mut()
+        .any(|child| child.set_webview_visible(label, visible)),
+    }
+  }
+
+  /// Selects the children of the stacks containing the webview. Returns `false` if the webview is not in the layout.
+  pub(crate) fn select_webview(&mut self, label: &str) -> bool {
+    match &mut self.kind {
+      LayoutKind::Webview(l) => l == label,
+      LayoutKind::Split { children, .. } => {
+        children.iter_mut().any(|child| child.select_webview(label))
+      }
+      LayoutKind::Stack { children, selected } => {
+        match children.iter().position(|child| child.contains(label)) {
+          Some(index) => {
+            *selected = index;
+            children[index].select_webview(label)
+          }
+          None => false,
+        }
+      }
+    }
+  }
+
+  /// Computes the bounds of the webviews in the given area, or `None` for the hidden webviews.
+  pub(crate) fn bounds(
+    &self,
+    position: LogicalPosition<f64>,
+    size: LogicalSize<f64>,
+  ) -> Vec<(String, Option<Bounds>)> {
+    let mut bounds = Vec::new();
+    self.compute(Some((position, size)), &mut bounds);
+    bounds
+  }
+
+  fn compute(&self, area: Option<Bounds>, bounds: &mut Vec<(String, Option<Bounds>)>) {
+    let area = area.filter(|_| self.visible);
+
+    match &self.kind {
+      LayoutKind::Webview(label) => bounds.push((label.clone(), area)),
+      LayoutKind::Stack { children, selected } => {
+        for (index, child) in children.iter().enumerate() {
+          child.compute(area.filter(|_| index == *selected), bounds);
+        }
+      }
+      LayoutKind::Split {
+        direction,
+        children,
+      } => {
+        let Some((position, size)) = area else {
+          for child in children {
+            child.compute(None, bounds);
+          }
+          return;
+        };
+
+        let length = match direction {
+          SplitDirection::Horizontal => size.width,
+          SplitDirection::Vertical => size.height,
+        };
+        let visible = || children.iter().filter(|child| child.visible);
+        let fixed: f64 = visible()
+          .filter_map(|child| match child.size {
+            LayoutSize::Fixed(size) => Some(size.max(0.)),
+            LayoutSize::Ratio(_) => None,
+          })
+          .sum();
+        let ratios: f64 = visible()
+          .filter_map(|child| match child.size {
+            LayoutSize::Ratio(ratio) => Some(ratio.max(0.)),
+            LayoutSize::Fixed(_) => None,
+          })
+          .sum();
+        let remaining = (length - fixed).max(0.);
+
+        let mut offset = 0.;
+        for child in children {
+          if !child.visible {
+            child.compute(None, bounds);
+            continue;
+          }
+
+          let child_length = match child.size {
+            LayoutSize::Fixed(size) => size.max(0.),
+            LayoutSize::Ratio(ratio) if ratios > 0. => remaining * ratio.max(0.) / ratios,
+            LayoutSize::Ratio(_) => 0.,
+          }
+          .min(length - offset);
+
+          let child_area = match direction {
+            SplitDirection::Horizontal => (
+              LogicalPosition::new(position.x + offset, position.y),
+              LogicalSize::new(child_length, size.height),
+            ),
+            SplitDirection::Vertical => (
+              LogicalPosition::new(position.x, position.y + offset),
+              LogicalSize::new(size.width, child_length),
+            ),
+          };
+          child.compute(Some(child_area), bounds);
+          offset += child_length;
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn bounds_of(layout: &Layout, label: &str) -> Option<(f64, f64, f64, f64)> {
+    layout
+      .bounds(LogicalPosition::new(0., 0.), LogicalSize::new(1000., 600.))
+      .into_iter()
+      .find(|(l, _)| l == label)
+      .unwrap()
+      .1
+      .map(|(position, size)| (position.x, position.y, size.width, size.height))
+  }
+
+  #[test]
+  fn split() {
+    let mut layout = Layout::split(SplitDirection::Horizontal)
+      .child(Layout::webview("sidebar").size(LayoutSize::Fixed(200.)))
+      .child(Layout::webview("main").size(LayoutSize::Ratio(3.)))
+      .child(Layout::webview("panel"));
+
+    assert_eq!(bounds_of(&layout, "sidebar"), Some((0., 0., 200., 600.)));
+    assert_eq!(bounds_of(&layout, "main"), Some((200., 0., 600., 600.)));
+    assert_eq!(bounds_of(&layout, "panel"), Some((800., 0., 200., 600.)));
+
+    // hidden items leave their space to the other ones
+    assert!(layout.set_webview_visible("panel", false));
+    assert_eq!(bounds_of(&layout, "main"), Some((200., 0., 800., 600.)));
+    assert_eq!(bounds_of(&layout, "panel"), None);
+
+    assert!(!layout.set_webview_visible("unknown", false));
+  }
+
+  #[test]
+  fn stack() {
+    let mut layout = Layout::split(SplitDirection::Vertical)
+      .child(Layout::webview("toolbar").size(LayoutSize::Fixed(40.)))
+      .child(
+        Layout::stack()
+          .child(Layout::webview("editor"))
+          .child(Layout::webview("preview")),
+      );
+
+    assert_eq!(bounds_of(&layout, "editor"), Some((0., 40., 1000., 560.)));
+    assert_eq!(bounds_of(&layout, "preview"), None);
+
+    assert!(layout.select_webview("preview"));
+    assert_eq!(bounds_of(&layout, "editor"), None);
+    assert_eq!(bounds_of(&layout, "preview"), Some((0., 40., 1000., 560.)));
+
+    assert_eq!(layout.webviews(), vec!["toolbar", "editor", "preview"]);
+  }
+}
@@ -4,7 +4,15 @@
 
 //! The Tauri window types and functions.
 
+#[cfg(desktop)]
+pub(crate) mod group;
+#[cfg(any(test, all(desktop, feature = "unstable")))]
+mod layout;
+#[cfg(desktop)]
+pub(crate) mod modal;
 pub(crate) mod plugin;
+#[cfg(desktop)]
+pub(crate) mod state;
 
 use tauri_runtime::{
   dpi::{PhysicalPosition, PhysicalSize},
@@ -14,6 +22,13 @@ pub use tauri_utils::{config::Color, WindowEffect as Effect, WindowEffectState a
 
 #[cfg(desktop)]
 pub use crate::runtime::ProgressBarStatus;
+#[cfg(desktop)]
+pub use group::WindowGroup;
+#[cfg(any(test, all(desktop, feature = "unstable")))]
+#[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "unstable"))))]
+pub use layout::{Layout, LayoutSize, SplitDirection};
+#[cfg(desktop)]
+pub use tauri_runtime::window::{HitTest, HitTestArea, HitTestRegion};
 
 use crate::{
   app::AppHandle,
@@ -21,7 +36,7 @@ use crate::{
   ipc::{CommandArg, CommandItem, InvokeError},
   manager::AppManager,
   runtime::{
-    monitor::Monitor as RuntimeMonitor,
+    monitor::{Monitor as RuntimeMonitor, PhysicalRect},
     window::{DetachedWindow, PendingWindow, WindowBuilder as _},
     RuntimeHandle, WindowDispatch,
   },
@@ -61,6 +76,7 @@ pub struct Monitor {
   pub(crate) name: Option<String>,
   pub(crate) size: PhysicalSize<u32>,
   pub(crate) position: PhysicalPosition<i32>,
+  pub(crate) work_area: PhysicalRect<i32, u32>,
   pub(crate) scale_factor: f64,
 }
 
@@ -70,6 +86,7 @@ impl From<RuntimeMonitor> for Monitor {
       name: monitor.name,
       size: monitor.size,
       position: monitor.position,
+      work_area: monitor.work_area,
       scale_factor: monitor.scale_factor,
     }
   }
@@ -92,6 +109,16 @@ impl Monitor {
     &self.position
   }
 
+  /// Returns the area of the monitor not covered by the taskbar, dock or menu bar,
+  /// relative to the larger full screen area.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS**: Returns the monitor bounds.
+  pub fn work_area(&self) -> &PhysicalRect<i32, u32> {
+    &self.work_area
+  }
+
   /// Returns the scale factor that can be used to map logical pixels to physical pixels, and vice versa.
   pub fn scale_factor(&self) -> f64 {
     self.scale_factor
@@ -122,6 +149,14 @@ unstable_struct!(
     #[cfg(desktop)]
     on_menu_event: Option<crate::app::GlobalMenuEventListener<Window<R>>>,
     window_effects: Option<WindowEffectsConfig>,
+    #[cfg(desktop)]
+    remember_state: bool,
+    #[cfg(any(test, all(desktop, feature = "unstable")))]
+    pub(crate) layout: Option<tauri_utils::config::WindowLayoutConfig>,
+    #[cfg(desktop)]
+    modal_parent: Option<String>,
+    #[cfg(desktop)]
+    group: Option<String>,
   }
 );
 
@@ -208,6 +243,14 @@ async fn create_window(app: tauri::AppHandle) {
       #[cfg(desktop)]
       on_menu_event: None,
       window_effects: None,
+      #[cfg(desktop)]
+      remember_state: false,
+      #[cfg(any(test, all(desktop, feature = "unstable")))]
+      layout: None,
+      #[cfg(desktop)]
+      modal_parent: None,
+      #[cfg(desktop)]
+      group: None,
     }
   }
 
@@ -215,6 +258,8 @@ async fn create_window(app: tauri::AppHandle) {
   /// Keep in mind that you can't create 2 windows with the same `label` so make sure
   /// that the initial window was closed or change the label of the new [`WindowBuilder`].
   ///
+  /// With the `unstable` feature, the webviews of the [`layout`](WindowConfig#structfield.layout) of the configuration are created with the window.
+  ///
   /// # Known issues
   ///
   /// On Windows, this function deadlocks when used in a synchronous command, see [the Webview2 issue].
@@ -254,6 +299,14 @@ async fn reopen_window(app: tauri::AppHandle) {
       menu: None,
       #[cfg(desktop)]
       on_menu_event: None,
+      #[cfg(desktop)]
+      remember_state: config.remember_state,
+      #[cfg(any(test, all(desktop, feature = "unstable")))]
+      layout: config.layout.clone(),
+      #[cfg(desktop)]
+      modal_parent: None,
+      #[cfg(desktop)]
+      group: None,
     };
 
     #[cfg(desktop)]
@@ -341,13 +394,26 @@ tauri::Builder::default()
     self,
     webview: Option<PendingWebview<EventLoopMessage, R>>,
   ) -> crate::Result<Window<R>> {
-    let mut pending = PendingWindow::new(self.window_builder.clone(), self.label.clone())?;
+    let app_manager = self.manager.manager();
+
+    #[cfg(desktop)]
+    let window_builder = if self.remember_state {
+      app_manager.window.state.restore(
+        self.manager.app_handle(),
+        &self.label,
+        self.window_builder.clone(),
+      )
+    } else {
+      self.window_builder.clone()
+    };
+    #[cfg(not(desktop))]
+    let window_builder = self.window_builder.clone();
+
+    let mut pending = PendingWindow::new(window_builder, self.label.clone())?;
     if let Some(webview) = webview {
       pending.set_webview(webview);
     }
 
-    let app_manager = self.manager.manager();
-
     let pending = app_manager.window.prepare_window(pending)?;
 
     #[cfg(desktop)]
@@ -392,6 +458,26 @@ tauri::Builder::default()
       window.on_menu_event(handler);
     }
 
+    #[cfg(desktop)]
+    if self.remember_state {
+      app_manager.window.state.track(window.label());
+    }
+
+    #[cfg(any(test, all(desktop, feature = "unstable")))]
+    if let Some(layout) = self.layout {
+      window.create_layout(layout)?;
+    }
+
+    #[cfg(desktop)]
+    if let Some(parent) = &self.modal_parent {
+      app_manager.window.modals.register(&window, parent);
+    }
+
+    #[cfg(desktop)]
+    if let Some(group) = &self.group {
+      app_manager.window.groups.add(group, window.label());
+    }
+
     if let Some(effects) = self.window_effects {
       crate::vibrancy::set_window_effects(&window, Some(effects))?;
     }
@@ -437,6 +523,20 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
     self
   }
 
+  /// Whether the position, size, maximized and fullscreen state of the window are saved
+  /// when it is moved, resized or closed, and restored the next time it is created.
+  ///
+  /// The saved state overrides the position and size given to the builder.
+  /// When the monitor the window was on is gone, it is moved on one of the available monitors.
+  /// A centered window is centered on the monitor it was on.
+  ///
+  /// The state is stored in the [`app_data_dir`](crate::path::PathResolver::app_data_dir), keyed by the window label.
+  #[must_use]
+  pub fn remember_state(mut self, remember: bool) -> Self {
+    self.remember_state = remember;
+    self
+  }
+
   /// Window size.
   #[must_use]
   pub fn inner_size(mut self, width: f64, height: f64) -> Self {
@@ -692,6 +792,26 @@ impl<'a, R: Runtime, M: Manager<R>> WindowBuilder<'a, R, M> {
     Ok(self)
   }
 
+  /// Makes the window to be created a modal window of `parent`, see [`Self::parent`].
+  ///
+  /// The input to the parent window is disabled until the modal window is closed.
+  /// Create it hidden and use [`Window::show_modal`] to show it and wait for its result.
+  pub fn modal(mut self, parent: &Window<R>) -> crate::Result<Self> {
+    self = self.parent(parent)?;
+    self.modal_parent.replace(parent.label().into());
+    Ok(self)
+  }
+
+  /// Adds the window to be created to a [`WindowGroup`], whose windows are minimized and restored together.
+  ///
+  /// Only minimizing and restoring a window follows the group. Hiding or closing a window does not affect
+  /// the other ones, use [`WindowGroup::show`], [`WindowGroup::hide`] and [`WindowGroup::close`] for that.
+  #[must_use]
+  pub fn group<G: Into<String>>(mut self, group: G) -> Self {
+    self.group.replace(group.into());
+    self
+  }
+
   /// Set an owner to the window to be created.
   ///
   /// From MSDN:
@@ -858,6 +978,9 @@ pub struct Window<R: Runtime> {
   // The menu set for this window
   #[cfg(desktop)]
   pub(crate) menu: Arc<Mutex<Option<WindowMenu<R>>>>,
+  /// The layout of the webviews of this window.
+  #[cfg(any(test, all(desktop, feature = "unstable")))]
+  pub(crate) layout: Arc<Mutex<Option<Layout>>>,
   pub(crate) resources_table: Arc<Mutex<ResourceTable>>,
 }
 
@@ -895,6 +1018,8 @@ impl<R: Runtime> Clone for Window<R> {
       app_handle: self.app_handle.clone(),
       #[cfg(desktop)]
       menu: self.menu.clone(),
+      #[cfg(any(test, all(desktop, feature = "unstable")))]
+      layout: self.layout.clone(),
       resources_table: self.resources_table.clone(),
     }
   }
@@ -964,6 +1089,8 @@ impl<R: Runtime> Window<R> {
       app_handle,
       #[cfg(desktop)]
       menu: Arc::new(std::sync::Mutex::new(menu)),
+      #[cfg(any(test, all(desktop, feature = "unstable")))]
+      layout: Default::default(),
       resources_table: Default::default(),
     }
   }
@@ -985,12 +1112,21 @@ impl<R: Runtime> Window<R> {
     webview_builder: WebviewBuilder<R>,
     position: P,
     size: S,
+  ) -> crate::Result<Webview<R>> {
+    self.create_child(webview_builder, position.into(), size.into())
+  }
+
+  /// Creates a webview as a child of this window on the main thread.
+  #[cfg(desktop)]
+  pub(crate) fn create_child(
+    &self,
+    webview_builder: WebviewBuilder<R>,
+    position: Position,
+    size: Size,
   ) -> crate::Result<Webview<R>> {
     use std::sync::mpsc::channel;
 
     let (tx, rx) = channel();
-    let position = position.into();
-    let size = size.into();
     let window_ = self.clone();
     self.run_on_main_thread(move || {
       let res = webview_builder.build(window_, position, size);
@@ -1309,6 +1445,105 @@ tauri::Builder::default()
   }
 }
 
+/// Layout APIs.
+#[cfg(any(test, all(desktop, feature = "unstable")))]
+#[cfg_attr(docsrs, doc(cfg(all(desktop, feature = "unstable"))))]
+impl<R: Runtime> Window<R> {
+  /// Sets the layout of the webviews of this window and applies it.
+  ///
+  /// The bounds of the webviews are updated when the window is resized or when its scale factor changes,
+  /// so they should not be [auto resized](Webview::set_auto_resize).
+  /// The webviews of the layout that are not children of this window are ignored.
+  pub fn set_layout(&self, layout: Layout) -> crate::Result<()> {
+    self.layout.lock().unwrap().replace(layout);
+    self.apply_layout()
+  }
+
+  /// Returns the layout of the webviews of this window.
+  pub fn layout(&self) -> Option<Layout> {
+    self.layout.lock().unwrap().clone()
+  }
+
+  /// Sets whether the webview is visible in the layout of this window.
+  ///
+  /// Hidden webviews leave their space to the other items of their split.
+  pub fn set_webview_visible(&self, label: &str, visible: bool) -> crate::Result<()> {
+    self.update_layout(|layout| layout.set_webview_visible(label, visible))
+  }
+
+  /// Shows the webview in the stacks of the layout of this window, for instance to switch tabs.
+  pub fn select_webview(&self, label: &str) -> crate::Result<()> {
+    self.update_layout(|layout| layout.select_webview(label))
+  }
+
+  fn update_layout(&self, f: impl FnOnce(&mut Layout) -> bool) -> crate::Result<()> {
+    let found = self.layout.lock().unwrap().as_mut().is_some_and(f);
+    if !found {
+      return Err(crate::Error::WebviewNotFound);
+    }
+    self.apply_layout()
+  }
+
+  /// Creates the webviews of a layout from the configuration.
+  fn create_layout(&self, config: tauri_utils::config::WindowLayoutConfig) -> crate::Result<()> {
+    fn webviews(
+      config: tauri_utils::config::WindowLayoutConfig,
+      out: &mut Vec<tauri_utils::config::LayoutWebviewConfig>,
+    ) {
+      use tauri_utils::config::WindowLayoutConfig;
+      match config {
+        WindowLayoutConfig::Webview(webview) => out.push(webview),
+        WindowLayoutConfig::Split(split) => {
+          split.children.into_iter().for_each(|c| webviews(c, out))
+        }
+        WindowLayoutConfig::Stack(stack) => {
+          stack.children.into_iter().for_each(|c| webviews(c, out))
+        }
+      }
+    }
+
+    let layout = Layout::from(config.clone());
+    let mut configs = Vec::new();
+    webviews(config, &mut configs);
+    for webview in configs {
+      self.create_child(
+        WebviewBuilder::new(webview.label, webview.url),
+        Position::Logical(Default::default()),
+        Size::Logical(Default::default()),
+      )?;
+    }
+
+    self.set_layout(layout)
+  }
+
+  /// Updates the bounds and visibility of the webviews of the layout.
+  pub(crate) fn apply_layout(&self) -> crate::Result<()> {
+    let Some(layout) = self.layout() else {
+      return Ok(());
+    };
+
+    let size = self.inner_size()?.to_logical(self.scale_factor()?);
+    let webviews = self.webviews();
+    for (label, bounds) in layout.bounds(Default::default(), size) {
+      let Some(webview) = webviews.iter().find(|w| w.label() == label) else {
+        continue;
+      };
+      match bounds {
+        Some((position, size)) => {
+          webview.set_bounds(tauri_runtime::Rect {
+            position: position.into(),
+            size: size.into(),
+          })?;
+          webview.show()?;
+        }
+        None => webview.hide()?,
+      }
+    }
+
+    Ok(())
+  }
+}
+
 /// Window getters.
 impl<R: Runtime> Window<R> {
   /// Returns the scale factor that can be used to map logical pixels to physical pixels, and vice versa.
@@ -1704,6 +1939,62 @@ impl<R: Runtime> Window<R> {
     self.window.dispatcher.destroy().map_err(Into::into)
   }
 
+  /// Shows this window and waits until it is closed, resolving with the result given to [`Self::close_with_result`].
+  ///
+  /// Resolves with `None` when the window is closed without a result.
+  /// Use [`WindowBuilder::modal`] to disable the input to the parent window while it is open.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::Manager;
+  ///
+  /// #[tauri::command]
+  /// async fn confirm(app: tauri::AppHandle) -> tauri::Result<bool> {
+  ///   let main = app.get_webview_window("main").unwrap();
+  ///   let dialog = tauri::WebviewWindowBuilder::new(&app, "confirm", tauri::WebviewUrl::App("confirm.html".into()))
+  ///     .modal(&main)?
+  ///     .visible(false)
+  ///     .build()?;
+  ///   // the frontend of the dialog calls `getCurrentWindow().closeWithResult(true)`
+  ///   Ok(dialog.show_modal::<bool>().await?.unwrap_or(false))
+  /// }
+  /// ```
+  pub async fn show_modal<T: serde::de::DeserializeOwned>(&self) -> crate::Result<Option<T>> {
+    let closed = self.manager.window.modals.wait(self.label());
+    self.show()?;
+    self.set_focus()?;
+    closed
+      .await
+      .ok()
+      .flatten()
+      .map(serde_json::from_value)
+      .transpose()
+      .map_err(Into::into)
+  }
+
+  /// Closes this window like [`Self::close`], resolving the [`Self::show_modal`] calls with `result`.
+  ///
+  /// If the close request is prevented, the result is discarded when the window is closed again.
+  pub fn close_with_result<T: Serialize>(&self, result: T) -> crate::Result<()> {
+    self
+      .manager
+      .window
+      .modals
+      .set_result(self.label(), serde_json::to_value(result)?);
+    self.close()
+  }
+
+  /// Returns the [`WindowGroup`] of this window, see [`WindowBuilder::group`].
+  pub fn group(&self) -> Option<WindowGroup<R>> {
+    self
+      .manager
+      .window
+      .groups
+      .group_of(self.label())
+      .map(|name| WindowGroup::new(name, self.manager.clone()))
+  }
+
   /// Determines if this window should be [decorated].
   ///
   /// [decorated]: https://en.wikipedia.org/wiki/Window_(computing)#Window_decoration
@@ -1977,6 +2268,40 @@ tauri::Builder::default()
       .map_err(Into::into)
   }
 
+  /// Sets the drag, caption button and resize areas of the undecorated window, in logical pixels.
+  ///
+  /// The areas are handled natively so they work without the `data-tauri-drag-region` attribute.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows**: Only the resize border is handled natively, use the `data-tauri-drag-region` attribute for the other areas.
+  /// - **macOS**: Unsupported.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use tauri::{window::{HitTest, HitTestArea}, Manager};
+  ///
+  /// tauri::Builder::default()
+  ///   .setup(|app| {
+  ///     let window = app.get_webview_window("main").unwrap();
+  ///     window.set_hit_test(
+  ///       HitTest::new()
+  ///         .resize_border(8.)
+  ///         .region(HitTestArea::Drag, (0., 0.), (800., 32.))
+  ///         .region(HitTestArea::Close, (768., 0.), (32., 32.)),
+  ///     )?;
+  ///     Ok(())
+  ///   });
+  /// ```
+  pub fn set_hit_test(&self, hit_test: HitTest) -> crate::Result<()> {
+    self
+      .window
+      .dispatcher
+      .set_hit_test(hit_test)
+      .map_err(Into::into)
+  }
+
   /// Sets the taskbar progress state.
   ///
   /// ## Platform-specific
@@ -0,0 +1,175 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, sync::Mutex};
+
+use serde_json::Value;
+use tokio::sync::oneshot;
+
+use crate::{Runtime, Window};
+
+#[derive(Default)]
+struct Modal {
+  /// The label of the window whose input is disabled while this one is open.
+  parent: Option<String>,
+  /// The result given to [`Window::close_with_result`].
+  result: Option<Value>,
+  /// Whether the close request of [`Window::close_with_result`] was not received yet.
+  closing: bool,
+  /// The [`Window::show_modal`] calls waiting for the window to close.
+  waiters: Vec<oneshot::Sender<Option<Value>>>,
+}
+
+/// The modal windows and the windows waited with [`Window::show_modal`], see [`WindowBuilder::modal`](super::WindowBuilder::modal).
+#[derive(Default)]
+pub(crate) struct ModalStore {
+  modals: Mutex<HashMap<String, Modal>>,
+}
+
+impl ModalStore {
+  /// Registers a modal window of the parent window, disabling the input to the parent.
+  pub(crate) fn register<R: Runtime>(&self, window: &Window<R>, parent: &str) {
+    self
+      .modals
+      .lock()
+      .unwrap()
+      .entry(window.label().into())
+      .or_default()
+      .parent
+      .replace(parent.into());
+
+    if let Some(parent) = window.manager.get_window(parent) {
+      let _ = parent.set_enabled(false);
+    }
+  }
+
+  /// Returns a receiver resolved with the result of the window when it is closed.
+  pub(crate) fn wait(&self, label: &str) -> oneshot::Receiver<Option<Value>> {
+    let (tx, rx) = oneshot::channel();
+    self
+      .modals
+      .lock()
+      .unwrap()
+      .entry(label.into())
+      .or_default()
+      .waiters
+      .push(tx);
+    rx
+  }
+
+  /// Sets the result of the window, used if the close request following this call isn't prevented.
+  pub(crate) fn set_result(&self, label: &str, result: Value) {
+    let mut modals = self.modals.lock().unwrap();
+    let modal = modals.entry(label.into()).or_default();
+    modal.result.replace(result);
+    modal.closing = true;
+  }
+
+  /// Discards the result of a previous close request, which was prevented since the window is still open.
+  pub(crate) fn on_close_requested(&self, label: &str) {
+    if let Some(modal) = self.modals.lock().unwrap().get_mut(label) {
+      if !std::mem::take(&mut modal.closing) {
+        modal.result.take();
+      }
+    }
+  }
+
+  /// Resolves the [`Window::show_modal`] calls of the window and enables its parent
+  /// once it has no other modal window.
+  pub(crate) fn on_destroyed<R: Runtime>(&self, window: &Window<R>) {
+    let (modal, parent_has_modals) = {
+      let mut modals = self.modals.lock().unwrap();
+      let Some(modal) = modals.remove(window.label()) else {
+        return;
+      };
+      let parent_has_modals = modals
+        .values()
+        .any(|m| m.parent.is_some() && m.parent == modal.parent);
+      (modal, parent_has_modals)
+    };
+
+    if let Some(parent) = modal
+      .parent
+      .filter(|_| !parent_has_modals)
+      .and_then(|parent| window.manager.get_window(&parent))
+    {
+      let _ = parent.set_enabled(true);
+      let _ = parent.set_focus();
+    }
+
+    for waiter in modal.waiters {
+      let _ = waiter.send(modal.result.clone());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::{json, Value};
+
+  use super::ModalStore;
+  use crate::test::mock_app;
+
+  fn closed(store: &ModalStore, window: &crate::Window<crate::test::MockRuntime>) -> Option<Value> {
+    let mut rx = store.wait(window.label());
+    store.on_destroyed(window);
+    rx.try_recv().unwrap()
+  }
+
+  #[test]
+  fn close_with_result() {
+    let app = mock_app();
+    let window = crate::WebviewWindowBuilder::new(&app, "dialog", Default::default())
+      .build()
+      .unwrap()
+      .as_ref()
+      .window();
+    let store = ModalStore::default();
+
+    assert_eq!(closed(&store, &window), None);
+
+    store.set_result("dialog", json!(true));
+    store.on_close_requested("dialog");
+    assert_eq!(closed(&store, &window), Some(json!(true)));
+  }
+
+  #[test]
+  fn prevented_close_discards_the_result() {
+    let app = mock_app();
+    let window = crate::WebviewWindowBuilder::new(&app, "dialog", Default::default())
+      .build()
+      .unwrap()
+      .as_ref()
+      .window();
+    let store = ModalStore::default();
+
+    store.set_result("dialog", json!(true));
+    // the close request of `close_with_result` is prevented
+    store.on_close_requested("dialog");
+    // the window is closed later without a result
+    store.on_close_requested("dialog");
+    assert_eq!(closed(&store, &window), None);
+  }
+
+  #[test]
+  fn waiters_are_resolved_once() {
+    let app = mock_app();
+    let window = crate::WebviewWindowBuilder::new(&app, "dialog", Default::default())
+      .build()
+      .unwrap()
+      .as_ref()
+      .window();
+    let store = ModalStore::default();
+
+    let mut first = store.wait("dialog");
+    let mut second = store.wait("dialog");
+    store.set_result("dialog", json!("ok"));
+    store.on_close_requested("dialog");
+    store.on_destroyed(&window);
+
+    assert_eq!(first.try_recv().unwrap(), Some(json!("ok")));
+    assert_eq!(second.try_recv().unwrap(), Some(json!("ok")));
+    assert!(store.modals.lock().unwrap().is_empty());
+  }
+}
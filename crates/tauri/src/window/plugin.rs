@@ -114,6 +114,7 @@ mod desktop_commands {
   setter!(show);
   setter!(hide);
   setter!(close);
+  setter!(close_with_result, serde_json::Value);
   setter!(destroy);
   setter!(set_decorations, bool);
   setter!(set_shadow, bool);
@@ -265,6 +266,7 @@ pub fn init<R: Runtime>() -> TauriPlugin<R> {
             desktop_commands::show,
             desktop_commands::hide,
             desktop_commands::close,
+            desktop_commands::close_with_result,
             desktop_commands::destroy,
             desktop_commands::set_decorations,
             desktop_commands::set_shadow,
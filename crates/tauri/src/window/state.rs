@@ -0,0 +1,288 @@
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+//! Persistence of the window geometry, see [`WindowBuilder::remember_state`](super::WindowBuilder::remember_state).
+
+use std::{
+  collections::{HashMap, HashSet},
+  path::PathBuf,
+  sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+use tauri_runtime::{
+  dpi::{PhysicalPosition, PhysicalSize},
+  window::WindowBuilder,
+};
+
+use crate::{AppHandle, Manager, Runtime, Window, WindowEvent};
+
+use super::Monitor;
+
+const STATE_FILENAME: &str = "window-state.json";
+
+/// The saved geometry of a window, in physical pixels.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub(crate) struct WindowState {
+  x: i32,
+  y: i32,
+  width: u32,
+  height: u32,
+  maximized: bool,
+  fullscreen: bool,
+  monitor: Option<String>,
+}
+
+impl WindowState {
+  /// Fits the window on the monitor it was on, on the monitor it overlaps when this one is gone,
+  /// or on the first monitor when it doesn't overlap any.
+  ///
+  /// Returns the position and size of the window along with the scale factor of its monitor.
+  fn fit(&self, monitors: &[Monitor]) -> Option<(PhysicalPosition<i32>, PhysicalSize<u32>, f64)> {
+    if self.width == 0 || self.height == 0 {
+      return None;
+    }
+
+    let monitor = monitors
+      .iter()
+      .find(|m| self.monitor.is_some() && m.name == self.monitor)
+      .or_else(|| monitors.iter().find(|m| self.overlaps(m)))
+      .or_else(|| monitors.first())?;
+
+    let width = self.width.min(monitor.size.width);
+    let height = self.height.min(monitor.size.height);
+    let x = self.x.clamp(
+      monitor.position.x,
+      monitor.position.x + (monitor.size.width - width) as i32,
+    );
+    let y = self.y.clamp(
+      monitor.position.y,
+      monitor.position.y + (monitor.size.height - height) as i32,
+    );
+
+    Some((
+      PhysicalPosition::new(x, y),
+      PhysicalSize::new(width, height),
+      monitor.scale_factor,
+    ))
+  }
+
+  fn overlaps(&self, monitor: &Monitor) -> bool {
+    let (x, y) = (monitor.position.x, monitor.position.y);
+    self.x < x + monitor.size.width as i32
+      && x < self.x + self.width as i32
+      && self.y < y + monitor.size.height as i32
+      && y < self.y + self.height as i32
+  }
+}
+
+/// The saved states of the windows created with [`WindowBuilder::remember_state`](super::WindowBuilder::remember_state).
+///
+/// The states are updated when the windows are moved or resized
+/// and written to the app data directory when they are closed or when the app exits.
+#[derive(Debug, Default)]
+pub(crate) struct WindowStateStore {
+  /// The labels of the windows whose state is remembered.
+  tracked: Mutex<HashSet<String>>,
+  /// The saved states, read on first use.
+  states: Mutex<Option<HashMap<String, WindowState>>>,
+}
+
+impl WindowStateStore {
+  fn path<R: Runtime>(app: &AppHandle<R>) -> crate::Result<PathBuf> {
+    Ok(app.path().app_data_dir()?.join(STATE_FILENAME))
+  }
+
+  fn with_states<R: Runtime, T>(
+    &self,
+    app: &AppHandle<R>,
+    f: impl FnOnce(&mut HashMap<String, WindowState>) -> T,
+  ) -> T {
+    let mut states = self.states.lock().unwrap();
+    let states = states.get_or_insert_with(|| {
+      Self::path(app)
+        .ok()
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+    });
+    f(states)
+  }
+
+  /// Applies the saved state of the window to its builder, fitted on the available monitors.
+  pub(crate) fn restore<R: Runtime, B: WindowBuilder>(
+    &self,
+    app: &AppHandle<R>,
+    label: &str,
+    mut builder: B,
+  ) -> B {
+    let Some(state) = self.with_states(app, |states| states.get(label).cloned()) else {
+      return builder;
+    };
+
+    let mut monitors = app.available_monitors().unwrap_or_default();
+    if let Ok(Some(primary)) = app.primary_monitor() {
+      monitors.sort_by_key(|m| m.name != primary.name);
+    }
+
+    if let Some((position, size, scale_factor)) = state.fit(&monitors) {
+      builder = builder
+        .position(
+          position.x as f64 / scale_factor,
+          position.y as f64 / scale_factor,
+        )
+        .inner_size(
+          size.width as f64 / scale_factor,
+          size.height as f64 / scale_factor,
+        );
+    }
+
+    builder
+      .maximized(state.maximized)
+      .fullscreen(state.fullscreen)
+  }
+
+  /// Starts remembering the state of the window.
+  pub(crate) fn track(&self, label: &str) {
+    self.tracked.lock().unwrap().insert(label.into());
+  }
+
+  pub(crate) fn on_window_event<R: Runtime>(&self, window: &Window<R>, event: &WindowEvent) {
+    if !self.tracked.lock().unwrap().contains(window.label()) {
+      return;
+    }
+
+    match event {
+      WindowEvent::Moved(_) | WindowEvent::Resized(_) => self.update(window),
+      WindowEvent::CloseRequested { .. } => {
+        self.update(window);
+        self.save(window.app_handle());
+      }
+      WindowEvent::Destroyed => {
+        self.tracked.lock().unwrap().remove(window.label());
+        self.save(window.app_handle());
+      }
+      _ => (),
+    }
+  }
+
+  /// Saves the current state of the window.
+  ///
+  /// The geometry of maximized and fullscreen windows is not saved
+  /// so they are restored to their previous size when leaving these states.
+  fn update<R: Runtime>(&self, window: &Window<R>) {
+    let (Ok(maximized), Ok(fullscreen), Ok(minimized)) = (
+      window.is_maximized(),
+      window.is_fullscreen(),
+      window.is_minimized(),
+    ) else {
+      return;
+    };
+    // minimized windows are moved out of the screen on Windows
+    if minimized {
+      return;
+    }
+
+    let geometry = if maximized || fullscreen {
+      None
+    } else {
+      window
+        .outer_position()
+        .and_then(|position| Ok((position, window.inner_size()?)))
+        .ok()
+    };
+    let monitor = window.current_monitor().ok().flatten().and_then(|m| m.name);
+
+    self.with_states(window.app_handle(), |states| {
+      let state = states.entry(window.label().into()).or_default();
+      state.maximized = maximized;
+      state.fullscreen = fullscreen;
+      if let Some((position, size)) = geometry {
+        state.x = position.x;
+        state.y = position.y;
+        state.width = size.width;
+        state.height = size.height;
+        state.monitor = monitor;
+      }
+    });
+  }
+
+  /// Writes the saved states to the app data directory, if any window state was read or updated.
+  pub(crate) fn save<R: Runtime>(&self, app: &AppHandle<R>) {
+    let contents = match &*self.states.lock().unwrap() {
+      Some(states) => serde_json::to_vec(states),
+      None => return,
+    };
+    let (Ok(path), Ok(contents)) = (Self::path(app), contents) else {
+      return;
+    };
+
+    if let Some(parent) = path.parent() {
+      let _ = std::fs::create_dir_all(parent);
+    }
+    if let Err(e) = std::fs::write(&path, contents) {
+      log::error!("failed to save the window state to {}: {e}", path.display());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn monitor(name: &str, x: i32, width: u32) -> Monitor {
+    Monitor {
+      name: Some(name.into()),
+      size: PhysicalSize::new(width, 1080),
+      position: PhysicalPosition::new(x, 0),
+      work_area: Default::default(),
+      scale_factor: 1.,
+    }
+  }
+
+  fn state(x: i32, y: i32, width: u32, height: u32, monitor: &str) -> WindowState {
+    WindowState {
+      x,
+      y,
+      width,
+      height,
+      monitor: Some(monitor.into()),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn fit_on_saved_monitor() {
+    let monitors = [monitor("left", 0, 1920), monitor("right", 1920, 2560)];
+
+    let (position, size, _) = state(2000, 100, 800, 600, "right").fit(&monitors).unwrap();
+    assert_eq!(position, PhysicalPosition::new(2000, 100));
+    assert_eq!(size, PhysicalSize::new(800, 600));
+
+    // moved back on its monitor when it went out of it
+    let (position, _, _) = state(4400, 900, 800, 600, "right").fit(&monitors).unwrap();
+    assert_eq!(position, PhysicalPosition::new(3680, 480));
+  }
+
+  #[test]
+  fn fit_when_monitor_is_gone() {
+    let monitors = [monitor("primary", 0, 1920), monitor("side", -1280, 1280)];
+
+    // on the monitor it overlaps
+    let (position, _, _) = state(-1000, 100, 800, 600, "unplugged")
+      .fit(&monitors)
+      .unwrap();
+    assert_eq!(position, PhysicalPosition::new(-1000, 100));
+
+    // on the first monitor, shrunk to fit it
+    let (position, size, _) = state(4000, 100, 2560, 1440, "unplugged")
+      .fit(&monitors)
+      .unwrap();
+    assert_eq!(position, PhysicalPosition::new(0, 0));
+    assert_eq!(size, PhysicalSize::new(1920, 1080));
+
+    assert!(state(0, 0, 800, 600, "primary").fit(&[]).is_none());
+  }
+}
@@ -180,6 +180,120 @@ async function invoke<T>(
   return window.__TAURI_INTERNALS__.invoke(cmd, args, options)
 }
 
+/**
+ * A command invocation submitted as part of a batch.
+ *
+ * @since 2.1.0
+ */
+interface BatchInvocation {
+  cmd: string
+  /** The command arguments. Batches only support JSON arguments. */
+  args?: Record<string, unknown>
+  /** Headers for this invocation, merged with the batch request headers. */
+  headers?: Record<string, string>
+}
+
+/**
+ * The outcome of a batched invocation.
+ *
+ * @since 2.1.0
+ */
+type BatchResult<T = unknown> =
+  | { status: 'ok'; data: T }
+  | { status: 'error'; data: unknown }
+
+const BATCH_COMMAND = '__TAURI_BATCH__'
+
+/**
+ * Sends several commands to the backend in a single IPC request.
+ * Each command is still checked individually against the app capabilities.
+ * @example
+ * ```typescript
+ * import { invokeBatch } from '@tauri-apps/api/core';
+ * const [user, settings] = await invokeBatch([
+ *   { cmd: 'get_user', args: { id: 1 } },
+ *   { cmd: 'get_settings' }
+ * ]);
+ * ```
+ *
+ * @param invocations The commands to run.
+ * @param options The request options.
+ * @return A promise resolving to the result of each command, in the order they were submitted.
+ *
+ * @since 2.1.0
+ */
+async function invokeBatch(
+  invocations: BatchInvocation[],
+  options?: InvokeOptions
+): Promise<BatchResult[]> {
+  return invoke(
+    BATCH_COMMAND,
+    {
+      invocations: invocations.map(({ cmd, args, headers }) => ({
+        cmd,
+        payload: args ?? {},
+        headers
+      }))
+    },
+    options
+  )
+}
+
+/**
+ * Sends several commands to the backend in a single IPC request,
+ * receiving each result as soon as its command completes.
+ * @example
+ * ```typescript
+ * import { invokeBatchStreaming } from '@tauri-apps/api/core';
+ * await invokeBatchStreaming(
+ *   [{ cmd: 'load_chart', args: { id: 1 } }, { cmd: 'load_chart', args: { id: 2 } }],
+ *   (index, result) => console.log(index, result)
+ * );
+ * ```
+ *
+ * @param invocations The commands to run.
+ * @param onResult Called with the index of the invocation and its result.
+ * @param options The request options.
+ * @return A promise resolving once every result has been received.
+ *
+ * @since 2.1.0
+ */
+async function invokeBatchStreaming(
+  invocations: BatchInvocation[],
+  onResult: (index: number, result: BatchResult) => void,
+  options?: InvokeOptions
+): Promise<void> {
+  const channel = new Channel<BatchResult & { index: number }>()
+  let received = 0
+  let expected: number | null = null
+  const delivered = new Promise<void>((resolve) => {
+    channel.onmessage = ({ index, ...result }) => {
+      onResult(index, result as BatchResult)
+      received += 1
+      if (received === expected) {
+        resolve()
+      }
+    }
+  })
+  // the backend resolves with the number of results sent through the channel,
+  // which may not all have been delivered yet
+  expected = await invoke<number>(
+    BATCH_COMMAND,
+    {
+      invocations: invocations.map(({ cmd, args, headers }) => ({
+        cmd,
+        payload: args ?? {},
+        headers
+      })),
+      channel
+    },
+    options
+  )
+  if (received < expected) {
+    await delivered
+  }
+}
+
 /**
  * Convert a device file path to an URL that can be loaded by the webview.
  * Note that `asset:` and `http://asset.localhost` must be added to [`app.security.csp`](https://v2.tauri.app/reference/config/#csp-1) in `tauri.conf.json`.
@@ -262,7 +376,7 @@ function isTauri(): boolean {
   return 'isTauri' in window && !!window.isTauri
 }
 
-export type { InvokeArgs, InvokeOptions }
+export type { InvokeArgs, InvokeOptions, BatchInvocation, BatchResult }
 
 export {
   transformCallback,
@@ -273,6 +387,8 @@ export {
   checkPermissions,
   requestPermissions,
   invoke,
+  invokeBatch,
+  invokeBatchStreaming,
   convertFileSrc,
   isTauri
 }
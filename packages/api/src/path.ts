@@ -539,6 +539,23 @@ async function tempDir(): Promise<string> {
   })
 }
 
+/**
+ * Returns the path of a base directory registered by the app or a plugin with `PathResolver::register_base_directory`.
+ * @example
+ * ```typescript
+ * import { customDir } from '@tauri-apps/api/path';
+ * const workspaceDirPath = await customDir('WORKSPACE');
+ * ```
+ * @param name The base directory name, with or without the `$` prefix.
+ *
+ * @since 2.1.0
+ */
+async function customDir(name: string): Promise<string> {
+  return invoke('plugin:path|resolve_directory', {
+    directory: name
+  })
+}
+
 /**
  * Returns the platform-specific path segment separator:
  * - `\` on Windows
@@ -699,5 +716,6 @@ export {
   extname,
   basename,
   isAbsolute,
-  tempDir
+  tempDir,
+  customDir
 }
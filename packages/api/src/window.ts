@@ -49,6 +49,15 @@ export interface Monitor {
   size: PhysicalSize
   /** the Top-left corner position of the monitor relative to the larger full screen area. */
   position: PhysicalPosition
+  /**
+   * The area of the monitor not covered by the taskbar, dock or menu bar, relative to the larger full screen area.
+   *
+   * @since 2.1.0
+   */
+  workArea: {
+    position: PhysicalPosition
+    size: PhysicalSize
+  }
   /** The scale factor that can be used to map physical pixels to logical pixels. */
   scaleFactor: number
 }
@@ -1124,6 +1133,26 @@ class Window {
     })
   }
 
+  /**
+   * Closes the window like {@link Window.close}, resolving the `show_modal` calls waiting for it on the Rust side with the given result.
+   * @example
+   * ```typescript
+   * import { getCurrentWindow } from '@tauri-apps/api/window';
+   * await getCurrentWindow().closeWithResult({ confirmed: true });
+   * ```
+   *
+   * @param result The result, serialized to JSON.
+   * @returns A promise indicating the success or failure of the operation.
+   *
+   * @since 2.1.0
+   */
+  async closeWithResult(result: unknown): Promise<void> {
+    return invoke('plugin:window|close_with_result', {
+      label: this.label,
+      value: result
+    })
+  }
+
   /**
    * Destroys the window. Behaves like {@link Window.close} but forces the window close instead of emitting a closeRequested event.
    * @example
@@ -2291,6 +2320,17 @@ interface WindowOptions {
    * @since 2.0.0
    */
   visibleOnAllWorkspaces?: boolean
+  /**
+   * Whether the position, size, maximized and fullscreen state of the window are saved
+   * when it is moved, resized or closed, and restored the next time it is created.
+   *
+   * #### Platform-specific
+   *
+   * - **iOS / Android:** Unsupported.
+   *
+   * @since 2.1.0
+   */
+  rememberState?: boolean
 }
 
 function mapMonitor(m: Monitor | null): Monitor | null {
@@ -2300,7 +2340,11 @@ function mapMonitor(m: Monitor | null): Monitor | null {
         name: m.name,
         scaleFactor: m.scaleFactor,
         position: mapPhysicalPosition(m.position),
-        size: mapPhysicalSize(m.size)
+        size: mapPhysicalSize(m.size),
+        workArea: {
+          position: mapPhysicalPosition(m.workArea.position),
+          size: mapPhysicalSize(m.workArea.size)
+        }
       }
 }
 